@@ -13,35 +13,35 @@
 //!
 //! # Supported Devices
 //!
-//! | `stm32_mcu` | Core name             | Reference manual                                                         | Available features                                       |
-//! |-------------|-----------------------|--------------------------------------------------------------------------|----------------------------------------------------------|
-//! | `stm32f100` | ARM® Cortex®-M3 r1p1  | [RM0041](https://www.st.com/resource/en/reference_manual/cd00246267.pdf) | `dma` `gpio` `spi` `tim`                                 |
-//! | `stm32f101` | ARM® Cortex®-M3 r1p1  | [RM0008](https://www.st.com/resource/en/reference_manual/cd00171190.pdf) | `dma` `gpio` `spi` `tim`                                 |
-//! | `stm32f102` | ARM® Cortex®-M3 r1p1  | [RM0008](https://www.st.com/resource/en/reference_manual/cd00171190.pdf) | `dma` `gpio` `spi` `tim`                                 |
-//! | `stm32f103` | ARM® Cortex®-M3 r1p1  | [RM0008](https://www.st.com/resource/en/reference_manual/cd00171190.pdf) | `dma` `gpio` `spi` `tim`                                 |
-//! | `stm32f107` | ARM® Cortex®-M3 r1p1  | [RM0008](https://www.st.com/resource/en/reference_manual/cd00171190.pdf) | `dma` `gpio` `spi` `tim`                                 |
-//! | `stm32f401` | ARM® Cortex®-M4F r0p1 | [RM0368](https://www.st.com/resource/en/reference_manual/dm00096844.pdf) | `adc` `dma` `exti` `gpio` `i2c` `tim`                    |
-//! | `stm32f405` | ARM® Cortex®-M4F r0p1 | [RM0090](https://www.st.com/resource/en/reference_manual/dm00031020.pdf) | `adc` `dma` `exti` `gpio` `i2c` `tim`                    |
-//! | `stm32f407` | ARM® Cortex®-M4F r0p1 | [RM0090](https://www.st.com/resource/en/reference_manual/dm00031020.pdf) | `adc` `dma` `exti` `gpio` `i2c` `tim`                    |
-//! | `stm32f410` | ARM® Cortex®-M4F r0p1 | [RM0401](https://www.st.com/resource/en/reference_manual/dm00180366.pdf) | `adc` `dma` `exti` `gpio` `i2c` `tim`                    |
-//! | `stm32f411` | ARM® Cortex®-M4F r0p1 | [RM0383](https://www.st.com/resource/en/reference_manual/dm00119316.pdf) | `adc` `dma` `exti` `gpio` `i2c` `tim`                    |
-//! | `stm32f412` | ARM® Cortex®-M4F r0p1 | [RM0402](https://www.st.com/resource/en/reference_manual/dm00180369.pdf) | `adc` `dma` `exti` `gpio` `i2c` `tim`                    |
-//! | `stm32f413` | ARM® Cortex®-M4F r0p1 | [RM0430](https://www.st.com/resource/en/reference_manual/dm00305666.pdf) | `adc` `dma` `exti` `gpio` `i2c` `tim`                    |
-//! | `stm32f427` | ARM® Cortex®-M4F r0p1 | [RM0090](https://www.st.com/resource/en/reference_manual/dm00031020.pdf) | `adc` `dma` `exti` `gpio` `i2c` `tim`                    |
-//! | `stm32f429` | ARM® Cortex®-M4F r0p1 | [RM0090](https://www.st.com/resource/en/reference_manual/dm00031020.pdf) | `adc` `dma` `exti` `gpio` `i2c` `tim`                    |
-//! | `stm32f446` | ARM® Cortex®-M4F r0p1 | [RM0390](https://www.st.com/resource/en/reference_manual/dm00135183.pdf) | `adc` `dma` `exti` `gpio` `i2c` `tim`                    |
-//! | `stm32f469` | ARM® Cortex®-M4F r0p1 | [RM0386](https://www.st.com/resource/en/reference_manual/dm00127514.pdf) | `adc` `dma` `exti` `gpio` `i2c` `tim`                    |
-//! | `stm32l4x1` | ARM® Cortex®-M4F r0p1 | [RM0394](https://www.st.com/resource/en/reference_manual/dm00151940.pdf) | `dma` `exti` `gpio` `i2c` `rtc` `spi` `tim` `uart`       |
-//! | `stm32l4x2` | ARM® Cortex®-M4F r0p1 | [RM0394](https://www.st.com/resource/en/reference_manual/dm00151940.pdf) | `dma` `exti` `gpio` `i2c` `rtc` `spi` `tim` `uart`       |
-//! | `stm32l4x3` | ARM® Cortex®-M4F r0p1 | [RM0394](https://www.st.com/resource/en/reference_manual/dm00151940.pdf) | `dma` `exti` `gpio` `i2c` `rtc` `spi` `tim` `uart`       |
-//! | `stm32l4x5` | ARM® Cortex®-M4F r0p1 | [RM0351](https://www.st.com/resource/en/reference_manual/dm00083560.pdf) | `dma` `exti` `gpio` `i2c` `rtc` `spi` `tim` `uart`       |
-//! | `stm32l4x6` | ARM® Cortex®-M4F r0p1 | [RM0351](https://www.st.com/resource/en/reference_manual/dm00083560.pdf) | `dma` `exti` `gpio` `i2c` `rtc` `spi` `tim` `uart`       |
-//! | `stm32l4r5` | ARM® Cortex®-M4F r0p1 | [RM0432](https://www.st.com/resource/en/reference_manual/dm00310109.pdf) | `adc` `dma` `exti` `gpio` `i2c` `rtc` `spi` `tim` `uart` |
-//! | `stm32l4s5` | ARM® Cortex®-M4F r0p1 | [RM0432](https://www.st.com/resource/en/reference_manual/dm00310109.pdf) | `adc` `dma` `exti` `gpio` `i2c` `rtc` `spi` `tim` `uart` |
-//! | `stm32l4r7` | ARM® Cortex®-M4F r0p1 | [RM0432](https://www.st.com/resource/en/reference_manual/dm00310109.pdf) | `adc` `dma` `exti` `gpio` `i2c` `rtc` `spi` `tim` `uart` |
-//! | `stm32l4s7` | ARM® Cortex®-M4F r0p1 | [RM0432](https://www.st.com/resource/en/reference_manual/dm00310109.pdf) | `adc` `dma` `exti` `gpio` `i2c` `rtc` `spi` `tim` `uart` |
-//! | `stm32l4r9` | ARM® Cortex®-M4F r0p1 | [RM0432](https://www.st.com/resource/en/reference_manual/dm00310109.pdf) | `adc` `dma` `exti` `gpio` `i2c` `rtc` `spi` `tim` `uart` |
-//! | `stm32l4s9` | ARM® Cortex®-M4F r0p1 | [RM0432](https://www.st.com/resource/en/reference_manual/dm00310109.pdf) | `adc` `dma` `exti` `gpio` `i2c` `rtc` `spi` `tim` `uart` |
+//! | `stm32_mcu` | Core name             | Reference manual                                                         | Available features                                                                                                                                                                                             |
+//! |-------------|-----------------------|--------------------------------------------------------------------------|----------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------|
+//! | `stm32f100` | ARM® Cortex®-M3 r1p1  | [RM0041](https://www.st.com/resource/en/reference_manual/cd00246267.pdf) | `adc` `bkp` `cec` `crc` `dac` `dma` `exti` `fsmc` `gpio` `osc` `spi` `syscfg` `tim` `uart`                                                                                                                     |
+//! | `stm32f101` | ARM® Cortex®-M3 r1p1  | [RM0008](https://www.st.com/resource/en/reference_manual/cd00171190.pdf) | `adc` `bkp` `crc` `dma` `exti` `fsmc` `gpio` `osc` `spi` `syscfg` `tim` `uart`                                                                                                                                 |
+//! | `stm32f102` | ARM® Cortex®-M3 r1p1  | [RM0008](https://www.st.com/resource/en/reference_manual/cd00171190.pdf) | `adc` `bkp` `crc` `dma` `exti` `gpio` `osc` `spi` `syscfg` `tim` `uart` `usb`                                                                                                                                  |
+//! | `stm32f103` | ARM® Cortex®-M3 r1p1  | [RM0008](https://www.st.com/resource/en/reference_manual/cd00171190.pdf) | `adc` `bkp` `can` `crc` `dma` `exti` `fsmc` `gpio` `osc` `sdio` `spi` `syscfg` `tim` `uart` `usb`                                                                                                              |
+//! | `stm32f107` | ARM® Cortex®-M3 r1p1  | [RM0008](https://www.st.com/resource/en/reference_manual/cd00171190.pdf) | `adc` `bkp` `can` `crc` `dma` `eth` `exti` `gpio` `osc` `spi` `syscfg` `tim` `uart`                                                                                                                            |
+//! | `stm32f401` | ARM® Cortex®-M4F r0p1 | [RM0368](https://www.st.com/resource/en/reference_manual/dm00096844.pdf) | `adc` `crc` `dma` `exti` `gpio` `i2c` `osc` `otg_fs` `pwr` `rtc` `sdio` `spi` `syscfg` `tim` `uart`                                                                                                            |
+//! | `stm32f405` | ARM® Cortex®-M4F r0p1 | [RM0090](https://www.st.com/resource/en/reference_manual/dm00031020.pdf) | `adc` `can` `crc` `cryp` `dac` `dma` `exti` `gpio` `i2c` `osc` `otg_fs` `otg_hs` `pwr` `rtc` `sdio` `spi` `syscfg` `tim` `uart`                                                                                |
+//! | `stm32f407` | ARM® Cortex®-M4F r0p1 | [RM0090](https://www.st.com/resource/en/reference_manual/dm00031020.pdf) | `adc` `can` `crc` `cryp` `dac` `dma` `eth` `exti` `gpio` `i2c` `osc` `otg_fs` `otg_hs` `pwr` `rtc` `sdio` `spi` `syscfg` `tim` `uart`                                                                          |
+//! | `stm32f410` | ARM® Cortex®-M4F r0p1 | [RM0401](https://www.st.com/resource/en/reference_manual/dm00180366.pdf) | `adc` `crc` `dac` `dma` `exti` `gpio` `i2c` `osc` `pwr` `rtc` `spi` `syscfg` `tim` `uart`                                                                                                                      |
+//! | `stm32f411` | ARM® Cortex®-M4F r0p1 | [RM0383](https://www.st.com/resource/en/reference_manual/dm00119316.pdf) | `adc` `crc` `dma` `exti` `gpio` `i2c` `osc` `otg_fs` `pwr` `rtc` `sdio` `spi` `syscfg` `tim` `uart`                                                                                                            |
+//! | `stm32f412` | ARM® Cortex®-M4F r0p1 | [RM0402](https://www.st.com/resource/en/reference_manual/dm00180369.pdf) | `adc` `crc` `dma` `exti` `gpio` `i2c` `osc` `otg_fs` `pwr` `quadspi` `rtc` `sdio` `spi` `syscfg` `tim` `uart`                                                                                                  |
+//! | `stm32f413` | ARM® Cortex®-M4F r0p1 | [RM0430](https://www.st.com/resource/en/reference_manual/dm00305666.pdf) | `adc` `crc` `dac` `dma` `exti` `gpio` `i2c` `osc` `otg_fs` `pwr` `quadspi` `rtc` `sai` `sdio` `spi` `syscfg` `tim` `uart`                                                                                      |
+//! | `stm32f427` | ARM® Cortex®-M4F r0p1 | [RM0090](https://www.st.com/resource/en/reference_manual/dm00031020.pdf) | `adc` `can` `crc` `cryp` `dac` `dma` `eth` `exti` `fmc` `gpio` `i2c` `osc` `otg_fs` `otg_hs` `pwr` `rtc` `sai` `sdio` `spi` `syscfg` `tim` `uart`                                                              |
+//! | `stm32f429` | ARM® Cortex®-M4F r0p1 | [RM0090](https://www.st.com/resource/en/reference_manual/dm00031020.pdf) | `adc` `can` `crc` `cryp` `dac` `dma` `dma2d` `eth` `exti` `fmc` `gpio` `i2c` `ltdc` `osc` `otg_fs` `otg_hs` `pwr` `rtc` `sai` `sdio` `spi` `syscfg` `tim` `uart`                                               |
+//! | `stm32f446` | ARM® Cortex®-M4F r0p1 | [RM0390](https://www.st.com/resource/en/reference_manual/dm00135183.pdf) | `adc` `can` `cec` `crc` `dac` `dma` `exti` `fmc` `gpio` `i2c` `osc` `otg_fs` `otg_hs` `pwr` `quadspi` `rtc` `sai` `sdio` `spdifrx` `spi` `syscfg` `tim` `uart`                                                 |
+//! | `stm32f469` | ARM® Cortex®-M4F r0p1 | [RM0386](https://www.st.com/resource/en/reference_manual/dm00127514.pdf) | `adc` `can` `crc` `cryp` `dac` `dma` `dma2d` `dsi` `eth` `exti` `fmc` `gpio` `i2c` `ltdc` `osc` `otg_fs` `otg_hs` `pwr` `quadspi` `rtc` `sai` `sdio` `spi` `syscfg` `tim` `uart`                               |
+//! | `stm32l4x1` | ARM® Cortex®-M4F r0p1 | [RM0394](https://www.st.com/resource/en/reference_manual/dm00151940.pdf) | `adc` `comp` `crc` `crs` `dac` `dma` `exti` `fw` `gpio` `i2c` `osc` `pwr` `quadspi` `rtc` `sai` `sdmmc` `spi` `swpmi` `syscfg` `tim` `tsc` `uart` `vrefbuf`                                                    |
+//! | `stm32l4x2` | ARM® Cortex®-M4F r0p1 | [RM0394](https://www.st.com/resource/en/reference_manual/dm00151940.pdf) | `adc` `aes` `comp` `crc` `crs` `dac` `dma` `exti` `fw` `gpio` `i2c` `opamp` `osc` `pwr` `quadspi` `rtc` `sai` `sdmmc` `spi` `swpmi` `syscfg` `tim` `tsc` `uart` `usb` `vrefbuf`                                |
+//! | `stm32l4x3` | ARM® Cortex®-M4F r0p1 | [RM0394](https://www.st.com/resource/en/reference_manual/dm00151940.pdf) | `adc` `aes` `comp` `crc` `crs` `dac` `dma` `exti` `fw` `gpio` `i2c` `lcd` `osc` `pwr` `rtc` `sai` `sdmmc` `spi` `swpmi` `syscfg` `tim` `tsc` `uart` `usb` `vrefbuf`                                            |
+//! | `stm32l4x5` | ARM® Cortex®-M4F r0p1 | [RM0351](https://www.st.com/resource/en/reference_manual/dm00083560.pdf) | `adc` `comp` `crc` `dac` `dfsdm` `dma` `exti` `fmc` `fw` `gpio` `i2c` `opamp` `osc` `pwr` `quadspi` `rtc` `sai` `sdmmc` `spi` `swpmi` `syscfg` `tim` `tsc` `uart` `vrefbuf`                                    |
+//! | `stm32l4x6` | ARM® Cortex®-M4F r0p1 | [RM0351](https://www.st.com/resource/en/reference_manual/dm00083560.pdf) | `adc` `aes` `can` `comp` `crc` `crs` `dac` `dfsdm` `dma` `exti` `fmc` `fw` `gpio` `i2c` `lcd` `opamp` `osc` `otg_fs` `pwr` `quadspi` `rtc` `sai` `sdmmc` `spi` `swpmi` `syscfg` `tim` `tsc` `uart` `vrefbuf`   |
+//! | `stm32l4r5` | ARM® Cortex®-M4F r0p1 | [RM0432](https://www.st.com/resource/en/reference_manual/dm00310109.pdf) | `adc` `comp` `crc` `crs` `dac` `dfsdm` `dma` `exti` `fmc` `fw` `gpio` `i2c` `octospi` `osc` `otg_fs` `pwr` `rtc` `sai` `sdmmc` `spi` `syscfg` `tim` `tsc` `uart` `vrefbuf`                                     |
+//! | `stm32l4s5` | ARM® Cortex®-M4F r0p1 | [RM0432](https://www.st.com/resource/en/reference_manual/dm00310109.pdf) | `adc` `aes` `comp` `crc` `crs` `dac` `dfsdm` `dma` `exti` `fmc` `fw` `gpio` `i2c` `octospi` `osc` `otg_fs` `pwr` `rtc` `sai` `sdmmc` `spi` `syscfg` `tim` `tsc` `uart` `vrefbuf`                               |
+//! | `stm32l4r7` | ARM® Cortex®-M4F r0p1 | [RM0432](https://www.st.com/resource/en/reference_manual/dm00310109.pdf) | `adc` `comp` `crc` `crs` `dac` `dfsdm` `dma` `exti` `fmc` `fw` `gpio` `i2c` `octospi` `osc` `otg_fs` `pwr` `rtc` `sai` `sdmmc` `spi` `syscfg` `tim` `tsc` `uart` `vrefbuf`                                     |
+//! | `stm32l4s7` | ARM® Cortex®-M4F r0p1 | [RM0432](https://www.st.com/resource/en/reference_manual/dm00310109.pdf) | `adc` `aes` `comp` `crc` `crs` `dac` `dfsdm` `dma` `exti` `fmc` `fw` `gpio` `i2c` `octospi` `osc` `otg_fs` `pwr` `rtc` `sai` `sdmmc` `spi` `syscfg` `tim` `tsc` `uart` `vrefbuf`                               |
+//! | `stm32l4r9` | ARM® Cortex®-M4F r0p1 | [RM0432](https://www.st.com/resource/en/reference_manual/dm00310109.pdf) | `adc` `comp` `crc` `crs` `dac` `dfsdm` `dma` `dma2d` `dsi` `exti` `fmc` `fw` `gfxmmu` `gpio` `i2c` `ltdc` `octospi` `osc` `otg_fs` `pwr` `rtc` `sai` `sdmmc` `spi` `syscfg` `tim` `tsc` `uart` `vrefbuf`       |
+//! | `stm32l4s9` | ARM® Cortex®-M4F r0p1 | [RM0432](https://www.st.com/resource/en/reference_manual/dm00310109.pdf) | `adc` `aes` `comp` `crc` `crs` `dac` `dfsdm` `dma` `dma2d` `dsi` `exti` `fmc` `fw` `gfxmmu` `gpio` `i2c` `ltdc` `octospi` `osc` `otg_fs` `pwr` `rtc` `sai` `sdmmc` `spi` `syscfg` `tim` `tsc` `uart` `vrefbuf` |
 //!
 //! `stm32_mcu` config flag should be set at the application level according to
 //! this table.
@@ -1,4 +1,7 @@
 //! Analog-to-digital converters common registers.
+//!
+//! `CCR` selects the dual/triple mode and holds the internal channel
+//! switches. `CDR` is only present on parts with more than one ADC.
 
 #[allow(unused_imports)]
 use drone_core::periph;
@@ -15,6 +18,11 @@ use drone_core::periph;
     stm32_mcu = "stm32f429",
     stm32_mcu = "stm32f446",
     stm32_mcu = "stm32f469",
+    stm32_mcu = "stm32l4x1",
+    stm32_mcu = "stm32l4x2",
+    stm32_mcu = "stm32l4x3",
+    stm32_mcu = "stm32l4x5",
+    stm32_mcu = "stm32l4x6",
     stm32_mcu = "stm32l4r5",
     stm32_mcu = "stm32l4r7",
     stm32_mcu = "stm32l4r9",
@@ -50,6 +58,11 @@ periph::singular! {
             ADCRST;
         }
         #[cfg(any(
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
             stm32_mcu = "stm32l4r5",
             stm32_mcu = "stm32l4r7",
             stm32_mcu = "stm32l4r9",
@@ -60,6 +73,36 @@ periph::singular! {
         AHB2RSTR {
             ADCRST;
         }
+        #[cfg(any(
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6"
+        ))]
+        AHB2ENR {
+            ADCEN;
+        }
+        #[cfg(any(
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6"
+        ))]
+        AHB2SMENR {
+            ADCSMEN;
+        }
+        #[cfg(any(
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6"
+        ))]
+        CCIPR {
+            ADCSEL;
+        }
     }
     ADC_Common {
         #[cfg(any(
@@ -72,6 +115,8 @@ periph::singular! {
             stm32_mcu = "stm32f429",
             stm32_mcu = "stm32f446",
             stm32_mcu = "stm32f469",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
             stm32_mcu = "stm32l4r5",
             stm32_mcu = "stm32l4r7",
             stm32_mcu = "stm32l4r9",
@@ -82,8 +127,14 @@ periph::singular! {
         CSR;
         CCR;
         #[cfg(any(
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
             stm32_mcu = "stm32f446",
-            stm32_mcu = "stm32f469"
+            stm32_mcu = "stm32f469",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6"
         ))]
         CDR;
     }
@@ -1,4 +1,12 @@
 //! Analog-to-digital converters.
+//!
+//! STM32F1 converters keep the calibration bits (`CAL`, `RSTCAL`) in `CR2`.
+//! `CR2.TSVREFE` exists only on ADC1, and the dual mode fields
+//! `CR1.DUALMOD` and `DR.ADC2DATA` only on ADC1 of STM32F103 and STM32F107.
+//!
+//! On STM32L4 all ADC instances share one clock enable bit and one kernel
+//! clock selection, so these `RCC` fields are mapped to the common
+//! peripheral (`periph_adc_com!`) instead of the individual converters.
 
 #![feature(proc_macro_hygiene)]
 #![warn(missing_docs)]
@@ -19,10 +27,53 @@ periph! {
     pub struct AdcPeriph;
 
     RCC {
+        #[cfg(any(
+            stm32_mcu = "stm32f100",
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f102",
+            stm32_mcu = "stm32f103",
+            stm32_mcu = "stm32f107",
+            stm32_mcu = "stm32f401",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f410",
+            stm32_mcu = "stm32f411",
+            stm32_mcu = "stm32f412",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469",
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9"
+        ))]
         BUSENR {
             0x20 RwRegBitBand Shared;
             ADCEN { RwRwRegFieldBitBand }
         }
+        #[cfg(any(
+            stm32_mcu = "stm32f401",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f410",
+            stm32_mcu = "stm32f411",
+            stm32_mcu = "stm32f412",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469",
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9"
+        ))]
         BUSSMENR {
             0x20 RwRegBitBand Shared;
             ADCSMEN { RwRwRegFieldBitBand }
@@ -42,6 +93,11 @@ periph! {
     }
     ADC {
         #[cfg(any(
+            stm32_mcu = "stm32f100",
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f102",
+            stm32_mcu = "stm32f103",
+            stm32_mcu = "stm32f107",
             stm32_mcu = "stm32f401",
             stm32_mcu = "stm32f405",
             stm32_mcu = "stm32f407",
@@ -56,6 +112,19 @@ periph! {
         ))]
         SR {
             0x20 RwReg;
+            #[cfg(any(
+                stm32_mcu = "stm32f401",
+                stm32_mcu = "stm32f405",
+                stm32_mcu = "stm32f407",
+                stm32_mcu = "stm32f410",
+                stm32_mcu = "stm32f411",
+                stm32_mcu = "stm32f412",
+                stm32_mcu = "stm32f413",
+                stm32_mcu = "stm32f427",
+                stm32_mcu = "stm32f429",
+                stm32_mcu = "stm32f446",
+                stm32_mcu = "stm32f469"
+            ))]
             OVR { RwRwRegFieldBit }
             STRT { RwRwRegFieldBit }
             JSTRT { RwRwRegFieldBit }
@@ -64,6 +133,11 @@ periph! {
             AWD { RwRwRegFieldBit }
         }
         #[cfg(any(
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
             stm32_mcu = "stm32l4r5",
             stm32_mcu = "stm32l4r7",
             stm32_mcu = "stm32l4r9",
@@ -86,6 +160,11 @@ periph! {
             OVR { RwRwRegFieldBit }
         }
         #[cfg(any(
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
             stm32_mcu = "stm32l4r5",
             stm32_mcu = "stm32l4r7",
             stm32_mcu = "stm32l4r9",
@@ -108,6 +187,11 @@ periph! {
             OVRIE { RwRwRegFieldBit }
         }
         #[cfg(any(
+            stm32_mcu = "stm32f100",
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f102",
+            stm32_mcu = "stm32f103",
+            stm32_mcu = "stm32f107",
             stm32_mcu = "stm32f401",
             stm32_mcu = "stm32f405",
             stm32_mcu = "stm32f407",
@@ -122,7 +206,33 @@ periph! {
         ))]
         CR1 {
             0x20 RwReg;
+            #[cfg(any(
+                stm32_mcu = "stm32f401",
+                stm32_mcu = "stm32f405",
+                stm32_mcu = "stm32f407",
+                stm32_mcu = "stm32f410",
+                stm32_mcu = "stm32f411",
+                stm32_mcu = "stm32f412",
+                stm32_mcu = "stm32f413",
+                stm32_mcu = "stm32f427",
+                stm32_mcu = "stm32f429",
+                stm32_mcu = "stm32f446",
+                stm32_mcu = "stm32f469"
+            ))]
             OVRIE { RwRwRegFieldBit }
+            #[cfg(any(
+                stm32_mcu = "stm32f401",
+                stm32_mcu = "stm32f405",
+                stm32_mcu = "stm32f407",
+                stm32_mcu = "stm32f410",
+                stm32_mcu = "stm32f411",
+                stm32_mcu = "stm32f412",
+                stm32_mcu = "stm32f413",
+                stm32_mcu = "stm32f427",
+                stm32_mcu = "stm32f429",
+                stm32_mcu = "stm32f446",
+                stm32_mcu = "stm32f469"
+            ))]
             RES { RwRwRegFieldBits }
             AWDEN { RwRwRegFieldBit }
             JAWDEN { RwRwRegFieldBit }
@@ -136,8 +246,21 @@ periph! {
             AWDIE { RwRwRegFieldBit }
             EOCIE { RwRwRegFieldBit }
             AWDCH { RwRwRegFieldBits }
+            #[cfg(any(
+                stm32_mcu = "stm32f100",
+                stm32_mcu = "stm32f101",
+                stm32_mcu = "stm32f102",
+                stm32_mcu = "stm32f103",
+                stm32_mcu = "stm32f107"
+            ))]
+            DUALMOD { RwRwRegFieldBits Option }
         }
         #[cfg(any(
+            stm32_mcu = "stm32f100",
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f102",
+            stm32_mcu = "stm32f103",
+            stm32_mcu = "stm32f107",
             stm32_mcu = "stm32f401",
             stm32_mcu = "stm32f405",
             stm32_mcu = "stm32f407",
@@ -153,19 +276,116 @@ periph! {
         CR2 {
             0x20 RwReg;
             SWSTART { RwRwRegFieldBit }
+            #[cfg(any(
+                stm32_mcu = "stm32f401",
+                stm32_mcu = "stm32f405",
+                stm32_mcu = "stm32f407",
+                stm32_mcu = "stm32f410",
+                stm32_mcu = "stm32f411",
+                stm32_mcu = "stm32f412",
+                stm32_mcu = "stm32f413",
+                stm32_mcu = "stm32f427",
+                stm32_mcu = "stm32f429",
+                stm32_mcu = "stm32f446",
+                stm32_mcu = "stm32f469"
+            ))]
             EXTEN { RwRwRegFieldBits }
             EXTSEL { RwRwRegFieldBits }
             JSWSTART { RwRwRegFieldBit }
+            #[cfg(any(
+                stm32_mcu = "stm32f401",
+                stm32_mcu = "stm32f405",
+                stm32_mcu = "stm32f407",
+                stm32_mcu = "stm32f410",
+                stm32_mcu = "stm32f411",
+                stm32_mcu = "stm32f412",
+                stm32_mcu = "stm32f413",
+                stm32_mcu = "stm32f427",
+                stm32_mcu = "stm32f429",
+                stm32_mcu = "stm32f446",
+                stm32_mcu = "stm32f469"
+            ))]
             JEXTEN { RwRwRegFieldBits }
             JEXTSEL { RwRwRegFieldBits }
             ALIGN { RwRwRegFieldBit }
+            #[cfg(any(
+                stm32_mcu = "stm32f401",
+                stm32_mcu = "stm32f405",
+                stm32_mcu = "stm32f407",
+                stm32_mcu = "stm32f410",
+                stm32_mcu = "stm32f411",
+                stm32_mcu = "stm32f412",
+                stm32_mcu = "stm32f413",
+                stm32_mcu = "stm32f427",
+                stm32_mcu = "stm32f429",
+                stm32_mcu = "stm32f446",
+                stm32_mcu = "stm32f469"
+            ))]
             EOCS { RwRwRegFieldBit }
+            #[cfg(any(
+                stm32_mcu = "stm32f401",
+                stm32_mcu = "stm32f405",
+                stm32_mcu = "stm32f407",
+                stm32_mcu = "stm32f410",
+                stm32_mcu = "stm32f411",
+                stm32_mcu = "stm32f412",
+                stm32_mcu = "stm32f413",
+                stm32_mcu = "stm32f427",
+                stm32_mcu = "stm32f429",
+                stm32_mcu = "stm32f446",
+                stm32_mcu = "stm32f469"
+            ))]
             DDS { RwRwRegFieldBit }
             DMA { RwRwRegFieldBit }
             CONT { RwRwRegFieldBit }
             ADON { RwRwRegFieldBit }
+            #[cfg(any(
+                stm32_mcu = "stm32f100",
+                stm32_mcu = "stm32f101",
+                stm32_mcu = "stm32f102",
+                stm32_mcu = "stm32f103",
+                stm32_mcu = "stm32f107"
+            ))]
+            TSVREFE { RwRwRegFieldBit Option }
+            #[cfg(any(
+                stm32_mcu = "stm32f100",
+                stm32_mcu = "stm32f101",
+                stm32_mcu = "stm32f102",
+                stm32_mcu = "stm32f103",
+                stm32_mcu = "stm32f107"
+            ))]
+            EXTTRIG { RwRwRegFieldBit }
+            #[cfg(any(
+                stm32_mcu = "stm32f100",
+                stm32_mcu = "stm32f101",
+                stm32_mcu = "stm32f102",
+                stm32_mcu = "stm32f103",
+                stm32_mcu = "stm32f107"
+            ))]
+            JEXTTRIG { RwRwRegFieldBit }
+            #[cfg(any(
+                stm32_mcu = "stm32f100",
+                stm32_mcu = "stm32f101",
+                stm32_mcu = "stm32f102",
+                stm32_mcu = "stm32f103",
+                stm32_mcu = "stm32f107"
+            ))]
+            RSTCAL { RwRwRegFieldBit }
+            #[cfg(any(
+                stm32_mcu = "stm32f100",
+                stm32_mcu = "stm32f101",
+                stm32_mcu = "stm32f102",
+                stm32_mcu = "stm32f103",
+                stm32_mcu = "stm32f107"
+            ))]
+            CAL { RwRwRegFieldBit }
         }
         #[cfg(any(
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
             stm32_mcu = "stm32l4r5",
             stm32_mcu = "stm32l4r7",
             stm32_mcu = "stm32l4r9",
@@ -187,6 +407,11 @@ periph! {
             JADSTP { RwRwRegFieldBit }
         }
         #[cfg(any(
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
             stm32_mcu = "stm32l4r5",
             stm32_mcu = "stm32l4r7",
             stm32_mcu = "stm32l4r9",
@@ -217,6 +442,11 @@ periph! {
             RES { RwRwRegFieldBits }
         }
         #[cfg(any(
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
             stm32_mcu = "stm32l4r5",
             stm32_mcu = "stm32l4r7",
             stm32_mcu = "stm32l4r9",
@@ -234,6 +464,11 @@ periph! {
             TROVS { RwRwRegFieldBit }
         }
         #[cfg(any(
+            stm32_mcu = "stm32f100",
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f102",
+            stm32_mcu = "stm32f103",
+            stm32_mcu = "stm32f107",
             stm32_mcu = "stm32f401",
             stm32_mcu = "stm32f405",
             stm32_mcu = "stm32f407",
@@ -248,6 +483,19 @@ periph! {
         ))]
         SMPR1 {
             0x20 RwReg;
+            #[cfg(any(
+                stm32_mcu = "stm32f401",
+                stm32_mcu = "stm32f405",
+                stm32_mcu = "stm32f407",
+                stm32_mcu = "stm32f410",
+                stm32_mcu = "stm32f411",
+                stm32_mcu = "stm32f412",
+                stm32_mcu = "stm32f413",
+                stm32_mcu = "stm32f427",
+                stm32_mcu = "stm32f429",
+                stm32_mcu = "stm32f446",
+                stm32_mcu = "stm32f469"
+            ))]
             SMP18 { RwRwRegFieldBits }
             SMP17 { RwRwRegFieldBits }
             SMP16 { RwRwRegFieldBits }
@@ -259,6 +507,11 @@ periph! {
             SMP10 { RwRwRegFieldBits }
         }
         #[cfg(any(
+            stm32_mcu = "stm32f100",
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f102",
+            stm32_mcu = "stm32f103",
+            stm32_mcu = "stm32f107",
             stm32_mcu = "stm32f401",
             stm32_mcu = "stm32f405",
             stm32_mcu = "stm32f407",
@@ -285,6 +538,11 @@ periph! {
             SMP0 { RwRwRegFieldBits }
         }
         #[cfg(any(
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
             stm32_mcu = "stm32l4r5",
             stm32_mcu = "stm32l4r7",
             stm32_mcu = "stm32l4r9",
@@ -306,6 +564,11 @@ periph! {
             SMP9 { RwRwRegFieldBits }
         }
         #[cfg(any(
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
             stm32_mcu = "stm32l4r5",
             stm32_mcu = "stm32l4r7",
             stm32_mcu = "stm32l4r9",
@@ -326,6 +589,11 @@ periph! {
             SMP18 { RwRwRegFieldBits }
         }
         #[cfg(any(
+            stm32_mcu = "stm32f100",
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f102",
+            stm32_mcu = "stm32f103",
+            stm32_mcu = "stm32f107",
             stm32_mcu = "stm32f401",
             stm32_mcu = "stm32f405",
             stm32_mcu = "stm32f407",
@@ -343,6 +611,11 @@ periph! {
             JOFFSET1 { RwRwRegFieldBits }
         }
         #[cfg(any(
+            stm32_mcu = "stm32f100",
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f102",
+            stm32_mcu = "stm32f103",
+            stm32_mcu = "stm32f107",
             stm32_mcu = "stm32f401",
             stm32_mcu = "stm32f405",
             stm32_mcu = "stm32f407",
@@ -360,6 +633,11 @@ periph! {
             JOFFSET2 { RwRwRegFieldBits }
         }
         #[cfg(any(
+            stm32_mcu = "stm32f100",
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f102",
+            stm32_mcu = "stm32f103",
+            stm32_mcu = "stm32f107",
             stm32_mcu = "stm32f401",
             stm32_mcu = "stm32f405",
             stm32_mcu = "stm32f407",
@@ -377,6 +655,11 @@ periph! {
             JOFFSET3 { RwRwRegFieldBits }
         }
         #[cfg(any(
+            stm32_mcu = "stm32f100",
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f102",
+            stm32_mcu = "stm32f103",
+            stm32_mcu = "stm32f107",
             stm32_mcu = "stm32f401",
             stm32_mcu = "stm32f405",
             stm32_mcu = "stm32f407",
@@ -394,6 +677,11 @@ periph! {
             JOFFSET4 { RwRwRegFieldBits }
         }
         #[cfg(any(
+            stm32_mcu = "stm32f100",
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f102",
+            stm32_mcu = "stm32f103",
+            stm32_mcu = "stm32f107",
             stm32_mcu = "stm32f401",
             stm32_mcu = "stm32f405",
             stm32_mcu = "stm32f407",
@@ -411,6 +699,11 @@ periph! {
             HT { RwRwRegFieldBits }
         }
         #[cfg(any(
+            stm32_mcu = "stm32f100",
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f102",
+            stm32_mcu = "stm32f103",
+            stm32_mcu = "stm32f107",
             stm32_mcu = "stm32f401",
             stm32_mcu = "stm32f405",
             stm32_mcu = "stm32f407",
@@ -428,6 +721,11 @@ periph! {
             LT { RwRwRegFieldBits }
         }
         #[cfg(any(
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
             stm32_mcu = "stm32l4r5",
             stm32_mcu = "stm32l4r7",
             stm32_mcu = "stm32l4r9",
@@ -441,6 +739,11 @@ periph! {
             LT1 { RwRwRegFieldBits }
         }
         #[cfg(any(
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
             stm32_mcu = "stm32l4r5",
             stm32_mcu = "stm32l4r7",
             stm32_mcu = "stm32l4r9",
@@ -454,6 +757,11 @@ periph! {
             LT2 { RwRwRegFieldBits }
         }
         #[cfg(any(
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
             stm32_mcu = "stm32l4r5",
             stm32_mcu = "stm32l4r7",
             stm32_mcu = "stm32l4r9",
@@ -467,6 +775,11 @@ periph! {
             LT3 { RwRwRegFieldBits }
         }
         #[cfg(any(
+            stm32_mcu = "stm32f100",
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f102",
+            stm32_mcu = "stm32f103",
+            stm32_mcu = "stm32f107",
             stm32_mcu = "stm32f401",
             stm32_mcu = "stm32f405",
             stm32_mcu = "stm32f407",
@@ -488,6 +801,11 @@ periph! {
             SQ13 { RwRwRegFieldBits }
         }
         #[cfg(any(
+            stm32_mcu = "stm32f100",
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f102",
+            stm32_mcu = "stm32f103",
+            stm32_mcu = "stm32f107",
             stm32_mcu = "stm32f401",
             stm32_mcu = "stm32f405",
             stm32_mcu = "stm32f407",
@@ -510,6 +828,11 @@ periph! {
             SQ7 { RwRwRegFieldBits }
         }
         #[cfg(any(
+            stm32_mcu = "stm32f100",
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f102",
+            stm32_mcu = "stm32f103",
+            stm32_mcu = "stm32f107",
             stm32_mcu = "stm32f401",
             stm32_mcu = "stm32f405",
             stm32_mcu = "stm32f407",
@@ -532,6 +855,11 @@ periph! {
             SQ1 { RwRwRegFieldBits }
         }
         #[cfg(any(
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
             stm32_mcu = "stm32l4r5",
             stm32_mcu = "stm32l4r7",
             stm32_mcu = "stm32l4r9",
@@ -548,6 +876,11 @@ periph! {
             SQ4 { RwRwRegFieldBits }
         }
         #[cfg(any(
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
             stm32_mcu = "stm32l4r5",
             stm32_mcu = "stm32l4r7",
             stm32_mcu = "stm32l4r9",
@@ -564,6 +897,11 @@ periph! {
             SQ9 { RwRwRegFieldBits }
         }
         #[cfg(any(
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
             stm32_mcu = "stm32l4r5",
             stm32_mcu = "stm32l4r7",
             stm32_mcu = "stm32l4r9",
@@ -580,6 +918,11 @@ periph! {
             SQ14 { RwRwRegFieldBits }
         }
         #[cfg(any(
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
             stm32_mcu = "stm32l4r5",
             stm32_mcu = "stm32l4r7",
             stm32_mcu = "stm32l4r9",
@@ -595,10 +938,23 @@ periph! {
         DR {
             0x20 RoReg;
             RDATA { RoRoRegFieldBits }
+            #[cfg(any(
+                stm32_mcu = "stm32f100",
+                stm32_mcu = "stm32f101",
+                stm32_mcu = "stm32f102",
+                stm32_mcu = "stm32f103",
+                stm32_mcu = "stm32f107"
+            ))]
+            ADC2DATA { RoRoRegFieldBits Option }
         }
         JSQR {
             0x20 RwReg;
             #[cfg(any(
+                stm32_mcu = "stm32l4x1",
+                stm32_mcu = "stm32l4x2",
+                stm32_mcu = "stm32l4x3",
+                stm32_mcu = "stm32l4x5",
+                stm32_mcu = "stm32l4x6",
                 stm32_mcu = "stm32l4r5",
                 stm32_mcu = "stm32l4r7",
                 stm32_mcu = "stm32l4r9",
@@ -608,6 +964,11 @@ periph! {
             ))]
             JEXTEN { RwRwRegFieldBits }
             #[cfg(any(
+                stm32_mcu = "stm32l4x1",
+                stm32_mcu = "stm32l4x2",
+                stm32_mcu = "stm32l4x3",
+                stm32_mcu = "stm32l4x5",
+                stm32_mcu = "stm32l4x6",
                 stm32_mcu = "stm32l4r5",
                 stm32_mcu = "stm32l4r7",
                 stm32_mcu = "stm32l4r9",
@@ -623,6 +984,11 @@ periph! {
             JSQ4 { RwRwRegFieldBits }
         }
         #[cfg(any(
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
             stm32_mcu = "stm32l4r5",
             stm32_mcu = "stm32l4r7",
             stm32_mcu = "stm32l4r9",
@@ -637,6 +1003,11 @@ periph! {
             OFFSET1 { RwRwRegFieldBits }
         }
         #[cfg(any(
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
             stm32_mcu = "stm32l4r5",
             stm32_mcu = "stm32l4r7",
             stm32_mcu = "stm32l4r9",
@@ -651,6 +1022,11 @@ periph! {
             OFFSET2 { RwRwRegFieldBits }
         }
         #[cfg(any(
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
             stm32_mcu = "stm32l4r5",
             stm32_mcu = "stm32l4r7",
             stm32_mcu = "stm32l4r9",
@@ -665,6 +1041,11 @@ periph! {
             OFFSET3 { RwRwRegFieldBits }
         }
         #[cfg(any(
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
             stm32_mcu = "stm32l4r5",
             stm32_mcu = "stm32l4r7",
             stm32_mcu = "stm32l4r9",
@@ -695,6 +1076,11 @@ periph! {
             JDATA { RoRoRegFieldBits }
         }
         #[cfg(any(
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
             stm32_mcu = "stm32l4r5",
             stm32_mcu = "stm32l4r7",
             stm32_mcu = "stm32l4r9",
@@ -707,6 +1093,11 @@ periph! {
             AWD2CH { RwRwRegFieldBits }
         }
         #[cfg(any(
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
             stm32_mcu = "stm32l4r5",
             stm32_mcu = "stm32l4r7",
             stm32_mcu = "stm32l4r9",
@@ -719,6 +1110,11 @@ periph! {
             AWD3CH { RwRwRegFieldBits }
         }
         #[cfg(any(
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
             stm32_mcu = "stm32l4r5",
             stm32_mcu = "stm32l4r7",
             stm32_mcu = "stm32l4r9",
@@ -732,6 +1128,11 @@ periph! {
             DIFSEL_16_18 { RoRwRegFieldBits }
         }
         #[cfg(any(
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
             stm32_mcu = "stm32l4r5",
             stm32_mcu = "stm32l4r7",
             stm32_mcu = "stm32l4r9",
@@ -760,6 +1161,9 @@ macro_rules! map_adc {
         $adcen:ident,
         $adcsmen:ident,
         $rdata:ident,
+        ($($dualmod:ident)?),
+        ($($tsvrefe:ident)?),
+        ($($adc2data:ident)?),
     ) => {
         periph::map! {
             #[doc = $adc_macro_doc]
@@ -774,10 +1178,53 @@ macro_rules! map_adc {
             crate;
 
             RCC {
+                #[cfg(any(
+                    stm32_mcu = "stm32f100",
+                    stm32_mcu = "stm32f101",
+                    stm32_mcu = "stm32f102",
+                    stm32_mcu = "stm32f103",
+                    stm32_mcu = "stm32f107",
+                    stm32_mcu = "stm32f401",
+                    stm32_mcu = "stm32f405",
+                    stm32_mcu = "stm32f407",
+                    stm32_mcu = "stm32f410",
+                    stm32_mcu = "stm32f411",
+                    stm32_mcu = "stm32f412",
+                    stm32_mcu = "stm32f413",
+                    stm32_mcu = "stm32f427",
+                    stm32_mcu = "stm32f429",
+                    stm32_mcu = "stm32f446",
+                    stm32_mcu = "stm32f469",
+                    stm32_mcu = "stm32l4r5",
+                    stm32_mcu = "stm32l4r7",
+                    stm32_mcu = "stm32l4r9",
+                    stm32_mcu = "stm32l4s5",
+                    stm32_mcu = "stm32l4s7",
+                    stm32_mcu = "stm32l4s9"
+                ))]
                 BUSENR {
                     $busenr Shared;
                     ADCEN { $adcen }
                 }
+                #[cfg(any(
+                    stm32_mcu = "stm32f401",
+                    stm32_mcu = "stm32f405",
+                    stm32_mcu = "stm32f407",
+                    stm32_mcu = "stm32f410",
+                    stm32_mcu = "stm32f411",
+                    stm32_mcu = "stm32f412",
+                    stm32_mcu = "stm32f413",
+                    stm32_mcu = "stm32f427",
+                    stm32_mcu = "stm32f429",
+                    stm32_mcu = "stm32f446",
+                    stm32_mcu = "stm32f469",
+                    stm32_mcu = "stm32l4r5",
+                    stm32_mcu = "stm32l4r7",
+                    stm32_mcu = "stm32l4r9",
+                    stm32_mcu = "stm32l4s5",
+                    stm32_mcu = "stm32l4s7",
+                    stm32_mcu = "stm32l4s9"
+                ))]
                 BUSSMENR {
                     $bussmenr Shared;
                     ADCSMEN { $adcsmen }
@@ -798,6 +1245,11 @@ macro_rules! map_adc {
             ADC {
                 $adc;
                 #[cfg(any(
+                    stm32_mcu = "stm32f100",
+                    stm32_mcu = "stm32f101",
+                    stm32_mcu = "stm32f102",
+                    stm32_mcu = "stm32f103",
+                    stm32_mcu = "stm32f107",
                     stm32_mcu = "stm32f401",
                     stm32_mcu = "stm32f405",
                     stm32_mcu = "stm32f407",
@@ -812,6 +1264,19 @@ macro_rules! map_adc {
                 ))]
                 SR {
                     SR;
+                    #[cfg(any(
+                        stm32_mcu = "stm32f401",
+                        stm32_mcu = "stm32f405",
+                        stm32_mcu = "stm32f407",
+                        stm32_mcu = "stm32f410",
+                        stm32_mcu = "stm32f411",
+                        stm32_mcu = "stm32f412",
+                        stm32_mcu = "stm32f413",
+                        stm32_mcu = "stm32f427",
+                        stm32_mcu = "stm32f429",
+                        stm32_mcu = "stm32f446",
+                        stm32_mcu = "stm32f469"
+                    ))]
                     OVR { OVR }
                     STRT { STRT }
                     JSTRT { JSTRT }
@@ -820,6 +1285,11 @@ macro_rules! map_adc {
                     AWD { AWD }
                 }
                 #[cfg(any(
+                    stm32_mcu = "stm32l4x1",
+                    stm32_mcu = "stm32l4x2",
+                    stm32_mcu = "stm32l4x3",
+                    stm32_mcu = "stm32l4x5",
+                    stm32_mcu = "stm32l4x6",
                     stm32_mcu = "stm32l4r5",
                     stm32_mcu = "stm32l4r7",
                     stm32_mcu = "stm32l4r9",
@@ -842,6 +1312,11 @@ macro_rules! map_adc {
                     OVR { OVR }
                 }
                 #[cfg(any(
+                    stm32_mcu = "stm32l4x1",
+                    stm32_mcu = "stm32l4x2",
+                    stm32_mcu = "stm32l4x3",
+                    stm32_mcu = "stm32l4x5",
+                    stm32_mcu = "stm32l4x6",
                     stm32_mcu = "stm32l4r5",
                     stm32_mcu = "stm32l4r7",
                     stm32_mcu = "stm32l4r9",
@@ -864,6 +1339,11 @@ macro_rules! map_adc {
                     OVRIE { OVRIE }
                 }
                 #[cfg(any(
+                    stm32_mcu = "stm32f100",
+                    stm32_mcu = "stm32f101",
+                    stm32_mcu = "stm32f102",
+                    stm32_mcu = "stm32f103",
+                    stm32_mcu = "stm32f107",
                     stm32_mcu = "stm32f401",
                     stm32_mcu = "stm32f405",
                     stm32_mcu = "stm32f407",
@@ -878,7 +1358,33 @@ macro_rules! map_adc {
                 ))]
                 CR1 {
                     CR1;
+                    #[cfg(any(
+                        stm32_mcu = "stm32f401",
+                        stm32_mcu = "stm32f405",
+                        stm32_mcu = "stm32f407",
+                        stm32_mcu = "stm32f410",
+                        stm32_mcu = "stm32f411",
+                        stm32_mcu = "stm32f412",
+                        stm32_mcu = "stm32f413",
+                        stm32_mcu = "stm32f427",
+                        stm32_mcu = "stm32f429",
+                        stm32_mcu = "stm32f446",
+                        stm32_mcu = "stm32f469"
+                    ))]
                     OVRIE { OVRIE }
+                    #[cfg(any(
+                        stm32_mcu = "stm32f401",
+                        stm32_mcu = "stm32f405",
+                        stm32_mcu = "stm32f407",
+                        stm32_mcu = "stm32f410",
+                        stm32_mcu = "stm32f411",
+                        stm32_mcu = "stm32f412",
+                        stm32_mcu = "stm32f413",
+                        stm32_mcu = "stm32f427",
+                        stm32_mcu = "stm32f429",
+                        stm32_mcu = "stm32f446",
+                        stm32_mcu = "stm32f469"
+                    ))]
                     RES { RES }
                     AWDEN { AWDEN }
                     JAWDEN { JAWDEN }
@@ -892,8 +1398,21 @@ macro_rules! map_adc {
                     AWDIE { AWDIE }
                     EOCIE { EOCIE }
                     AWDCH { AWDCH }
+                    #[cfg(any(
+                        stm32_mcu = "stm32f100",
+                        stm32_mcu = "stm32f101",
+                        stm32_mcu = "stm32f102",
+                        stm32_mcu = "stm32f103",
+                        stm32_mcu = "stm32f107"
+                    ))]
+                    DUALMOD { $($dualmod Option)* }
                 }
                 #[cfg(any(
+                    stm32_mcu = "stm32f100",
+                    stm32_mcu = "stm32f101",
+                    stm32_mcu = "stm32f102",
+                    stm32_mcu = "stm32f103",
+                    stm32_mcu = "stm32f107",
                     stm32_mcu = "stm32f401",
                     stm32_mcu = "stm32f405",
                     stm32_mcu = "stm32f407",
@@ -909,19 +1428,116 @@ macro_rules! map_adc {
                 CR2 {
                     CR2;
                     SWSTART { SWSTART }
+                    #[cfg(any(
+                        stm32_mcu = "stm32f401",
+                        stm32_mcu = "stm32f405",
+                        stm32_mcu = "stm32f407",
+                        stm32_mcu = "stm32f410",
+                        stm32_mcu = "stm32f411",
+                        stm32_mcu = "stm32f412",
+                        stm32_mcu = "stm32f413",
+                        stm32_mcu = "stm32f427",
+                        stm32_mcu = "stm32f429",
+                        stm32_mcu = "stm32f446",
+                        stm32_mcu = "stm32f469"
+                    ))]
                     EXTEN { EXTEN }
                     EXTSEL { EXTSEL }
                     JSWSTART { JSWSTART }
+                    #[cfg(any(
+                        stm32_mcu = "stm32f401",
+                        stm32_mcu = "stm32f405",
+                        stm32_mcu = "stm32f407",
+                        stm32_mcu = "stm32f410",
+                        stm32_mcu = "stm32f411",
+                        stm32_mcu = "stm32f412",
+                        stm32_mcu = "stm32f413",
+                        stm32_mcu = "stm32f427",
+                        stm32_mcu = "stm32f429",
+                        stm32_mcu = "stm32f446",
+                        stm32_mcu = "stm32f469"
+                    ))]
                     JEXTEN { JEXTEN }
                     JEXTSEL { JEXTSEL }
                     ALIGN { ALIGN }
+                    #[cfg(any(
+                        stm32_mcu = "stm32f401",
+                        stm32_mcu = "stm32f405",
+                        stm32_mcu = "stm32f407",
+                        stm32_mcu = "stm32f410",
+                        stm32_mcu = "stm32f411",
+                        stm32_mcu = "stm32f412",
+                        stm32_mcu = "stm32f413",
+                        stm32_mcu = "stm32f427",
+                        stm32_mcu = "stm32f429",
+                        stm32_mcu = "stm32f446",
+                        stm32_mcu = "stm32f469"
+                    ))]
                     EOCS { EOCS }
+                    #[cfg(any(
+                        stm32_mcu = "stm32f401",
+                        stm32_mcu = "stm32f405",
+                        stm32_mcu = "stm32f407",
+                        stm32_mcu = "stm32f410",
+                        stm32_mcu = "stm32f411",
+                        stm32_mcu = "stm32f412",
+                        stm32_mcu = "stm32f413",
+                        stm32_mcu = "stm32f427",
+                        stm32_mcu = "stm32f429",
+                        stm32_mcu = "stm32f446",
+                        stm32_mcu = "stm32f469"
+                    ))]
                     DDS { DDS }
                     DMA { DMA }
                     CONT { CONT }
                     ADON { ADON }
+                    #[cfg(any(
+                        stm32_mcu = "stm32f100",
+                        stm32_mcu = "stm32f101",
+                        stm32_mcu = "stm32f102",
+                        stm32_mcu = "stm32f103",
+                        stm32_mcu = "stm32f107"
+                    ))]
+                    TSVREFE { $($tsvrefe Option)* }
+                    #[cfg(any(
+                        stm32_mcu = "stm32f100",
+                        stm32_mcu = "stm32f101",
+                        stm32_mcu = "stm32f102",
+                        stm32_mcu = "stm32f103",
+                        stm32_mcu = "stm32f107"
+                    ))]
+                    EXTTRIG { EXTTRIG }
+                    #[cfg(any(
+                        stm32_mcu = "stm32f100",
+                        stm32_mcu = "stm32f101",
+                        stm32_mcu = "stm32f102",
+                        stm32_mcu = "stm32f103",
+                        stm32_mcu = "stm32f107"
+                    ))]
+                    JEXTTRIG { JEXTTRIG }
+                    #[cfg(any(
+                        stm32_mcu = "stm32f100",
+                        stm32_mcu = "stm32f101",
+                        stm32_mcu = "stm32f102",
+                        stm32_mcu = "stm32f103",
+                        stm32_mcu = "stm32f107"
+                    ))]
+                    RSTCAL { RSTCAL }
+                    #[cfg(any(
+                        stm32_mcu = "stm32f100",
+                        stm32_mcu = "stm32f101",
+                        stm32_mcu = "stm32f102",
+                        stm32_mcu = "stm32f103",
+                        stm32_mcu = "stm32f107"
+                    ))]
+                    CAL { CAL }
                 }
                 #[cfg(any(
+                    stm32_mcu = "stm32l4x1",
+                    stm32_mcu = "stm32l4x2",
+                    stm32_mcu = "stm32l4x3",
+                    stm32_mcu = "stm32l4x5",
+                    stm32_mcu = "stm32l4x6",
                     stm32_mcu = "stm32l4r5",
                     stm32_mcu = "stm32l4r7",
                     stm32_mcu = "stm32l4r9",
@@ -943,6 +1559,11 @@ macro_rules! map_adc {
                     JADSTP { JADSTP }
                 }
                 #[cfg(any(
+                    stm32_mcu = "stm32l4x1",
+                    stm32_mcu = "stm32l4x2",
+                    stm32_mcu = "stm32l4x3",
+                    stm32_mcu = "stm32l4x5",
+                    stm32_mcu = "stm32l4x6",
                     stm32_mcu = "stm32l4r5",
                     stm32_mcu = "stm32l4r7",
                     stm32_mcu = "stm32l4r9",
@@ -973,6 +1594,11 @@ macro_rules! map_adc {
                     RES { RES }
                 }
                 #[cfg(any(
+                    stm32_mcu = "stm32l4x1",
+                    stm32_mcu = "stm32l4x2",
+                    stm32_mcu = "stm32l4x3",
+                    stm32_mcu = "stm32l4x5",
+                    stm32_mcu = "stm32l4x6",
                     stm32_mcu = "stm32l4r5",
                     stm32_mcu = "stm32l4r7",
                     stm32_mcu = "stm32l4r9",
@@ -990,6 +1616,11 @@ macro_rules! map_adc {
                     TROVS { TROVS }
                 }
                 #[cfg(any(
+                    stm32_mcu = "stm32f100",
+                    stm32_mcu = "stm32f101",
+                    stm32_mcu = "stm32f102",
+                    stm32_mcu = "stm32f103",
+                    stm32_mcu = "stm32f107",
                     stm32_mcu = "stm32f401",
                     stm32_mcu = "stm32f405",
                     stm32_mcu = "stm32f407",
@@ -1004,6 +1635,19 @@ macro_rules! map_adc {
                 ))]
                 SMPR1 {
                     SMPR1;
+                    #[cfg(any(
+                        stm32_mcu = "stm32f401",
+                        stm32_mcu = "stm32f405",
+                        stm32_mcu = "stm32f407",
+                        stm32_mcu = "stm32f410",
+                        stm32_mcu = "stm32f411",
+                        stm32_mcu = "stm32f412",
+                        stm32_mcu = "stm32f413",
+                        stm32_mcu = "stm32f427",
+                        stm32_mcu = "stm32f429",
+                        stm32_mcu = "stm32f446",
+                        stm32_mcu = "stm32f469"
+                    ))]
                     SMP18 { SMP18 }
                     SMP17 { SMP17 }
                     SMP16 { SMP16 }
@@ -1015,6 +1659,11 @@ macro_rules! map_adc {
                     SMP10 { SMP10 }
                 }
                 #[cfg(any(
+                    stm32_mcu = "stm32f100",
+                    stm32_mcu = "stm32f101",
+                    stm32_mcu = "stm32f102",
+                    stm32_mcu = "stm32f103",
+                    stm32_mcu = "stm32f107",
                     stm32_mcu = "stm32f401",
                     stm32_mcu = "stm32f405",
                     stm32_mcu = "stm32f407",
@@ -1041,6 +1690,11 @@ macro_rules! map_adc {
                     SMP0 { SMP0 }
                 }
                 #[cfg(any(
+                    stm32_mcu = "stm32l4x1",
+                    stm32_mcu = "stm32l4x2",
+                    stm32_mcu = "stm32l4x3",
+                    stm32_mcu = "stm32l4x5",
+                    stm32_mcu = "stm32l4x6",
                     stm32_mcu = "stm32l4r5",
                     stm32_mcu = "stm32l4r7",
                     stm32_mcu = "stm32l4r9",
@@ -1062,6 +1716,11 @@ macro_rules! map_adc {
                     SMP9 { SMP9 }
                 }
                 #[cfg(any(
+                    stm32_mcu = "stm32l4x1",
+                    stm32_mcu = "stm32l4x2",
+                    stm32_mcu = "stm32l4x3",
+                    stm32_mcu = "stm32l4x5",
+                    stm32_mcu = "stm32l4x6",
                     stm32_mcu = "stm32l4r5",
                     stm32_mcu = "stm32l4r7",
                     stm32_mcu = "stm32l4r9",
@@ -1082,6 +1741,11 @@ macro_rules! map_adc {
                     SMP18 { SMP18 }
                 }
                 #[cfg(any(
+                    stm32_mcu = "stm32f100",
+                    stm32_mcu = "stm32f101",
+                    stm32_mcu = "stm32f102",
+                    stm32_mcu = "stm32f103",
+                    stm32_mcu = "stm32f107",
                     stm32_mcu = "stm32f401",
                     stm32_mcu = "stm32f405",
                     stm32_mcu = "stm32f407",
@@ -1099,6 +1763,11 @@ macro_rules! map_adc {
                     JOFFSET1 { JOFFSET1 }
                 }
                 #[cfg(any(
+                    stm32_mcu = "stm32f100",
+                    stm32_mcu = "stm32f101",
+                    stm32_mcu = "stm32f102",
+                    stm32_mcu = "stm32f103",
+                    stm32_mcu = "stm32f107",
                     stm32_mcu = "stm32f401",
                     stm32_mcu = "stm32f405",
                     stm32_mcu = "stm32f407",
@@ -1116,6 +1785,11 @@ macro_rules! map_adc {
                     JOFFSET2 { JOFFSET2 }
                 }
                 #[cfg(any(
+                    stm32_mcu = "stm32f100",
+                    stm32_mcu = "stm32f101",
+                    stm32_mcu = "stm32f102",
+                    stm32_mcu = "stm32f103",
+                    stm32_mcu = "stm32f107",
                     stm32_mcu = "stm32f401",
                     stm32_mcu = "stm32f405",
                     stm32_mcu = "stm32f407",
@@ -1133,6 +1807,11 @@ macro_rules! map_adc {
                     JOFFSET3 { JOFFSET3 }
                 }
                 #[cfg(any(
+                    stm32_mcu = "stm32f100",
+                    stm32_mcu = "stm32f101",
+                    stm32_mcu = "stm32f102",
+                    stm32_mcu = "stm32f103",
+                    stm32_mcu = "stm32f107",
                     stm32_mcu = "stm32f401",
                     stm32_mcu = "stm32f405",
                     stm32_mcu = "stm32f407",
@@ -1150,6 +1829,11 @@ macro_rules! map_adc {
                     JOFFSET4 { JOFFSET4 }
                 }
                 #[cfg(any(
+                    stm32_mcu = "stm32f100",
+                    stm32_mcu = "stm32f101",
+                    stm32_mcu = "stm32f102",
+                    stm32_mcu = "stm32f103",
+                    stm32_mcu = "stm32f107",
                     stm32_mcu = "stm32f401",
                     stm32_mcu = "stm32f405",
                     stm32_mcu = "stm32f407",
@@ -1167,6 +1851,11 @@ macro_rules! map_adc {
                     HT { HT }
                 }
                 #[cfg(any(
+                    stm32_mcu = "stm32f100",
+                    stm32_mcu = "stm32f101",
+                    stm32_mcu = "stm32f102",
+                    stm32_mcu = "stm32f103",
+                    stm32_mcu = "stm32f107",
                     stm32_mcu = "stm32f401",
                     stm32_mcu = "stm32f405",
                     stm32_mcu = "stm32f407",
@@ -1184,6 +1873,11 @@ macro_rules! map_adc {
                     LT { LT }
                 }
                 #[cfg(any(
+                    stm32_mcu = "stm32l4x1",
+                    stm32_mcu = "stm32l4x2",
+                    stm32_mcu = "stm32l4x3",
+                    stm32_mcu = "stm32l4x5",
+                    stm32_mcu = "stm32l4x6",
                     stm32_mcu = "stm32l4r5",
                     stm32_mcu = "stm32l4r7",
                     stm32_mcu = "stm32l4r9",
@@ -1197,6 +1891,11 @@ macro_rules! map_adc {
                     LT1 { LT1 }
                 }
                 #[cfg(any(
+                    stm32_mcu = "stm32l4x1",
+                    stm32_mcu = "stm32l4x2",
+                    stm32_mcu = "stm32l4x3",
+                    stm32_mcu = "stm32l4x5",
+                    stm32_mcu = "stm32l4x6",
                     stm32_mcu = "stm32l4r5",
                     stm32_mcu = "stm32l4r7",
                     stm32_mcu = "stm32l4r9",
@@ -1210,6 +1909,11 @@ macro_rules! map_adc {
                     LT2 { LT2 }
                 }
                 #[cfg(any(
+                    stm32_mcu = "stm32l4x1",
+                    stm32_mcu = "stm32l4x2",
+                    stm32_mcu = "stm32l4x3",
+                    stm32_mcu = "stm32l4x5",
+                    stm32_mcu = "stm32l4x6",
                     stm32_mcu = "stm32l4r5",
                     stm32_mcu = "stm32l4r7",
                     stm32_mcu = "stm32l4r9",
@@ -1223,6 +1927,11 @@ macro_rules! map_adc {
                     LT3 { LT3 }
                 }
                 #[cfg(any(
+                    stm32_mcu = "stm32f100",
+                    stm32_mcu = "stm32f101",
+                    stm32_mcu = "stm32f102",
+                    stm32_mcu = "stm32f103",
+                    stm32_mcu = "stm32f107",
                     stm32_mcu = "stm32f401",
                     stm32_mcu = "stm32f405",
                     stm32_mcu = "stm32f407",
@@ -1244,6 +1953,11 @@ macro_rules! map_adc {
                     SQ13 { SQ13 }
                 }
                 #[cfg(any(
+                    stm32_mcu = "stm32f100",
+                    stm32_mcu = "stm32f101",
+                    stm32_mcu = "stm32f102",
+                    stm32_mcu = "stm32f103",
+                    stm32_mcu = "stm32f107",
                     stm32_mcu = "stm32f401",
                     stm32_mcu = "stm32f405",
                     stm32_mcu = "stm32f407",
@@ -1266,6 +1980,11 @@ macro_rules! map_adc {
                     SQ7 { SQ7 }
                 }
                 #[cfg(any(
+                    stm32_mcu = "stm32f100",
+                    stm32_mcu = "stm32f101",
+                    stm32_mcu = "stm32f102",
+                    stm32_mcu = "stm32f103",
+                    stm32_mcu = "stm32f107",
                     stm32_mcu = "stm32f401",
                     stm32_mcu = "stm32f405",
                     stm32_mcu = "stm32f407",
@@ -1288,6 +2007,11 @@ macro_rules! map_adc {
                     SQ1 { SQ1 }
                 }
                 #[cfg(any(
+                    stm32_mcu = "stm32l4x1",
+                    stm32_mcu = "stm32l4x2",
+                    stm32_mcu = "stm32l4x3",
+                    stm32_mcu = "stm32l4x5",
+                    stm32_mcu = "stm32l4x6",
                     stm32_mcu = "stm32l4r5",
                     stm32_mcu = "stm32l4r7",
                     stm32_mcu = "stm32l4r9",
@@ -1304,6 +2028,11 @@ macro_rules! map_adc {
                     SQ4 { SQ4 }
                 }
                 #[cfg(any(
+                    stm32_mcu = "stm32l4x1",
+                    stm32_mcu = "stm32l4x2",
+                    stm32_mcu = "stm32l4x3",
+                    stm32_mcu = "stm32l4x5",
+                    stm32_mcu = "stm32l4x6",
                     stm32_mcu = "stm32l4r5",
                     stm32_mcu = "stm32l4r7",
                     stm32_mcu = "stm32l4r9",
@@ -1320,6 +2049,11 @@ macro_rules! map_adc {
                     SQ9 { SQ9 }
                 }
                 #[cfg(any(
+                    stm32_mcu = "stm32l4x1",
+                    stm32_mcu = "stm32l4x2",
+                    stm32_mcu = "stm32l4x3",
+                    stm32_mcu = "stm32l4x5",
+                    stm32_mcu = "stm32l4x6",
                     stm32_mcu = "stm32l4r5",
                     stm32_mcu = "stm32l4r7",
                     stm32_mcu = "stm32l4r9",
@@ -1336,6 +2070,11 @@ macro_rules! map_adc {
                     SQ14 { SQ14 }
                 }
                 #[cfg(any(
+                    stm32_mcu = "stm32l4x1",
+                    stm32_mcu = "stm32l4x2",
+                    stm32_mcu = "stm32l4x3",
+                    stm32_mcu = "stm32l4x5",
+                    stm32_mcu = "stm32l4x6",
                     stm32_mcu = "stm32l4r5",
                     stm32_mcu = "stm32l4r7",
                     stm32_mcu = "stm32l4r9",
@@ -1351,10 +2090,23 @@ macro_rules! map_adc {
                 DR {
                     DR;
                     RDATA { $rdata }
+                    #[cfg(any(
+                        stm32_mcu = "stm32f100",
+                        stm32_mcu = "stm32f101",
+                        stm32_mcu = "stm32f102",
+                        stm32_mcu = "stm32f103",
+                        stm32_mcu = "stm32f107"
+                    ))]
+                    ADC2DATA { $($adc2data Option)* }
                 }
                 JSQR {
                     JSQR;
                     #[cfg(any(
+                        stm32_mcu = "stm32l4x1",
+                        stm32_mcu = "stm32l4x2",
+                        stm32_mcu = "stm32l4x3",
+                        stm32_mcu = "stm32l4x5",
+                        stm32_mcu = "stm32l4x6",
                         stm32_mcu = "stm32l4r5",
                         stm32_mcu = "stm32l4r7",
                         stm32_mcu = "stm32l4r9",
@@ -1364,6 +2116,11 @@ macro_rules! map_adc {
                     ))]
                     JEXTEN { JEXTEN }
                     #[cfg(any(
+                        stm32_mcu = "stm32l4x1",
+                        stm32_mcu = "stm32l4x2",
+                        stm32_mcu = "stm32l4x3",
+                        stm32_mcu = "stm32l4x5",
+                        stm32_mcu = "stm32l4x6",
                         stm32_mcu = "stm32l4r5",
                         stm32_mcu = "stm32l4r7",
                         stm32_mcu = "stm32l4r9",
@@ -1379,6 +2136,11 @@ macro_rules! map_adc {
                     JSQ4 { JSQ4 }
                 }
                 #[cfg(any(
+                    stm32_mcu = "stm32l4x1",
+                    stm32_mcu = "stm32l4x2",
+                    stm32_mcu = "stm32l4x3",
+                    stm32_mcu = "stm32l4x5",
+                    stm32_mcu = "stm32l4x6",
                     stm32_mcu = "stm32l4r5",
                     stm32_mcu = "stm32l4r7",
                     stm32_mcu = "stm32l4r9",
@@ -1393,6 +2155,11 @@ macro_rules! map_adc {
                     OFFSET1 { OFFSET1 }
                 }
                 #[cfg(any(
+                    stm32_mcu = "stm32l4x1",
+                    stm32_mcu = "stm32l4x2",
+                    stm32_mcu = "stm32l4x3",
+                    stm32_mcu = "stm32l4x5",
+                    stm32_mcu = "stm32l4x6",
                     stm32_mcu = "stm32l4r5",
                     stm32_mcu = "stm32l4r7",
                     stm32_mcu = "stm32l4r9",
@@ -1407,6 +2174,11 @@ macro_rules! map_adc {
                     OFFSET2 { OFFSET2 }
                 }
                 #[cfg(any(
+                    stm32_mcu = "stm32l4x1",
+                    stm32_mcu = "stm32l4x2",
+                    stm32_mcu = "stm32l4x3",
+                    stm32_mcu = "stm32l4x5",
+                    stm32_mcu = "stm32l4x6",
                     stm32_mcu = "stm32l4r5",
                     stm32_mcu = "stm32l4r7",
                     stm32_mcu = "stm32l4r9",
@@ -1421,6 +2193,11 @@ macro_rules! map_adc {
                     OFFSET3 { OFFSET3 }
                 }
                 #[cfg(any(
+                    stm32_mcu = "stm32l4x1",
+                    stm32_mcu = "stm32l4x2",
+                    stm32_mcu = "stm32l4x3",
+                    stm32_mcu = "stm32l4x5",
+                    stm32_mcu = "stm32l4x6",
                     stm32_mcu = "stm32l4r5",
                     stm32_mcu = "stm32l4r7",
                     stm32_mcu = "stm32l4r9",
@@ -1451,6 +2228,11 @@ macro_rules! map_adc {
                     JDATA { JDATA }
                 }
                 #[cfg(any(
+                    stm32_mcu = "stm32l4x1",
+                    stm32_mcu = "stm32l4x2",
+                    stm32_mcu = "stm32l4x3",
+                    stm32_mcu = "stm32l4x5",
+                    stm32_mcu = "stm32l4x6",
                     stm32_mcu = "stm32l4r5",
                     stm32_mcu = "stm32l4r7",
                     stm32_mcu = "stm32l4r9",
@@ -1463,6 +2245,11 @@ macro_rules! map_adc {
                     AWD2CH { AWD2CH }
                 }
                 #[cfg(any(
+                    stm32_mcu = "stm32l4x1",
+                    stm32_mcu = "stm32l4x2",
+                    stm32_mcu = "stm32l4x3",
+                    stm32_mcu = "stm32l4x5",
+                    stm32_mcu = "stm32l4x6",
                     stm32_mcu = "stm32l4r5",
                     stm32_mcu = "stm32l4r7",
                     stm32_mcu = "stm32l4r9",
@@ -1475,6 +2262,11 @@ macro_rules! map_adc {
                     AWD3CH { AWD3CH }
                 }
                 #[cfg(any(
+                    stm32_mcu = "stm32l4x1",
+                    stm32_mcu = "stm32l4x2",
+                    stm32_mcu = "stm32l4x3",
+                    stm32_mcu = "stm32l4x5",
+                    stm32_mcu = "stm32l4x6",
                     stm32_mcu = "stm32l4r5",
                     stm32_mcu = "stm32l4r7",
                     stm32_mcu = "stm32l4r9",
@@ -1488,6 +2280,11 @@ macro_rules! map_adc {
                     DIFSEL_16_18 { DIFSEL_16_18 }
                 }
                 #[cfg(any(
+                    stm32_mcu = "stm32l4x1",
+                    stm32_mcu = "stm32l4x2",
+                    stm32_mcu = "stm32l4x3",
+                    stm32_mcu = "stm32l4x5",
+                    stm32_mcu = "stm32l4x6",
                     stm32_mcu = "stm32l4r5",
                     stm32_mcu = "stm32l4r7",
                     stm32_mcu = "stm32l4r9",
@@ -1505,6 +2302,74 @@ macro_rules! map_adc {
     };
 }
 
+#[cfg(any(stm32_mcu = "stm32f100", stm32_mcu = "stm32f101", stm32_mcu = "stm32f102"))]
+map_adc! {
+    "Extracts ADC1 register tokens.",
+    periph_adc1,
+    "ADC1 peripheral variant.",
+    Adc1,
+    ADC1,
+    APB2ENR,
+    APB2SMENR,
+    ADC1EN,
+    ADC1SMEN,
+    DATA,
+    (),
+    (TSVREFE),
+    (),
+}
+
+#[cfg(any(stm32_mcu = "stm32f103", stm32_mcu = "stm32f107"))]
+map_adc! {
+    "Extracts ADC1 register tokens.",
+    periph_adc1,
+    "ADC1 peripheral variant.",
+    Adc1,
+    ADC1,
+    APB2ENR,
+    APB2SMENR,
+    ADC1EN,
+    ADC1SMEN,
+    DATA,
+    (DUALMOD),
+    (TSVREFE),
+    (ADC2DATA),
+}
+
+#[cfg(any(stm32_mcu = "stm32f103", stm32_mcu = "stm32f107"))]
+map_adc! {
+    "Extracts ADC2 register tokens.",
+    periph_adc2,
+    "ADC2 peripheral variant.",
+    Adc2,
+    ADC2,
+    APB2ENR,
+    APB2SMENR,
+    ADC2EN,
+    ADC2SMEN,
+    DATA,
+    (),
+    (),
+    (),
+}
+
+#[cfg(any(stm32_mcu = "stm32f103"))]
+map_adc! {
+    "Extracts ADC3 register tokens.",
+    periph_adc3,
+    "ADC3 peripheral variant.",
+    Adc3,
+    ADC3,
+    APB2ENR,
+    APB2SMENR,
+    ADC3EN,
+    ADC3SMEN,
+    DATA,
+    (),
+    (),
+    (),
+}
+
 #[cfg(any(
     stm32_mcu = "stm32f401",
     stm32_mcu = "stm32f405",
@@ -1529,6 +2394,9 @@ map_adc! {
     ADC1EN,
     ADC1LPEN,
     DATA,
+    (),
+    (),
+    (),
 }
 
 #[cfg(any(
@@ -1550,6 +2418,9 @@ map_adc! {
     ADC2EN,
     ADC2LPEN,
     DATA,
+    (),
+    (),
+    (),
 }
 
 #[cfg(any(
@@ -1571,6 +2442,9 @@ map_adc! {
     ADC3EN,
     ADC3LPEN,
     DATA,
+    (),
+    (),
+    (),
 }
 
 #[cfg(any(
@@ -1592,4 +2466,75 @@ map_adc! {
     ADCEN,
     ADCSMEN,
     RDATA,
+    (),
+    (),
+    (),
+}
+
+#[cfg(any(stm32_mcu = "stm32l4x1", stm32_mcu = "stm32l4x2", stm32_mcu = "stm32l4x3"))]
+map_adc! {
+    "Extracts ADC1 register tokens.",
+    periph_adc1,
+    "ADC1 peripheral variant.",
+    Adc1,
+    ADC,
+    AHB2ENR,
+    AHB2SMENR,
+    ADCEN,
+    ADCSMEN,
+    RDATA,
+    (),
+    (),
+    (),
+}
+
+#[cfg(any(stm32_mcu = "stm32l4x5", stm32_mcu = "stm32l4x6"))]
+map_adc! {
+    "Extracts ADC1 register tokens.",
+    periph_adc1,
+    "ADC1 peripheral variant.",
+    Adc1,
+    ADC1,
+    AHB2ENR,
+    AHB2SMENR,
+    ADCEN,
+    ADCSMEN,
+    RDATA,
+    (),
+    (),
+    (),
+}
+
+#[cfg(any(stm32_mcu = "stm32l4x5", stm32_mcu = "stm32l4x6"))]
+map_adc! {
+    "Extracts ADC2 register tokens.",
+    periph_adc2,
+    "ADC2 peripheral variant.",
+    Adc2,
+    ADC2,
+    AHB2ENR,
+    AHB2SMENR,
+    ADCEN,
+    ADCSMEN,
+    RDATA,
+    (),
+    (),
+    (),
+}
+
+#[cfg(any(stm32_mcu = "stm32l4x5", stm32_mcu = "stm32l4x6"))]
+map_adc! {
+    "Extracts ADC3 register tokens.",
+    periph_adc3,
+    "ADC3 peripheral variant.",
+    Adc3,
+    ADC3,
+    AHB2ENR,
+    AHB2SMENR,
+    ADCEN,
+    ADCSMEN,
+    RDATA,
+    (),
+    (),
+    (),
 }
@@ -0,0 +1,72 @@
+//! AES hardware accelerator.
+//!
+//! The accelerator is present on the STM32L442, STM32L443, STM32L462,
+//! STM32L486, STM32L4A6 and STM32L4S devices. Except for the STM32L4S devices,
+//! these share the `stm32_mcu` value of their counterparts without the
+//! accelerator.
+
+#![feature(proc_macro_hygiene)]
+#![warn(missing_docs)]
+#![warn(clippy::pedantic)]
+#![allow(clippy::type_repetition_in_bounds, clippy::wildcard_imports)]
+#![no_std]
+
+#[allow(unused_imports)]
+use drone_core::periph;
+
+#[cfg(any(
+    stm32_mcu = "stm32l4x2",
+    stm32_mcu = "stm32l4x3",
+    stm32_mcu = "stm32l4x6",
+    stm32_mcu = "stm32l4s5",
+    stm32_mcu = "stm32l4s7",
+    stm32_mcu = "stm32l4s9"
+))]
+periph::singular! {
+    /// Extracts AES register tokens.
+    pub macro periph_aes;
+
+    /// AES peripheral.
+    pub struct AesPeriph;
+
+    drone_stm32_map_pieces::reg;
+    crate;
+
+    RCC {
+        AHB2ENR {
+            AESEN;
+        }
+        AHB2RSTR {
+            AESRST;
+        }
+        AHB2SMENR {
+            AESSMEN;
+        }
+    }
+    AES {
+        CR;
+        SR;
+        DINR;
+        DOUTR;
+        KEYR0;
+        KEYR1;
+        KEYR2;
+        KEYR3;
+        IVR0;
+        IVR1;
+        IVR2;
+        IVR3;
+        KEYR4;
+        KEYR5;
+        KEYR6;
+        KEYR7;
+        SUSP0R;
+        SUSP1R;
+        SUSP2R;
+        SUSP3R;
+        SUSP4R;
+        SUSP5R;
+        SUSP6R;
+        SUSP7R;
+    }
+}
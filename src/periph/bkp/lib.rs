@@ -0,0 +1,89 @@
+//! Backup registers.
+//!
+//! The backup registers live in the backup domain, which is write-protected
+//! after reset. Set the `PWR_CR.DBP` bit (with the `PWREN` clock enabled) to
+//! allow writes to the data registers, `RTCCR`, `CR` and `CSR`. Only the first
+//! ten data registers are implemented on low- and medium-density devices.
+
+#![feature(proc_macro_hygiene)]
+#![warn(missing_docs)]
+#![warn(clippy::pedantic)]
+#![allow(clippy::type_repetition_in_bounds, clippy::wildcard_imports)]
+#![no_std]
+
+#[allow(unused_imports)]
+use drone_core::periph;
+
+#[cfg(any(
+    stm32_mcu = "stm32f100",
+    stm32_mcu = "stm32f101",
+    stm32_mcu = "stm32f102",
+    stm32_mcu = "stm32f103",
+    stm32_mcu = "stm32f107"
+))]
+periph::singular! {
+    /// Extracts BKP register tokens.
+    pub macro periph_bkp;
+
+    /// BKP peripheral.
+    pub struct BkpPeriph;
+
+    drone_stm32_map_pieces::reg;
+    crate;
+
+    RCC {
+        APB1ENR {
+            BKPEN;
+        }
+        APB1RSTR {
+            BKPRST;
+        }
+    }
+    BKP {
+        DR1;
+        DR2;
+        DR3;
+        DR4;
+        DR5;
+        DR6;
+        DR7;
+        DR8;
+        DR9;
+        DR10;
+        DR11;
+        DR12;
+        DR13;
+        DR14;
+        DR15;
+        DR16;
+        DR17;
+        DR18;
+        DR19;
+        DR20;
+        DR21;
+        DR22;
+        DR23;
+        DR24;
+        DR25;
+        DR26;
+        DR27;
+        DR28;
+        DR29;
+        DR30;
+        DR31;
+        DR32;
+        DR33;
+        DR34;
+        DR35;
+        DR36;
+        DR37;
+        DR38;
+        DR39;
+        DR40;
+        DR41;
+        DR42;
+        RTCCR;
+        CR;
+        CSR;
+    }
+}
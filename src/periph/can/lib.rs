@@ -0,0 +1,1391 @@
+//! Controller Area Network.
+//!
+//! CAN1 is the master instance: it owns the filter banks, which are shared with
+//! the CAN2 slave instance. The filter registers are therefore only mapped for
+//! CAN1.
+//!
+//! The `CAN2SB` field of the `FMR` register sets the first filter bank assigned
+//! to CAN2. Banks below it are used by CAN1. STM32L4 names this field `CANSB`;
+//! it is mapped to `CAN2SB` here.
+//!
+//! On STM32F103, CAN1 and the USB device peripheral share a dedicated SRAM
+//! buffer and cannot be used at the same time. `periph_can1!` therefore also
+//! takes the `RCC_APB1ENR_USBEN` field token, so acquiring both CAN1 and USB
+//! fails to compile.
+
+#![feature(proc_macro_hygiene)]
+#![warn(missing_docs)]
+#![warn(clippy::pedantic)]
+#![allow(clippy::type_repetition_in_bounds, clippy::wildcard_imports)]
+#![no_std]
+
+use drone_core::periph;
+use drone_cortexm::reg::marker::*;
+
+periph! {
+    /// Generic CAN peripheral variant.
+    pub trait CanMap {}
+
+    /// Generic CAN peripheral.
+    pub struct CanPeriph;
+
+    RCC {
+        BUSENR {
+            0x20 RwRegBitBand Shared;
+            CANEN { RwRwRegFieldBitBand }
+            #[cfg(any(stm32_mcu = "stm32f103"))]
+            USBEN { RwRwRegFieldBitBand Option }
+        }
+        BUSRSTR {
+            0x20 RwRegBitBand Shared;
+            CANRST { RwRwRegFieldBitBand }
+        }
+        #[cfg(any(
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469",
+            stm32_mcu = "stm32l4x6"
+        ))]
+        BUSSMENR {
+            0x20 RwRegBitBand Shared;
+            CANSMEN { RwRwRegFieldBitBand }
+        }
+    }
+    CAN {
+        MCR {
+            0x20 RwRegBitBand;
+            ABOM { RwRwRegFieldBitBand }
+            AWUM { RwRwRegFieldBitBand }
+            DBF { RwRwRegFieldBitBand }
+            INRQ { RwRwRegFieldBitBand }
+            NART { RwRwRegFieldBitBand }
+            RESET { RwRwRegFieldBitBand }
+            RFLM { RwRwRegFieldBitBand }
+            SLEEP { RwRwRegFieldBitBand }
+            TTCM { RwRwRegFieldBitBand }
+            TXFP { RwRwRegFieldBitBand }
+        }
+        MSR {
+            0x20 RwRegBitBand;
+            ERRI { RwRwRegFieldBitBand }
+            INAK { RoRwRegFieldBitBand }
+            RX { RoRwRegFieldBitBand }
+            RXM { RoRwRegFieldBitBand }
+            SAMP { RoRwRegFieldBitBand }
+            SLAK { RoRwRegFieldBitBand }
+            SLAKI { RwRwRegFieldBitBand }
+            TXM { RoRwRegFieldBitBand }
+            WKUI { RwRwRegFieldBitBand }
+        }
+        TSR {
+            0x20 RwRegBitBand;
+            ABRQ0 { RwRwRegFieldBitBand }
+            ABRQ1 { RwRwRegFieldBitBand }
+            ABRQ2 { RwRwRegFieldBitBand }
+            ALST0 { RwRwRegFieldBitBand }
+            ALST1 { RwRwRegFieldBitBand }
+            ALST2 { RwRwRegFieldBitBand }
+            CODE { RoRwRegFieldBits }
+            LOW0 { RoRwRegFieldBitBand }
+            LOW1 { RoRwRegFieldBitBand }
+            LOW2 { RoRwRegFieldBitBand }
+            RQCP0 { RwRwRegFieldBitBand }
+            RQCP1 { RwRwRegFieldBitBand }
+            RQCP2 { RwRwRegFieldBitBand }
+            TERR0 { RwRwRegFieldBitBand }
+            TERR1 { RwRwRegFieldBitBand }
+            TERR2 { RwRwRegFieldBitBand }
+            TME0 { RoRwRegFieldBitBand }
+            TME1 { RoRwRegFieldBitBand }
+            TME2 { RoRwRegFieldBitBand }
+            TXOK0 { RwRwRegFieldBitBand }
+            TXOK1 { RwRwRegFieldBitBand }
+            TXOK2 { RwRwRegFieldBitBand }
+        }
+        RF0R {
+            0x20 RwRegBitBand;
+            FMP0 { RoRwRegFieldBits }
+            FOVR0 { RwRwRegFieldBitBand }
+            FULL0 { RwRwRegFieldBitBand }
+            RFOM0 { RwRwRegFieldBitBand }
+        }
+        RF1R {
+            0x20 RwRegBitBand;
+            FMP1 { RoRwRegFieldBits }
+            FOVR1 { RwRwRegFieldBitBand }
+            FULL1 { RwRwRegFieldBitBand }
+            RFOM1 { RwRwRegFieldBitBand }
+        }
+        IER {
+            0x20 RwRegBitBand;
+            BOFIE { RwRwRegFieldBitBand }
+            EPVIE { RwRwRegFieldBitBand }
+            ERRIE { RwRwRegFieldBitBand }
+            EWGIE { RwRwRegFieldBitBand }
+            FFIE0 { RwRwRegFieldBitBand }
+            FFIE1 { RwRwRegFieldBitBand }
+            FMPIE0 { RwRwRegFieldBitBand }
+            FMPIE1 { RwRwRegFieldBitBand }
+            FOVIE0 { RwRwRegFieldBitBand }
+            FOVIE1 { RwRwRegFieldBitBand }
+            LECIE { RwRwRegFieldBitBand }
+            SLKIE { RwRwRegFieldBitBand }
+            TMEIE { RwRwRegFieldBitBand }
+            WKUIE { RwRwRegFieldBitBand }
+        }
+        ESR {
+            0x20 RwRegBitBand;
+            BOFF { RoRwRegFieldBitBand }
+            EPVF { RoRwRegFieldBitBand }
+            EWGF { RoRwRegFieldBitBand }
+            LEC { RwRwRegFieldBits }
+            REC { RoRwRegFieldBits }
+            TEC { RoRwRegFieldBits }
+        }
+        BTR {
+            0x20 RwRegBitBand;
+            BRP { RwRwRegFieldBits }
+            LBKM { RwRwRegFieldBitBand }
+            SILM { RwRwRegFieldBitBand }
+            SJW { RwRwRegFieldBits }
+            TS1 { RwRwRegFieldBits }
+            TS2 { RwRwRegFieldBits }
+        }
+        TI0R {
+            0x20 RwRegBitBand;
+            EXID { RwRwRegFieldBits }
+            IDE { RwRwRegFieldBitBand }
+            RTR { RwRwRegFieldBitBand }
+            STID { RwRwRegFieldBits }
+            TXRQ { RwRwRegFieldBitBand }
+        }
+        TDT0R {
+            0x20 RwRegBitBand;
+            DLC { RwRwRegFieldBits }
+            TGT { RwRwRegFieldBitBand }
+            TIME { RwRwRegFieldBits }
+        }
+        TDL0R {
+            0x20 RwRegBitBand;
+            DATA0 { RwRwRegFieldBits }
+            DATA1 { RwRwRegFieldBits }
+            DATA2 { RwRwRegFieldBits }
+            DATA3 { RwRwRegFieldBits }
+        }
+        TDH0R {
+            0x20 RwRegBitBand;
+            DATA4 { RwRwRegFieldBits }
+            DATA5 { RwRwRegFieldBits }
+            DATA6 { RwRwRegFieldBits }
+            DATA7 { RwRwRegFieldBits }
+        }
+        TI1R {
+            0x20 RwRegBitBand;
+            EXID { RwRwRegFieldBits }
+            IDE { RwRwRegFieldBitBand }
+            RTR { RwRwRegFieldBitBand }
+            STID { RwRwRegFieldBits }
+            TXRQ { RwRwRegFieldBitBand }
+        }
+        TDT1R {
+            0x20 RwRegBitBand;
+            DLC { RwRwRegFieldBits }
+            TGT { RwRwRegFieldBitBand }
+            TIME { RwRwRegFieldBits }
+        }
+        TDL1R {
+            0x20 RwRegBitBand;
+            DATA0 { RwRwRegFieldBits }
+            DATA1 { RwRwRegFieldBits }
+            DATA2 { RwRwRegFieldBits }
+            DATA3 { RwRwRegFieldBits }
+        }
+        TDH1R {
+            0x20 RwRegBitBand;
+            DATA4 { RwRwRegFieldBits }
+            DATA5 { RwRwRegFieldBits }
+            DATA6 { RwRwRegFieldBits }
+            DATA7 { RwRwRegFieldBits }
+        }
+        TI2R {
+            0x20 RwRegBitBand;
+            EXID { RwRwRegFieldBits }
+            IDE { RwRwRegFieldBitBand }
+            RTR { RwRwRegFieldBitBand }
+            STID { RwRwRegFieldBits }
+            TXRQ { RwRwRegFieldBitBand }
+        }
+        TDT2R {
+            0x20 RwRegBitBand;
+            DLC { RwRwRegFieldBits }
+            TGT { RwRwRegFieldBitBand }
+            TIME { RwRwRegFieldBits }
+        }
+        TDL2R {
+            0x20 RwRegBitBand;
+            DATA0 { RwRwRegFieldBits }
+            DATA1 { RwRwRegFieldBits }
+            DATA2 { RwRwRegFieldBits }
+            DATA3 { RwRwRegFieldBits }
+        }
+        TDH2R {
+            0x20 RwRegBitBand;
+            DATA4 { RwRwRegFieldBits }
+            DATA5 { RwRwRegFieldBits }
+            DATA6 { RwRwRegFieldBits }
+            DATA7 { RwRwRegFieldBits }
+        }
+        RI0R {
+            0x20 RoRegBitBand;
+            EXID { RoRoRegFieldBits }
+            IDE { RoRoRegFieldBitBand }
+            RTR { RoRoRegFieldBitBand }
+            STID { RoRoRegFieldBits }
+        }
+        RDT0R {
+            0x20 RoRegBitBand;
+            DLC { RoRoRegFieldBits }
+            FMI { RoRoRegFieldBits }
+            TIME { RoRoRegFieldBits }
+        }
+        RDL0R {
+            0x20 RoRegBitBand;
+            DATA0 { RoRoRegFieldBits }
+            DATA1 { RoRoRegFieldBits }
+            DATA2 { RoRoRegFieldBits }
+            DATA3 { RoRoRegFieldBits }
+        }
+        RDH0R {
+            0x20 RoRegBitBand;
+            DATA4 { RoRoRegFieldBits }
+            DATA5 { RoRoRegFieldBits }
+            DATA6 { RoRoRegFieldBits }
+            DATA7 { RoRoRegFieldBits }
+        }
+        RI1R {
+            0x20 RoRegBitBand;
+            EXID { RoRoRegFieldBits }
+            IDE { RoRoRegFieldBitBand }
+            RTR { RoRoRegFieldBitBand }
+            STID { RoRoRegFieldBits }
+        }
+        RDT1R {
+            0x20 RoRegBitBand;
+            DLC { RoRoRegFieldBits }
+            FMI { RoRoRegFieldBits }
+            TIME { RoRoRegFieldBits }
+        }
+        RDL1R {
+            0x20 RoRegBitBand;
+            DATA0 { RoRoRegFieldBits }
+            DATA1 { RoRoRegFieldBits }
+            DATA2 { RoRoRegFieldBits }
+            DATA3 { RoRoRegFieldBits }
+        }
+        RDH1R {
+            0x20 RoRegBitBand;
+            DATA4 { RoRoRegFieldBits }
+            DATA5 { RoRoRegFieldBits }
+            DATA6 { RoRoRegFieldBits }
+            DATA7 { RoRoRegFieldBits }
+        }
+        FMR {
+            0x20 RwRegBitBand Option;
+            CAN2SB { RwRwRegFieldBits Option }
+            FINIT { RwRwRegFieldBitBand }
+        }
+        FM1R {
+            0x20 RwRegBitBand Option;
+            FBM { RwRwRegFieldBits }
+        }
+        FS1R {
+            0x20 RwRegBitBand Option;
+            FSC { RwRwRegFieldBits }
+        }
+        FFA1R {
+            0x20 RwRegBitBand Option;
+            FFA { RwRwRegFieldBits }
+        }
+        FA1R {
+            0x20 RwRegBitBand Option;
+            FACT { RwRwRegFieldBits }
+        }
+        F0R1 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+        F0R2 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+        F1R1 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+        F1R2 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+        F2R1 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+        F2R2 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+        F3R1 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+        F3R2 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+        F4R1 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+        F4R2 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+        F5R1 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+        F5R2 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+        F6R1 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+        F6R2 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+        F7R1 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+        F7R2 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+        F8R1 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+        F8R2 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+        F9R1 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+        F9R2 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+        F10R1 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+        F10R2 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+        F11R1 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+        F11R2 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+        F12R1 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+        F12R2 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+        F13R1 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+        F13R2 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+        F14R1 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+        F14R2 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+        F15R1 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+        F15R2 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+        F16R1 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+        F16R2 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+        F17R1 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+        F17R2 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+        F18R1 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+        F18R2 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+        F19R1 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+        F19R2 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+        F20R1 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+        F20R2 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+        F21R1 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+        F21R2 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+        F22R1 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+        F22R2 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+        F23R1 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+        F23R2 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+        F24R1 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+        F24R2 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+        F25R1 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+        F25R2 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+        F26R1 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+        F26R2 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+        F27R1 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+        F27R2 {
+            0x20 RwRegBitBand Option;
+            FB { RwRwRegFieldBits }
+        }
+    }
+}
+
+#[allow(unused_macros)]
+macro_rules! map_can {
+    (
+        $can_macro_doc:expr,
+        $can_macro:ident,
+        $can_ty_doc:expr,
+        $can_ty:ident,
+        $busenr:ident,
+        $busrstr:ident,
+        $bussmenr:ident,
+        $canen:ident,
+        $canrst:ident,
+        $cansmen:ident,
+        $can:ident,
+        ($($usben:ident)?),
+        ($(
+            $fmr:ident,
+            $($can2sb:ident)?,
+            $fm1r:ident,
+            $fs1r:ident,
+            $ffa1r:ident,
+            $fa1r:ident
+        )?),
+        ($(
+            $f0r1:ident,
+            $f0r2:ident,
+            $f1r1:ident,
+            $f1r2:ident,
+            $f2r1:ident,
+            $f2r2:ident,
+            $f3r1:ident,
+            $f3r2:ident,
+            $f4r1:ident,
+            $f4r2:ident,
+            $f5r1:ident,
+            $f5r2:ident,
+            $f6r1:ident,
+            $f6r2:ident,
+            $f7r1:ident,
+            $f7r2:ident,
+            $f8r1:ident,
+            $f8r2:ident,
+            $f9r1:ident,
+            $f9r2:ident,
+            $f10r1:ident,
+            $f10r2:ident,
+            $f11r1:ident,
+            $f11r2:ident,
+            $f12r1:ident,
+            $f12r2:ident,
+            $f13r1:ident,
+            $f13r2:ident
+        )?),
+        ($(
+            $f14r1:ident,
+            $f14r2:ident,
+            $f15r1:ident,
+            $f15r2:ident,
+            $f16r1:ident,
+            $f16r2:ident,
+            $f17r1:ident,
+            $f17r2:ident,
+            $f18r1:ident,
+            $f18r2:ident,
+            $f19r1:ident,
+            $f19r2:ident,
+            $f20r1:ident,
+            $f20r2:ident,
+            $f21r1:ident,
+            $f21r2:ident,
+            $f22r1:ident,
+            $f22r2:ident,
+            $f23r1:ident,
+            $f23r2:ident,
+            $f24r1:ident,
+            $f24r2:ident,
+            $f25r1:ident,
+            $f25r2:ident,
+            $f26r1:ident,
+            $f26r2:ident,
+            $f27r1:ident,
+            $f27r2:ident
+        )?),
+    ) => {
+        periph::map! {
+            #[doc = $can_macro_doc]
+            pub macro $can_macro;
+
+            #[doc = $can_ty_doc]
+            pub struct $can_ty;
+
+            impl CanMap for $can_ty {}
+
+            drone_stm32_map_pieces::reg;
+            crate;
+
+            RCC {
+                BUSENR {
+                    $busenr Shared;
+                    CANEN { $canen }
+                    #[cfg(any(stm32_mcu = "stm32f103"))]
+                    USBEN { $($usben Option)* }
+                }
+                BUSRSTR {
+                    $busrstr Shared;
+                    CANRST { $canrst }
+                }
+                #[cfg(any(
+                    stm32_mcu = "stm32f405",
+                    stm32_mcu = "stm32f407",
+                    stm32_mcu = "stm32f427",
+                    stm32_mcu = "stm32f429",
+                    stm32_mcu = "stm32f446",
+                    stm32_mcu = "stm32f469",
+                    stm32_mcu = "stm32l4x6"
+                ))]
+                BUSSMENR {
+                    $bussmenr Shared;
+                    CANSMEN { $cansmen }
+                }
+            }
+            CAN {
+                $can;
+                MCR {
+                    MCR;
+                    ABOM { ABOM }
+                    AWUM { AWUM }
+                    DBF { DBF }
+                    INRQ { INRQ }
+                    NART { NART }
+                    RESET { RESET }
+                    RFLM { RFLM }
+                    SLEEP { SLEEP }
+                    TTCM { TTCM }
+                    TXFP { TXFP }
+                }
+                MSR {
+                    MSR;
+                    ERRI { ERRI }
+                    INAK { INAK }
+                    RX { RX }
+                    RXM { RXM }
+                    SAMP { SAMP }
+                    SLAK { SLAK }
+                    SLAKI { SLAKI }
+                    TXM { TXM }
+                    WKUI { WKUI }
+                }
+                TSR {
+                    TSR;
+                    ABRQ0 { ABRQ0 }
+                    ABRQ1 { ABRQ1 }
+                    ABRQ2 { ABRQ2 }
+                    ALST0 { ALST0 }
+                    ALST1 { ALST1 }
+                    ALST2 { ALST2 }
+                    CODE { CODE }
+                    LOW0 { LOW0 }
+                    LOW1 { LOW1 }
+                    LOW2 { LOW2 }
+                    RQCP0 { RQCP0 }
+                    RQCP1 { RQCP1 }
+                    RQCP2 { RQCP2 }
+                    TERR0 { TERR0 }
+                    TERR1 { TERR1 }
+                    TERR2 { TERR2 }
+                    TME0 { TME0 }
+                    TME1 { TME1 }
+                    TME2 { TME2 }
+                    TXOK0 { TXOK0 }
+                    TXOK1 { TXOK1 }
+                    TXOK2 { TXOK2 }
+                }
+                RF0R {
+                    RF0R;
+                    FMP0 { FMP0 }
+                    FOVR0 { FOVR0 }
+                    FULL0 { FULL0 }
+                    RFOM0 { RFOM0 }
+                }
+                RF1R {
+                    RF1R;
+                    FMP1 { FMP1 }
+                    FOVR1 { FOVR1 }
+                    FULL1 { FULL1 }
+                    RFOM1 { RFOM1 }
+                }
+                IER {
+                    IER;
+                    BOFIE { BOFIE }
+                    EPVIE { EPVIE }
+                    ERRIE { ERRIE }
+                    EWGIE { EWGIE }
+                    FFIE0 { FFIE0 }
+                    FFIE1 { FFIE1 }
+                    FMPIE0 { FMPIE0 }
+                    FMPIE1 { FMPIE1 }
+                    FOVIE0 { FOVIE0 }
+                    FOVIE1 { FOVIE1 }
+                    LECIE { LECIE }
+                    SLKIE { SLKIE }
+                    TMEIE { TMEIE }
+                    WKUIE { WKUIE }
+                }
+                ESR {
+                    ESR;
+                    BOFF { BOFF }
+                    EPVF { EPVF }
+                    EWGF { EWGF }
+                    LEC { LEC }
+                    REC { REC }
+                    TEC { TEC }
+                }
+                BTR {
+                    BTR;
+                    BRP { BRP }
+                    LBKM { LBKM }
+                    SILM { SILM }
+                    SJW { SJW }
+                    TS1 { TS1 }
+                    TS2 { TS2 }
+                }
+                TI0R {
+                    TI0R;
+                    EXID { EXID }
+                    IDE { IDE }
+                    RTR { RTR }
+                    STID { STID }
+                    TXRQ { TXRQ }
+                }
+                TDT0R {
+                    TDT0R;
+                    DLC { DLC }
+                    TGT { TGT }
+                    TIME { TIME }
+                }
+                TDL0R {
+                    TDL0R;
+                    DATA0 { DATA0 }
+                    DATA1 { DATA1 }
+                    DATA2 { DATA2 }
+                    DATA3 { DATA3 }
+                }
+                TDH0R {
+                    TDH0R;
+                    DATA4 { DATA4 }
+                    DATA5 { DATA5 }
+                    DATA6 { DATA6 }
+                    DATA7 { DATA7 }
+                }
+                TI1R {
+                    TI1R;
+                    EXID { EXID }
+                    IDE { IDE }
+                    RTR { RTR }
+                    STID { STID }
+                    TXRQ { TXRQ }
+                }
+                TDT1R {
+                    TDT1R;
+                    DLC { DLC }
+                    TGT { TGT }
+                    TIME { TIME }
+                }
+                TDL1R {
+                    TDL1R;
+                    DATA0 { DATA0 }
+                    DATA1 { DATA1 }
+                    DATA2 { DATA2 }
+                    DATA3 { DATA3 }
+                }
+                TDH1R {
+                    TDH1R;
+                    DATA4 { DATA4 }
+                    DATA5 { DATA5 }
+                    DATA6 { DATA6 }
+                    DATA7 { DATA7 }
+                }
+                TI2R {
+                    TI2R;
+                    EXID { EXID }
+                    IDE { IDE }
+                    RTR { RTR }
+                    STID { STID }
+                    TXRQ { TXRQ }
+                }
+                TDT2R {
+                    TDT2R;
+                    DLC { DLC }
+                    TGT { TGT }
+                    TIME { TIME }
+                }
+                TDL2R {
+                    TDL2R;
+                    DATA0 { DATA0 }
+                    DATA1 { DATA1 }
+                    DATA2 { DATA2 }
+                    DATA3 { DATA3 }
+                }
+                TDH2R {
+                    TDH2R;
+                    DATA4 { DATA4 }
+                    DATA5 { DATA5 }
+                    DATA6 { DATA6 }
+                    DATA7 { DATA7 }
+                }
+                RI0R {
+                    RI0R;
+                    EXID { EXID }
+                    IDE { IDE }
+                    RTR { RTR }
+                    STID { STID }
+                }
+                RDT0R {
+                    RDT0R;
+                    DLC { DLC }
+                    FMI { FMI }
+                    TIME { TIME }
+                }
+                RDL0R {
+                    RDL0R;
+                    DATA0 { DATA0 }
+                    DATA1 { DATA1 }
+                    DATA2 { DATA2 }
+                    DATA3 { DATA3 }
+                }
+                RDH0R {
+                    RDH0R;
+                    DATA4 { DATA4 }
+                    DATA5 { DATA5 }
+                    DATA6 { DATA6 }
+                    DATA7 { DATA7 }
+                }
+                RI1R {
+                    RI1R;
+                    EXID { EXID }
+                    IDE { IDE }
+                    RTR { RTR }
+                    STID { STID }
+                }
+                RDT1R {
+                    RDT1R;
+                    DLC { DLC }
+                    FMI { FMI }
+                    TIME { TIME }
+                }
+                RDL1R {
+                    RDL1R;
+                    DATA0 { DATA0 }
+                    DATA1 { DATA1 }
+                    DATA2 { DATA2 }
+                    DATA3 { DATA3 }
+                }
+                RDH1R {
+                    RDH1R;
+                    DATA4 { DATA4 }
+                    DATA5 { DATA5 }
+                    DATA6 { DATA6 }
+                    DATA7 { DATA7 }
+                }
+                FMR {
+                    $(
+                        $fmr Option;
+                        CAN2SB { $($can2sb Option)* }
+                        FINIT { FINIT }
+                    )*
+                }
+                FM1R {
+                    $(
+                        $fm1r Option;
+                        FBM { FBM }
+                    )*
+                }
+                FS1R {
+                    $(
+                        $fs1r Option;
+                        FSC { FSC }
+                    )*
+                }
+                FFA1R {
+                    $(
+                        $ffa1r Option;
+                        FFA { FFA }
+                    )*
+                }
+                FA1R {
+                    $(
+                        $fa1r Option;
+                        FACT { FACT }
+                    )*
+                }
+                F0R1 {
+                    $(
+                        $f0r1 Option;
+                        FB { FB }
+                    )*
+                }
+                F0R2 {
+                    $(
+                        $f0r2 Option;
+                        FB { FB }
+                    )*
+                }
+                F1R1 {
+                    $(
+                        $f1r1 Option;
+                        FB { FB }
+                    )*
+                }
+                F1R2 {
+                    $(
+                        $f1r2 Option;
+                        FB { FB }
+                    )*
+                }
+                F2R1 {
+                    $(
+                        $f2r1 Option;
+                        FB { FB }
+                    )*
+                }
+                F2R2 {
+                    $(
+                        $f2r2 Option;
+                        FB { FB }
+                    )*
+                }
+                F3R1 {
+                    $(
+                        $f3r1 Option;
+                        FB { FB }
+                    )*
+                }
+                F3R2 {
+                    $(
+                        $f3r2 Option;
+                        FB { FB }
+                    )*
+                }
+                F4R1 {
+                    $(
+                        $f4r1 Option;
+                        FB { FB }
+                    )*
+                }
+                F4R2 {
+                    $(
+                        $f4r2 Option;
+                        FB { FB }
+                    )*
+                }
+                F5R1 {
+                    $(
+                        $f5r1 Option;
+                        FB { FB }
+                    )*
+                }
+                F5R2 {
+                    $(
+                        $f5r2 Option;
+                        FB { FB }
+                    )*
+                }
+                F6R1 {
+                    $(
+                        $f6r1 Option;
+                        FB { FB }
+                    )*
+                }
+                F6R2 {
+                    $(
+                        $f6r2 Option;
+                        FB { FB }
+                    )*
+                }
+                F7R1 {
+                    $(
+                        $f7r1 Option;
+                        FB { FB }
+                    )*
+                }
+                F7R2 {
+                    $(
+                        $f7r2 Option;
+                        FB { FB }
+                    )*
+                }
+                F8R1 {
+                    $(
+                        $f8r1 Option;
+                        FB { FB }
+                    )*
+                }
+                F8R2 {
+                    $(
+                        $f8r2 Option;
+                        FB { FB }
+                    )*
+                }
+                F9R1 {
+                    $(
+                        $f9r1 Option;
+                        FB { FB }
+                    )*
+                }
+                F9R2 {
+                    $(
+                        $f9r2 Option;
+                        FB { FB }
+                    )*
+                }
+                F10R1 {
+                    $(
+                        $f10r1 Option;
+                        FB { FB }
+                    )*
+                }
+                F10R2 {
+                    $(
+                        $f10r2 Option;
+                        FB { FB }
+                    )*
+                }
+                F11R1 {
+                    $(
+                        $f11r1 Option;
+                        FB { FB }
+                    )*
+                }
+                F11R2 {
+                    $(
+                        $f11r2 Option;
+                        FB { FB }
+                    )*
+                }
+                F12R1 {
+                    $(
+                        $f12r1 Option;
+                        FB { FB }
+                    )*
+                }
+                F12R2 {
+                    $(
+                        $f12r2 Option;
+                        FB { FB }
+                    )*
+                }
+                F13R1 {
+                    $(
+                        $f13r1 Option;
+                        FB { FB }
+                    )*
+                }
+                F13R2 {
+                    $(
+                        $f13r2 Option;
+                        FB { FB }
+                    )*
+                }
+                F14R1 {
+                    $(
+                        $f14r1 Option;
+                        FB { FB }
+                    )*
+                }
+                F14R2 {
+                    $(
+                        $f14r2 Option;
+                        FB { FB }
+                    )*
+                }
+                F15R1 {
+                    $(
+                        $f15r1 Option;
+                        FB { FB }
+                    )*
+                }
+                F15R2 {
+                    $(
+                        $f15r2 Option;
+                        FB { FB }
+                    )*
+                }
+                F16R1 {
+                    $(
+                        $f16r1 Option;
+                        FB { FB }
+                    )*
+                }
+                F16R2 {
+                    $(
+                        $f16r2 Option;
+                        FB { FB }
+                    )*
+                }
+                F17R1 {
+                    $(
+                        $f17r1 Option;
+                        FB { FB }
+                    )*
+                }
+                F17R2 {
+                    $(
+                        $f17r2 Option;
+                        FB { FB }
+                    )*
+                }
+                F18R1 {
+                    $(
+                        $f18r1 Option;
+                        FB { FB }
+                    )*
+                }
+                F18R2 {
+                    $(
+                        $f18r2 Option;
+                        FB { FB }
+                    )*
+                }
+                F19R1 {
+                    $(
+                        $f19r1 Option;
+                        FB { FB }
+                    )*
+                }
+                F19R2 {
+                    $(
+                        $f19r2 Option;
+                        FB { FB }
+                    )*
+                }
+                F20R1 {
+                    $(
+                        $f20r1 Option;
+                        FB { FB }
+                    )*
+                }
+                F20R2 {
+                    $(
+                        $f20r2 Option;
+                        FB { FB }
+                    )*
+                }
+                F21R1 {
+                    $(
+                        $f21r1 Option;
+                        FB { FB }
+                    )*
+                }
+                F21R2 {
+                    $(
+                        $f21r2 Option;
+                        FB { FB }
+                    )*
+                }
+                F22R1 {
+                    $(
+                        $f22r1 Option;
+                        FB { FB }
+                    )*
+                }
+                F22R2 {
+                    $(
+                        $f22r2 Option;
+                        FB { FB }
+                    )*
+                }
+                F23R1 {
+                    $(
+                        $f23r1 Option;
+                        FB { FB }
+                    )*
+                }
+                F23R2 {
+                    $(
+                        $f23r2 Option;
+                        FB { FB }
+                    )*
+                }
+                F24R1 {
+                    $(
+                        $f24r1 Option;
+                        FB { FB }
+                    )*
+                }
+                F24R2 {
+                    $(
+                        $f24r2 Option;
+                        FB { FB }
+                    )*
+                }
+                F25R1 {
+                    $(
+                        $f25r1 Option;
+                        FB { FB }
+                    )*
+                }
+                F25R2 {
+                    $(
+                        $f25r2 Option;
+                        FB { FB }
+                    )*
+                }
+                F26R1 {
+                    $(
+                        $f26r1 Option;
+                        FB { FB }
+                    )*
+                }
+                F26R2 {
+                    $(
+                        $f26r2 Option;
+                        FB { FB }
+                    )*
+                }
+                F27R1 {
+                    $(
+                        $f27r1 Option;
+                        FB { FB }
+                    )*
+                }
+                F27R2 {
+                    $(
+                        $f27r2 Option;
+                        FB { FB }
+                    )*
+                }
+            }
+        }
+    };
+}
+
+#[cfg(any(stm32_mcu = "stm32f103"))]
+map_can! {
+    "Extracts CAN1 register tokens.",
+    periph_can1,
+    "CAN1 peripheral variant.",
+    Can1,
+    APB1ENR,
+    APB1RSTR,
+    APB1SMENR,
+    CANEN,
+    CANRST,
+    CANSMEN,
+    CAN1,
+    (USBEN),
+    (FMR,, FM1R, FS1R, FFA1R, FA1R),
+    (F0R1, F0R2, F1R1, F1R2, F2R1, F2R2, F3R1, F3R2, F4R1, F4R2, F5R1, F5R2, F6R1, F6R2, F7R1,
+        F7R2, F8R1, F8R2, F9R1, F9R2, F10R1, F10R2, F11R1, F11R2, F12R1, F12R2, F13R1, F13R2),
+    (),
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32f107",
+    stm32_mcu = "stm32f405",
+    stm32_mcu = "stm32f407",
+    stm32_mcu = "stm32f427",
+    stm32_mcu = "stm32f429",
+    stm32_mcu = "stm32f446",
+    stm32_mcu = "stm32f469",
+))]
+map_can! {
+    "Extracts CAN1 register tokens.",
+    periph_can1,
+    "CAN1 peripheral variant.",
+    Can1,
+    APB1ENR,
+    APB1RSTR,
+    APB1LPENR,
+    CAN1EN,
+    CAN1RST,
+    CAN1LPEN,
+    CAN1,
+    (),
+    (FMR, CAN2SB, FM1R, FS1R, FFA1R, FA1R),
+    (F0R1, F0R2, F1R1, F1R2, F2R1, F2R2, F3R1, F3R2, F4R1, F4R2, F5R1, F5R2, F6R1, F6R2, F7R1,
+        F7R2, F8R1, F8R2, F9R1, F9R2, F10R1, F10R2, F11R1, F11R2, F12R1, F12R2, F13R1, F13R2),
+    (F14R1, F14R2, F15R1, F15R2, F16R1, F16R2, F17R1, F17R2, F18R1, F18R2, F19R1, F19R2, F20R1,
+        F20R2, F21R1, F21R2, F22R1, F22R2, F23R1, F23R2, F24R1, F24R2, F25R1, F25R2, F26R1,
+        F26R2, F27R1, F27R2),
+}
+
+#[cfg(any(stm32_mcu = "stm32l4x6"))]
+map_can! {
+    "Extracts CAN1 register tokens.",
+    periph_can1,
+    "CAN1 peripheral variant.",
+    Can1,
+    APB1ENR1,
+    APB1RSTR1,
+    APB1SMENR1,
+    CAN1EN,
+    CAN1RST,
+    CAN1SMEN,
+    CAN1,
+    (),
+    (FMR, CANSB, FM1R, FS1R, FFA1R, FA1R),
+    (F0R1, F0R2, F1R1, F1R2, F2R1, F2R2, F3R1, F3R2, F4R1, F4R2, F5R1, F5R2, F6R1, F6R2, F7R1,
+        F7R2, F8R1, F8R2, F9R1, F9R2, F10R1, F10R2, F11R1, F11R2, F12R1, F12R2, F13R1, F13R2),
+    (F14R1, F14R2, F15R1, F15R2, F16R1, F16R2, F17R1, F17R2, F18R1, F18R2, F19R1, F19R2, F20R1,
+        F20R2, F21R1, F21R2, F22R1, F22R2, F23R1, F23R2, F24R1, F24R2, F25R1, F25R2, F26R1,
+        F26R2, F27R1, F27R2),
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32f107",
+    stm32_mcu = "stm32f405",
+    stm32_mcu = "stm32f407",
+    stm32_mcu = "stm32f427",
+    stm32_mcu = "stm32f429",
+    stm32_mcu = "stm32f446",
+    stm32_mcu = "stm32f469",
+))]
+map_can! {
+    "Extracts CAN2 register tokens.",
+    periph_can2,
+    "CAN2 peripheral variant.",
+    Can2,
+    APB1ENR,
+    APB1RSTR,
+    APB1LPENR,
+    CAN2EN,
+    CAN2RST,
+    CAN2LPEN,
+    CAN2,
+    (),
+    (),
+    (),
+    (),
+}
+
+#[cfg(any(stm32_mcu = "stm32l4x6"))]
+map_can! {
+    "Extracts CAN2 register tokens.",
+    periph_can2,
+    "CAN2 peripheral variant.",
+    Can2,
+    APB1ENR1,
+    APB1RSTR1,
+    APB1SMENR1,
+    CAN2EN,
+    CAN2RST,
+    CAN2SMEN,
+    CAN2,
+    (),
+    (),
+    (),
+    (),
+}
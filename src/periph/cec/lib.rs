@@ -0,0 +1,79 @@
+//! HDMI-CEC controller.
+//!
+//! STM32F100 has the original CEC block (`CFGR`, `OAR`, `PRES`, `ESR`, `CSR`,
+//! `TXD`, `RXD`). STM32F446 has the newer block with separate `CR`, `ISR` and
+//! `IER` registers. Its kernel clock is selected by `RCC_DCKCFGR2.CECSEL`.
+
+#![feature(proc_macro_hygiene)]
+#![warn(missing_docs)]
+#![warn(clippy::pedantic)]
+#![allow(clippy::type_repetition_in_bounds, clippy::wildcard_imports)]
+#![no_std]
+
+#[allow(unused_imports)]
+use drone_core::periph;
+
+#[cfg(any(stm32_mcu = "stm32f100"))]
+periph::singular! {
+    /// Extracts HDMI-CEC register tokens.
+    pub macro periph_cec;
+
+    /// HDMI-CEC peripheral.
+    pub struct CecPeriph;
+
+    drone_stm32_map_pieces::reg;
+    crate;
+
+    RCC {
+        APB1ENR {
+            CECEN;
+        }
+        APB1RSTR {
+            CECRST;
+        }
+    }
+    CEC {
+        CFGR;
+        OAR;
+        PRES;
+        ESR;
+        CSR;
+        TXD;
+        RXD;
+    }
+}
+
+#[cfg(any(stm32_mcu = "stm32f446"))]
+periph::singular! {
+    /// Extracts HDMI-CEC register tokens.
+    pub macro periph_cec;
+
+    /// HDMI-CEC peripheral.
+    pub struct CecPeriph;
+
+    drone_stm32_map_pieces::reg;
+    crate;
+
+    RCC {
+        APB1ENR {
+            CECEN;
+        }
+        APB1LPENR {
+            CECLPEN;
+        }
+        APB1RSTR {
+            CECRST;
+        }
+        DCKCFGR2 {
+            CECSEL;
+        }
+    }
+    CEC {
+        CR;
+        CFGR;
+        TXDR;
+        RXDR;
+        ISR;
+        IER;
+    }
+}
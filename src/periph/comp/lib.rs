@@ -0,0 +1,182 @@
+//! Comparators.
+//!
+//! The comparator outputs are connected to EXTI lines 21 (COMP1) and 22
+//! (COMP2), which are available as `periph_exti21!` and `periph_exti22!` in
+//! the `exti` crate. Configure those lines to wake up from the comparator
+//! output.
+//!
+//! The comparators have no clock enable bit of their own. They are clocked
+//! together with SYSCFG through the `RCC_APB2ENR.SYSCFGEN` bit.
+
+#![feature(proc_macro_hygiene)]
+#![warn(missing_docs)]
+#![warn(clippy::pedantic)]
+#![allow(clippy::type_repetition_in_bounds, clippy::wildcard_imports)]
+#![no_std]
+
+use drone_core::periph;
+use drone_cortexm::reg::marker::*;
+
+/// EXTI line connected to the COMP1 output.
+pub const COMP1_EXTI_LINE: usize = 21;
+
+/// EXTI line connected to the COMP2 output.
+pub const COMP2_EXTI_LINE: usize = 22;
+
+periph! {
+    /// Generic COMP peripheral variant.
+    pub trait CompMap {}
+
+    /// Generic COMP peripheral.
+    pub struct CompPeriph;
+
+    COMP {
+        CSR {
+            0x20 RwRegBitBand;
+            BLANKING { RwRwRegFieldBits }
+            BRGEN { RwRwRegFieldBitBand }
+            EN { RwRwRegFieldBitBand }
+            HYST { RwRwRegFieldBits }
+            INMESEL { RwRwRegFieldBits Option }
+            INMSEL { RwRwRegFieldBits }
+            #[cfg(any(
+                stm32_mcu = "stm32l4x1",
+                stm32_mcu = "stm32l4x2"
+            ))]
+            INPSEL { RwRwRegFieldBits }
+            #[cfg(any(
+                stm32_mcu = "stm32l4x3",
+                stm32_mcu = "stm32l4x5",
+                stm32_mcu = "stm32l4x6",
+                stm32_mcu = "stm32l4r5",
+                stm32_mcu = "stm32l4r7",
+                stm32_mcu = "stm32l4r9",
+                stm32_mcu = "stm32l4s5",
+                stm32_mcu = "stm32l4s7",
+                stm32_mcu = "stm32l4s9"
+            ))]
+            INPSEL { RwRwRegFieldBitBand }
+            LOCK { RwRwRegFieldBitBand }
+            POLARITY { RwRwRegFieldBitBand }
+            PWRMODE { RwRwRegFieldBits }
+            SCALEN { RwRwRegFieldBitBand }
+            VALUE { RoRwRegFieldBitBand }
+            WINMODE { RwRwRegFieldBitBand Option }
+        }
+    }
+}
+
+#[allow(unused_macros)]
+macro_rules! map_comp {
+    (
+        $comp_macro_doc:expr,
+        $comp_macro:ident,
+        $comp_ty_doc:expr,
+        $comp_ty:ident,
+        $csr:ident,
+        ($($inmesel:ident)?),
+        ($($winmode:ident)?),
+    ) => {
+        periph::map! {
+            #[doc = $comp_macro_doc]
+            pub macro $comp_macro;
+
+            #[doc = $comp_ty_doc]
+            pub struct $comp_ty;
+
+            impl CompMap for $comp_ty {}
+
+            drone_stm32_map_pieces::reg;
+            crate;
+
+            COMP {
+                CSR {
+                    $csr;
+                    BLANKING { BLANKING }
+                    BRGEN { BRGEN }
+                    EN { EN }
+                    HYST { HYST }
+                    INMESEL { $($inmesel Option)* }
+                    INMSEL { INMSEL }
+                    INPSEL { INPSEL }
+                    LOCK { LOCK }
+                    POLARITY { POLARITY }
+                    PWRMODE { PWRMODE }
+                    SCALEN { SCALEN }
+                    VALUE { VALUE }
+                    WINMODE { $($winmode Option)* }
+                }
+            }
+        }
+    };
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32l4x1",
+    stm32_mcu = "stm32l4x2"
+))]
+map_comp! {
+    "Extracts COMP1 register tokens.",
+    periph_comp1,
+    "COMP1 peripheral variant.",
+    Comp1,
+    COMP1_CSR,
+    (INMESEL),
+    (),
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32l4x3",
+    stm32_mcu = "stm32l4x5",
+    stm32_mcu = "stm32l4x6",
+    stm32_mcu = "stm32l4r5",
+    stm32_mcu = "stm32l4r7",
+    stm32_mcu = "stm32l4r9",
+    stm32_mcu = "stm32l4s5",
+    stm32_mcu = "stm32l4s7",
+    stm32_mcu = "stm32l4s9"
+))]
+map_comp! {
+    "Extracts COMP1 register tokens.",
+    periph_comp1,
+    "COMP1 peripheral variant.",
+    Comp1,
+    COMP1_CSR,
+    (),
+    (),
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32l4x1",
+    stm32_mcu = "stm32l4x2"
+))]
+map_comp! {
+    "Extracts COMP2 register tokens.",
+    periph_comp2,
+    "COMP2 peripheral variant.",
+    Comp2,
+    COMP2_CSR,
+    (INMESEL),
+    (WINMODE),
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32l4x3",
+    stm32_mcu = "stm32l4x5",
+    stm32_mcu = "stm32l4x6",
+    stm32_mcu = "stm32l4r5",
+    stm32_mcu = "stm32l4r7",
+    stm32_mcu = "stm32l4r9",
+    stm32_mcu = "stm32l4s5",
+    stm32_mcu = "stm32l4s7",
+    stm32_mcu = "stm32l4s9"
+))]
+map_comp! {
+    "Extracts COMP2 register tokens.",
+    periph_comp2,
+    "COMP2 peripheral variant.",
+    Comp2,
+    COMP2_CSR,
+    (),
+    (WINMODE),
+}
@@ -0,0 +1,299 @@
+//! Cyclic redundancy check calculation unit.
+//!
+//! STM32F1 and STM32F4 have a fixed CRC-32 polynomial. STM32L4 adds a
+//! programmable polynomial, initial value and bit reversal, which appear as
+//! the optional `INIT`, `POL` and `CR` fields of [`CrcMap`].
+
+#![feature(proc_macro_hygiene)]
+#![warn(missing_docs)]
+#![warn(clippy::pedantic)]
+#![allow(clippy::type_repetition_in_bounds, clippy::wildcard_imports)]
+#![no_std]
+
+use drone_core::periph;
+use drone_cortexm::reg::marker::*;
+
+periph! {
+    /// Generic CRC peripheral variant.
+    pub trait CrcMap {}
+
+    /// Generic CRC peripheral.
+    pub struct CrcPeriph;
+
+    RCC {
+        BUSENR {
+            0x20 RwRegBitBand Shared;
+            CRCEN { RwRwRegFieldBitBand }
+        }
+        #[cfg(any(
+            stm32_mcu = "stm32f401",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f410",
+            stm32_mcu = "stm32f411",
+            stm32_mcu = "stm32f412",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469",
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9"
+        ))]
+        BUSRSTR {
+            0x20 RwRegBitBand Shared;
+            CRCRST { RwRwRegFieldBitBand }
+        }
+        #[cfg(any(
+            stm32_mcu = "stm32f401",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f410",
+            stm32_mcu = "stm32f411",
+            stm32_mcu = "stm32f412",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469",
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9"
+        ))]
+        BUSSMENR {
+            0x20 RwRegBitBand Shared;
+            CRCSMEN { RwRwRegFieldBitBand }
+        }
+    }
+    CRC {
+        DR {
+            0x20 RwReg;
+            DR { RwRwRegFieldBits }
+        }
+        IDR {
+            0x20 RwReg;
+            IDR { RwRwRegFieldBits }
+        }
+        CR {
+            0x20 RwReg;
+            POLYSIZE { RwRwRegFieldBits Option }
+            RESET { RwRwRegFieldBit }
+            REV_IN { RwRwRegFieldBits Option }
+            REV_OUT { RwRwRegFieldBit Option }
+        }
+        INIT {
+            0x20 RwReg Option;
+            INIT { RwRwRegFieldBits }
+        }
+        POL {
+            0x20 RwReg Option;
+            POL { RwRwRegFieldBits }
+        }
+    }
+}
+
+#[allow(unused_macros)]
+macro_rules! map_crc {
+    (
+        $busenr:ident,
+        $busrstr:ident,
+        $bussmenr:ident,
+        $crcsmen:ident,
+        ($($polysize:ident)?),
+        ($($rev_in:ident)?),
+        ($($rev_out:ident)?),
+        ($($init:ident)?),
+        ($($pol:ident)?),
+    ) => {
+        periph::map! {
+            /// Extracts CRC register tokens.
+            pub macro periph_crc;
+
+            /// CRC peripheral variant.
+            pub struct Crc;
+
+            impl CrcMap for Crc {}
+
+            drone_stm32_map_pieces::reg;
+            crate;
+
+            RCC {
+                BUSENR {
+                    $busenr Shared;
+                    CRCEN { CRCEN }
+                }
+                #[cfg(any(
+                    stm32_mcu = "stm32f401",
+                    stm32_mcu = "stm32f405",
+                    stm32_mcu = "stm32f407",
+                    stm32_mcu = "stm32f410",
+                    stm32_mcu = "stm32f411",
+                    stm32_mcu = "stm32f412",
+                    stm32_mcu = "stm32f413",
+                    stm32_mcu = "stm32f427",
+                    stm32_mcu = "stm32f429",
+                    stm32_mcu = "stm32f446",
+                    stm32_mcu = "stm32f469",
+                    stm32_mcu = "stm32l4x1",
+                    stm32_mcu = "stm32l4x2",
+                    stm32_mcu = "stm32l4x3",
+                    stm32_mcu = "stm32l4x5",
+                    stm32_mcu = "stm32l4x6",
+                    stm32_mcu = "stm32l4r5",
+                    stm32_mcu = "stm32l4r7",
+                    stm32_mcu = "stm32l4r9",
+                    stm32_mcu = "stm32l4s5",
+                    stm32_mcu = "stm32l4s7",
+                    stm32_mcu = "stm32l4s9"
+                ))]
+                BUSRSTR {
+                    $busrstr Shared;
+                    CRCRST { CRCRST }
+                }
+                #[cfg(any(
+                    stm32_mcu = "stm32f401",
+                    stm32_mcu = "stm32f405",
+                    stm32_mcu = "stm32f407",
+                    stm32_mcu = "stm32f410",
+                    stm32_mcu = "stm32f411",
+                    stm32_mcu = "stm32f412",
+                    stm32_mcu = "stm32f413",
+                    stm32_mcu = "stm32f427",
+                    stm32_mcu = "stm32f429",
+                    stm32_mcu = "stm32f446",
+                    stm32_mcu = "stm32f469",
+                    stm32_mcu = "stm32l4x1",
+                    stm32_mcu = "stm32l4x2",
+                    stm32_mcu = "stm32l4x3",
+                    stm32_mcu = "stm32l4x5",
+                    stm32_mcu = "stm32l4x6",
+                    stm32_mcu = "stm32l4r5",
+                    stm32_mcu = "stm32l4r7",
+                    stm32_mcu = "stm32l4r9",
+                    stm32_mcu = "stm32l4s5",
+                    stm32_mcu = "stm32l4s7",
+                    stm32_mcu = "stm32l4s9"
+                ))]
+                BUSSMENR {
+                    $bussmenr Shared;
+                    CRCSMEN { $crcsmen }
+                }
+            }
+            CRC {
+                DR {
+                    DR;
+                    DR { DR }
+                }
+                IDR {
+                    IDR;
+                    IDR { IDR }
+                }
+                CR {
+                    CR;
+                    POLYSIZE { $($polysize Option)* }
+                    RESET { RESET }
+                    REV_IN { $($rev_in Option)* }
+                    REV_OUT { $($rev_out Option)* }
+                }
+                INIT {
+                    $(
+                        $init Option;
+                        INIT { INIT }
+                    )*
+                }
+                POL {
+                    $(
+                        $pol Option;
+                        POL { POL }
+                    )*
+                }
+            }
+        }
+    };
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32f100",
+    stm32_mcu = "stm32f101",
+    stm32_mcu = "stm32f102",
+    stm32_mcu = "stm32f103",
+    stm32_mcu = "stm32f107"
+))]
+map_crc! {
+    AHBENR,
+    AHBRSTR,
+    AHBSMENR,
+    CRCSMEN,
+    (),
+    (),
+    (),
+    (),
+    (),
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32f401",
+    stm32_mcu = "stm32f405",
+    stm32_mcu = "stm32f407",
+    stm32_mcu = "stm32f410",
+    stm32_mcu = "stm32f411",
+    stm32_mcu = "stm32f412",
+    stm32_mcu = "stm32f413",
+    stm32_mcu = "stm32f427",
+    stm32_mcu = "stm32f429",
+    stm32_mcu = "stm32f446",
+    stm32_mcu = "stm32f469"
+))]
+map_crc! {
+    AHB1ENR,
+    AHB1RSTR,
+    AHB1LPENR,
+    CRCLPEN,
+    (),
+    (),
+    (),
+    (),
+    (),
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32l4x1",
+    stm32_mcu = "stm32l4x2",
+    stm32_mcu = "stm32l4x3",
+    stm32_mcu = "stm32l4x5",
+    stm32_mcu = "stm32l4x6",
+    stm32_mcu = "stm32l4r5",
+    stm32_mcu = "stm32l4r7",
+    stm32_mcu = "stm32l4r9",
+    stm32_mcu = "stm32l4s5",
+    stm32_mcu = "stm32l4s7",
+    stm32_mcu = "stm32l4s9"
+))]
+map_crc! {
+    AHB1ENR,
+    AHB1RSTR,
+    AHB1SMENR,
+    CRCSMEN,
+    (POLYSIZE),
+    (REV_IN),
+    (REV_OUT),
+    (INIT),
+    (POL),
+}
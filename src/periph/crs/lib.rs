@@ -0,0 +1,55 @@
+//! Clock recovery system.
+//!
+//! The CRS trims the HSI48 oscillator against an external synchronization
+//! source, such as the USB start-of-frame packets, which is required for
+//! crystal-less USB operation.
+
+#![feature(proc_macro_hygiene)]
+#![warn(missing_docs)]
+#![warn(clippy::pedantic)]
+#![allow(clippy::type_repetition_in_bounds, clippy::wildcard_imports)]
+#![no_std]
+
+#[allow(unused_imports)]
+use drone_core::periph;
+
+#[cfg(any(
+    stm32_mcu = "stm32l4x1",
+    stm32_mcu = "stm32l4x2",
+    stm32_mcu = "stm32l4x3",
+    stm32_mcu = "stm32l4x6",
+    stm32_mcu = "stm32l4r5",
+    stm32_mcu = "stm32l4r7",
+    stm32_mcu = "stm32l4r9",
+    stm32_mcu = "stm32l4s5",
+    stm32_mcu = "stm32l4s7",
+    stm32_mcu = "stm32l4s9"
+))]
+periph::singular! {
+    /// Extracts CRS register tokens.
+    pub macro periph_crs;
+
+    /// CRS peripheral.
+    pub struct CrsPeriph;
+
+    drone_stm32_map_pieces::reg;
+    crate;
+
+    RCC {
+        APB1ENR1 {
+            CRSEN;
+        }
+        APB1RSTR1 {
+            CRSRST;
+        }
+        APB1SMENR1 {
+            CRSSMEN;
+        }
+    }
+    CRS {
+        CR;
+        CFGR;
+        ISR;
+        ICR;
+    }
+}
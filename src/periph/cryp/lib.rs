@@ -0,0 +1,163 @@
+//! Cryptographic processor.
+//!
+//! The processor is present only on the STM32F415, STM32F417, STM32F437,
+//! STM32F439 and STM32F479 devices. These share the `stm32_mcu` value of their
+//! counterparts without the processor: `stm32f405`, `stm32f407`, `stm32f427`,
+//! `stm32f429` and `stm32f469`, respectively.
+
+#![feature(proc_macro_hygiene)]
+#![warn(missing_docs)]
+#![warn(clippy::pedantic)]
+#![allow(clippy::type_repetition_in_bounds, clippy::wildcard_imports)]
+#![no_std]
+
+#[allow(unused_imports)]
+use drone_core::periph;
+
+#[cfg(any(
+    stm32_mcu = "stm32f405",
+    stm32_mcu = "stm32f407",
+    stm32_mcu = "stm32f427",
+    stm32_mcu = "stm32f429",
+    stm32_mcu = "stm32f469"
+))]
+periph::singular! {
+    /// Extracts CRYP register tokens.
+    pub macro periph_cryp;
+
+    /// CRYP peripheral.
+    pub struct CrypPeriph;
+
+    drone_stm32_map_pieces::reg;
+    crate;
+
+    RCC {
+        AHB2ENR {
+            CRYPEN;
+        }
+        AHB2RSTR {
+            CRYPRST;
+        }
+        AHB2LPENR {
+            CRYPLPEN;
+        }
+    }
+    CRYP {
+        CR;
+        SR;
+        DIN;
+        DOUT;
+        DMACR;
+        IMSCR;
+        RISR;
+        MISR;
+        K0LR;
+        K0RR;
+        K1LR;
+        K1RR;
+        K2LR;
+        K2RR;
+        K3LR;
+        K3RR;
+        IV0LR;
+        IV0RR;
+        IV1LR;
+        IV1RR;
+        #[cfg(any(
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f469"
+        ))]
+        CSGCMCCM0R;
+        #[cfg(any(
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f469"
+        ))]
+        CSGCMCCM1R;
+        #[cfg(any(
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f469"
+        ))]
+        CSGCMCCM2R;
+        #[cfg(any(
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f469"
+        ))]
+        CSGCMCCM3R;
+        #[cfg(any(
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f469"
+        ))]
+        CSGCMCCM4R;
+        #[cfg(any(
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f469"
+        ))]
+        CSGCMCCM5R;
+        #[cfg(any(
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f469"
+        ))]
+        CSGCMCCM6R;
+        #[cfg(any(
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f469"
+        ))]
+        CSGCMCCM7R;
+        #[cfg(any(
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f469"
+        ))]
+        CSGCM0R;
+        #[cfg(any(
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f469"
+        ))]
+        CSGCM1R;
+        #[cfg(any(
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f469"
+        ))]
+        CSGCM2R;
+        #[cfg(any(
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f469"
+        ))]
+        CSGCM3R;
+        #[cfg(any(
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f469"
+        ))]
+        CSGCM4R;
+        #[cfg(any(
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f469"
+        ))]
+        CSGCM5R;
+        #[cfg(any(
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f469"
+        ))]
+        CSGCM6R;
+        #[cfg(any(
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f469"
+        ))]
+        CSGCM7R;
+    }
+}
@@ -0,0 +1,463 @@
+//! Digital-to-analog converter.
+//!
+//! STM32L4 adds calibration, sample-and-hold and mode registers, which appear
+//! as the optional `CCR`, `MCR`, `SHSR1`, `SHSR2`, `SHHR` and `SHRR` registers
+//! of [`DacMap`]. The output buffer disable bits `BOFF1` and `BOFF2` exist on
+//! STM32F1 and STM32F4 only.
+
+#![feature(proc_macro_hygiene)]
+#![warn(missing_docs)]
+#![warn(clippy::pedantic)]
+#![allow(clippy::type_repetition_in_bounds, clippy::wildcard_imports)]
+#![no_std]
+
+use drone_core::periph;
+use drone_cortexm::reg::marker::*;
+
+periph! {
+    /// Generic DAC peripheral variant.
+    pub trait DacMap {}
+
+    /// Generic DAC peripheral.
+    pub struct DacPeriph;
+
+    RCC {
+        BUSENR {
+            0x20 RwRegBitBand Shared;
+            DACEN { RwRwRegFieldBitBand }
+        }
+        BUSRSTR {
+            0x20 RwRegBitBand Shared;
+            DACRST { RwRwRegFieldBitBand }
+        }
+        #[cfg(any(
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f410",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469",
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9"
+        ))]
+        BUSSMENR {
+            0x20 RwRegBitBand Shared;
+            DACSMEN { RwRwRegFieldBitBand }
+        }
+    }
+    DAC {
+        CR {
+            0x20 RwRegBitBand;
+            BOFF1 { RwRwRegFieldBitBand Option }
+            BOFF2 { RwRwRegFieldBitBand Option }
+            CEN1 { RwRwRegFieldBitBand Option }
+            CEN2 { RwRwRegFieldBitBand Option }
+            DMAEN1 { RwRwRegFieldBitBand }
+            DMAEN2 { RwRwRegFieldBitBand }
+            DMAUDRIE1 { RwRwRegFieldBitBand }
+            DMAUDRIE2 { RwRwRegFieldBitBand }
+            EN1 { RwRwRegFieldBitBand }
+            EN2 { RwRwRegFieldBitBand }
+            MAMP1 { RwRwRegFieldBits }
+            MAMP2 { RwRwRegFieldBits }
+            TEN1 { RwRwRegFieldBitBand }
+            TEN2 { RwRwRegFieldBitBand }
+            TSEL1 { RwRwRegFieldBits }
+            TSEL2 { RwRwRegFieldBits }
+            WAVE1 { RwRwRegFieldBits }
+            WAVE2 { RwRwRegFieldBits }
+        }
+        SWTRIGR {
+            0x20 WoRegBitBand;
+            SWTRIG1 { WoWoRegFieldBitBand }
+            SWTRIG2 { WoWoRegFieldBitBand }
+        }
+        DHR12R1 {
+            0x20 RwRegBitBand;
+            DACC1DHR { RwRwRegFieldBits }
+        }
+        DHR12L1 {
+            0x20 RwRegBitBand;
+            DACC1DHR { RwRwRegFieldBits }
+        }
+        DHR8R1 {
+            0x20 RwRegBitBand;
+            DACC1DHR { RwRwRegFieldBits }
+        }
+        DHR12R2 {
+            0x20 RwRegBitBand;
+            DACC2DHR { RwRwRegFieldBits }
+        }
+        DHR12L2 {
+            0x20 RwRegBitBand;
+            DACC2DHR { RwRwRegFieldBits }
+        }
+        DHR8R2 {
+            0x20 RwRegBitBand;
+            DACC2DHR { RwRwRegFieldBits }
+        }
+        DHR12RD {
+            0x20 RwRegBitBand;
+            DACC1DHR { RwRwRegFieldBits }
+            DACC2DHR { RwRwRegFieldBits }
+        }
+        DHR12LD {
+            0x20 RwRegBitBand;
+            DACC1DHR { RwRwRegFieldBits }
+            DACC2DHR { RwRwRegFieldBits }
+        }
+        DHR8RD {
+            0x20 RwRegBitBand;
+            DACC1DHR { RwRwRegFieldBits }
+            DACC2DHR { RwRwRegFieldBits }
+        }
+        DOR1 {
+            0x20 RoRegBitBand;
+            DACC1DOR { RoRoRegFieldBits }
+        }
+        DOR2 {
+            0x20 RoRegBitBand;
+            DACC2DOR { RoRoRegFieldBits }
+        }
+        SR {
+            0x20 RwRegBitBand;
+            BWST1 { RoRwRegFieldBitBand Option }
+            BWST2 { RoRwRegFieldBitBand Option }
+            CAL_FLAG1 { RoRwRegFieldBitBand Option }
+            CAL_FLAG2 { RoRwRegFieldBitBand Option }
+            DMAUDR1 { RwRwRegFieldBitBand }
+            DMAUDR2 { RwRwRegFieldBitBand }
+        }
+        CCR {
+            0x20 RwRegBitBand Option;
+            OTRIM1 { RwRwRegFieldBits }
+            OTRIM2 { RwRwRegFieldBits }
+        }
+        MCR {
+            0x20 RwRegBitBand Option;
+            MODE1 { RwRwRegFieldBits }
+            MODE2 { RwRwRegFieldBits }
+        }
+        SHSR1 {
+            0x20 RwRegBitBand Option;
+            TSAMPLE1 { RwRwRegFieldBits }
+        }
+        SHSR2 {
+            0x20 RwRegBitBand Option;
+            TSAMPLE2 { RwRwRegFieldBits }
+        }
+        SHHR {
+            0x20 RwRegBitBand Option;
+            THOLD1 { RwRwRegFieldBits }
+            THOLD2 { RwRwRegFieldBits }
+        }
+        SHRR {
+            0x20 RwRegBitBand Option;
+            TREFRESH1 { RwRwRegFieldBits }
+            TREFRESH2 { RwRwRegFieldBits }
+        }
+    }
+}
+
+#[allow(unused_macros)]
+macro_rules! map_dac {
+    (
+        $busenr:ident,
+        $busrstr:ident,
+        $bussmenr:ident,
+        $dacen:ident,
+        $dacrst:ident,
+        $dacsmen:ident,
+        ($($boff1:ident)?),
+        ($($boff2:ident)?),
+        ($($cen1:ident)?),
+        ($($cen2:ident)?),
+        ($($cal_flag1:ident)?),
+        ($($cal_flag2:ident)?),
+        ($($bwst1:ident)?),
+        ($($bwst2:ident)?),
+        ($($ccr:ident)?),
+        ($($mcr:ident)?),
+        ($($shsr1:ident)?),
+        ($($shsr2:ident)?),
+        ($($shhr:ident)?),
+        ($($shrr:ident)?),
+    ) => {
+        periph::map! {
+            /// Extracts DAC register tokens.
+            pub macro periph_dac;
+
+            /// DAC peripheral variant.
+            pub struct Dac;
+
+            impl DacMap for Dac {}
+
+            drone_stm32_map_pieces::reg;
+            crate;
+
+            RCC {
+                BUSENR {
+                    $busenr Shared;
+                    DACEN { $dacen }
+                }
+                BUSRSTR {
+                    $busrstr Shared;
+                    DACRST { $dacrst }
+                }
+                #[cfg(any(
+                    stm32_mcu = "stm32f405",
+                    stm32_mcu = "stm32f407",
+                    stm32_mcu = "stm32f410",
+                    stm32_mcu = "stm32f413",
+                    stm32_mcu = "stm32f427",
+                    stm32_mcu = "stm32f429",
+                    stm32_mcu = "stm32f446",
+                    stm32_mcu = "stm32f469",
+                    stm32_mcu = "stm32l4x1",
+                    stm32_mcu = "stm32l4x2",
+                    stm32_mcu = "stm32l4x3",
+                    stm32_mcu = "stm32l4x5",
+                    stm32_mcu = "stm32l4x6",
+                    stm32_mcu = "stm32l4r5",
+                    stm32_mcu = "stm32l4r7",
+                    stm32_mcu = "stm32l4r9",
+                    stm32_mcu = "stm32l4s5",
+                    stm32_mcu = "stm32l4s7",
+                    stm32_mcu = "stm32l4s9"
+                ))]
+                BUSSMENR {
+                    $bussmenr Shared;
+                    DACSMEN { $dacsmen }
+                }
+            }
+            DAC {
+                CR {
+                    CR;
+                    BOFF1 { $($boff1 Option)* }
+                    BOFF2 { $($boff2 Option)* }
+                    CEN1 { $($cen1 Option)* }
+                    CEN2 { $($cen2 Option)* }
+                    DMAEN1 { DMAEN1 }
+                    DMAEN2 { DMAEN2 }
+                    DMAUDRIE1 { DMAUDRIE1 }
+                    DMAUDRIE2 { DMAUDRIE2 }
+                    EN1 { EN1 }
+                    EN2 { EN2 }
+                    MAMP1 { MAMP1 }
+                    MAMP2 { MAMP2 }
+                    TEN1 { TEN1 }
+                    TEN2 { TEN2 }
+                    TSEL1 { TSEL1 }
+                    TSEL2 { TSEL2 }
+                    WAVE1 { WAVE1 }
+                    WAVE2 { WAVE2 }
+                }
+                SWTRIGR {
+                    SWTRIGR;
+                    SWTRIG1 { SWTRIG1 }
+                    SWTRIG2 { SWTRIG2 }
+                }
+                DHR12R1 {
+                    DHR12R1;
+                    DACC1DHR { DACC1DHR }
+                }
+                DHR12L1 {
+                    DHR12L1;
+                    DACC1DHR { DACC1DHR }
+                }
+                DHR8R1 {
+                    DHR8R1;
+                    DACC1DHR { DACC1DHR }
+                }
+                DHR12R2 {
+                    DHR12R2;
+                    DACC2DHR { DACC2DHR }
+                }
+                DHR12L2 {
+                    DHR12L2;
+                    DACC2DHR { DACC2DHR }
+                }
+                DHR8R2 {
+                    DHR8R2;
+                    DACC2DHR { DACC2DHR }
+                }
+                DHR12RD {
+                    DHR12RD;
+                    DACC1DHR { DACC1DHR }
+                    DACC2DHR { DACC2DHR }
+                }
+                DHR12LD {
+                    DHR12LD;
+                    DACC1DHR { DACC1DHR }
+                    DACC2DHR { DACC2DHR }
+                }
+                DHR8RD {
+                    DHR8RD;
+                    DACC1DHR { DACC1DHR }
+                    DACC2DHR { DACC2DHR }
+                }
+                DOR1 {
+                    DOR1;
+                    DACC1DOR { DACC1DOR }
+                }
+                DOR2 {
+                    DOR2;
+                    DACC2DOR { DACC2DOR }
+                }
+                SR {
+                    SR;
+                    BWST1 { $($bwst1 Option)* }
+                    BWST2 { $($bwst2 Option)* }
+                    CAL_FLAG1 { $($cal_flag1 Option)* }
+                    CAL_FLAG2 { $($cal_flag2 Option)* }
+                    DMAUDR1 { DMAUDR1 }
+                    DMAUDR2 { DMAUDR2 }
+                }
+                CCR {
+                    $(
+                        $ccr Option;
+                        OTRIM1 { OTRIM1 }
+                        OTRIM2 { OTRIM2 }
+                    )*
+                }
+                MCR {
+                    $(
+                        $mcr Option;
+                        MODE1 { MODE1 }
+                        MODE2 { MODE2 }
+                    )*
+                }
+                SHSR1 {
+                    $(
+                        $shsr1 Option;
+                        TSAMPLE1 { TSAMPLE1 }
+                    )*
+                }
+                SHSR2 {
+                    $(
+                        $shsr2 Option;
+                        TSAMPLE2 { TSAMPLE2 }
+                    )*
+                }
+                SHHR {
+                    $(
+                        $shhr Option;
+                        THOLD1 { THOLD1 }
+                        THOLD2 { THOLD2 }
+                    )*
+                }
+                SHRR {
+                    $(
+                        $shrr Option;
+                        TREFRESH1 { TREFRESH1 }
+                        TREFRESH2 { TREFRESH2 }
+                    )*
+                }
+            }
+        }
+    };
+}
+
+#[cfg(any(stm32_mcu = "stm32f100"))]
+map_dac! {
+    APB1ENR,
+    APB1RSTR,
+    APB1SMENR,
+    DACEN,
+    DACRST,
+    DACSMEN,
+    (BOFF1),
+    (BOFF2),
+    (),
+    (),
+    (),
+    (),
+    (),
+    (),
+    (),
+    (),
+    (),
+    (),
+    (),
+    (),
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32f405",
+    stm32_mcu = "stm32f407",
+    stm32_mcu = "stm32f410",
+    stm32_mcu = "stm32f413",
+    stm32_mcu = "stm32f427",
+    stm32_mcu = "stm32f429",
+    stm32_mcu = "stm32f446",
+    stm32_mcu = "stm32f469"
+))]
+map_dac! {
+    APB1ENR,
+    APB1RSTR,
+    APB1LPENR,
+    DACEN,
+    DACRST,
+    DACLPEN,
+    (BOFF1),
+    (BOFF2),
+    (),
+    (),
+    (),
+    (),
+    (),
+    (),
+    (),
+    (),
+    (),
+    (),
+    (),
+    (),
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32l4x1",
+    stm32_mcu = "stm32l4x2",
+    stm32_mcu = "stm32l4x3",
+    stm32_mcu = "stm32l4x5",
+    stm32_mcu = "stm32l4x6",
+    stm32_mcu = "stm32l4r5",
+    stm32_mcu = "stm32l4r7",
+    stm32_mcu = "stm32l4r9",
+    stm32_mcu = "stm32l4s5",
+    stm32_mcu = "stm32l4s7",
+    stm32_mcu = "stm32l4s9"
+))]
+map_dac! {
+    APB1ENR1,
+    APB1RSTR1,
+    APB1SMENR1,
+    DAC1EN,
+    DAC1RST,
+    DAC1SMEN,
+    (),
+    (),
+    (CEN1),
+    (CEN2),
+    (CAL_FLAG1),
+    (CAL_FLAG2),
+    (BWST1),
+    (BWST2),
+    (CCR),
+    (MCR),
+    (SHSR1),
+    (SHSR2),
+    (SHHR),
+    (SHRR),
+}
@@ -0,0 +1,343 @@
+//! DFSDM channels.
+
+use drone_core::periph;
+use drone_cortexm::reg::marker::*;
+
+periph! {
+    /// Generic DFSDM channel peripheral variant.
+    pub trait DfsdmChMap {
+        /// DFSDM head peripheral variant.
+        type DfsdmMap: super::DfsdmMap;
+    }
+
+    /// Generic DFSDM channel peripheral.
+    pub struct DfsdmChPeriph;
+
+    DFSDM {
+        CHCFGR1 {
+            0x20 RwRegBitBand;
+            CHEN { RwRwRegFieldBitBand }
+            CHINSEL { RwRwRegFieldBitBand }
+            CKABEN { RwRwRegFieldBitBand }
+            CKOUTDIV { RwRwRegFieldBits Option }
+            CKOUTSRC { RwRwRegFieldBitBand Option }
+            DATMPX { RwRwRegFieldBits }
+            DATPACK { RwRwRegFieldBits }
+            DFSDMEN { RwRwRegFieldBitBand Option }
+            SCDEN { RwRwRegFieldBitBand }
+            SITP { RwRwRegFieldBits }
+            SPICKSEL { RwRwRegFieldBits }
+        }
+        CHCFGR2 {
+            0x20 RwRegBitBand;
+            DTRBS { RwRwRegFieldBits }
+            OFFSET { RwRwRegFieldBits }
+        }
+        AWSCDR {
+            0x20 RwRegBitBand;
+            AWFORD { RwRwRegFieldBits }
+            AWFOSR { RwRwRegFieldBits }
+            BKSCD { RwRwRegFieldBits }
+            SCDT { RwRwRegFieldBits }
+        }
+        CHWDATR {
+            0x20 RwRegBitBand;
+            WDATA { RwRwRegFieldBits }
+        }
+        CHDATINR {
+            0x20 RwRegBitBand;
+            INDAT0 { RwRwRegFieldBits }
+            INDAT1 { RwRwRegFieldBits }
+        }
+    }
+}
+
+#[allow(unused_macros)]
+macro_rules! map_dfsdm_ch {
+    (
+        $dfsdm_ch_macro_doc:expr,
+        $dfsdm_ch_macro:ident,
+        $dfsdm_ch_ty_doc:expr,
+        $dfsdm_ch_ty:ident,
+        $dfsdm_ty:ident,
+        $dfsdm:ident,
+        $chcfgr1:ident,
+        $chcfgr2:ident,
+        $awscdr:ident,
+        $chwdatr:ident,
+        $chdatinr:ident,
+        ($($ckoutdiv:ident)?),
+        ($($ckoutsrc:ident)?),
+        ($($dfsdmen:ident)?),
+    ) => {
+        periph::map! {
+            #[doc = $dfsdm_ch_macro_doc]
+            pub macro $dfsdm_ch_macro;
+
+            #[doc = $dfsdm_ch_ty_doc]
+            pub struct $dfsdm_ch_ty;
+
+            impl DfsdmChMap for $dfsdm_ch_ty {
+                type DfsdmMap = super::$dfsdm_ty;
+            }
+
+            drone_stm32_map_pieces::reg;
+            crate::ch;
+
+            DFSDM {
+                $dfsdm;
+                CHCFGR1 {
+                    $chcfgr1;
+                    CHEN { CHEN }
+                    CHINSEL { CHINSEL }
+                    CKABEN { CKABEN }
+                    CKOUTDIV { $($ckoutdiv Option)* }
+                    CKOUTSRC { $($ckoutsrc Option)* }
+                    DATMPX { DATMPX }
+                    DATPACK { DATPACK }
+                    DFSDMEN { $($dfsdmen Option)* }
+                    SCDEN { SCDEN }
+                    SITP { SITP }
+                    SPICKSEL { SPICKSEL }
+                }
+                CHCFGR2 {
+                    $chcfgr2;
+                    DTRBS { DTRBS }
+                    OFFSET { OFFSET }
+                }
+                AWSCDR {
+                    $awscdr;
+                    AWFORD { AWFORD }
+                    AWFOSR { AWFOSR }
+                    BKSCD { BKSCD }
+                    SCDT { SCDT }
+                }
+                CHWDATR {
+                    $chwdatr;
+                    WDATA { WDATA }
+                }
+                CHDATINR {
+                    $chdatinr;
+                    INDAT0 { INDAT0 }
+                    INDAT1 { INDAT1 }
+                }
+            }
+        }
+    };
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32l4x5",
+    stm32_mcu = "stm32l4x6",
+    stm32_mcu = "stm32l4r5",
+    stm32_mcu = "stm32l4r7",
+    stm32_mcu = "stm32l4r9",
+    stm32_mcu = "stm32l4s5",
+    stm32_mcu = "stm32l4s7",
+    stm32_mcu = "stm32l4s9"
+))]
+map_dfsdm_ch! {
+    "Extracts DFSDM1 channel 0 register tokens.",
+    periph_dfsdm1_ch0,
+    "DFSDM1 channel 0 peripheral variant.",
+    Dfsdm1Ch0,
+    Dfsdm1,
+    DFSDM1,
+    CHCFG0R1,
+    CHCFG0R2,
+    AWSCD0R,
+    CHWDAT0R,
+    CHDATIN0R,
+    (CKOUTDIV),
+    (CKOUTSRC),
+    (DFSDMEN),
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32l4x5",
+    stm32_mcu = "stm32l4x6",
+    stm32_mcu = "stm32l4r5",
+    stm32_mcu = "stm32l4r7",
+    stm32_mcu = "stm32l4r9",
+    stm32_mcu = "stm32l4s5",
+    stm32_mcu = "stm32l4s7",
+    stm32_mcu = "stm32l4s9"
+))]
+map_dfsdm_ch! {
+    "Extracts DFSDM1 channel 1 register tokens.",
+    periph_dfsdm1_ch1,
+    "DFSDM1 channel 1 peripheral variant.",
+    Dfsdm1Ch1,
+    Dfsdm1,
+    DFSDM1,
+    CHCFG1R1,
+    CHCFG1R2,
+    AWSCD1R,
+    CHWDAT1R,
+    CHDATIN1R,
+    (),
+    (),
+    (),
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32l4x5",
+    stm32_mcu = "stm32l4x6",
+    stm32_mcu = "stm32l4r5",
+    stm32_mcu = "stm32l4r7",
+    stm32_mcu = "stm32l4r9",
+    stm32_mcu = "stm32l4s5",
+    stm32_mcu = "stm32l4s7",
+    stm32_mcu = "stm32l4s9"
+))]
+map_dfsdm_ch! {
+    "Extracts DFSDM1 channel 2 register tokens.",
+    periph_dfsdm1_ch2,
+    "DFSDM1 channel 2 peripheral variant.",
+    Dfsdm1Ch2,
+    Dfsdm1,
+    DFSDM1,
+    CHCFG2R1,
+    CHCFG2R2,
+    AWSCD2R,
+    CHWDAT2R,
+    CHDATIN2R,
+    (),
+    (),
+    (),
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32l4x5",
+    stm32_mcu = "stm32l4x6",
+    stm32_mcu = "stm32l4r5",
+    stm32_mcu = "stm32l4r7",
+    stm32_mcu = "stm32l4r9",
+    stm32_mcu = "stm32l4s5",
+    stm32_mcu = "stm32l4s7",
+    stm32_mcu = "stm32l4s9"
+))]
+map_dfsdm_ch! {
+    "Extracts DFSDM1 channel 3 register tokens.",
+    periph_dfsdm1_ch3,
+    "DFSDM1 channel 3 peripheral variant.",
+    Dfsdm1Ch3,
+    Dfsdm1,
+    DFSDM1,
+    CHCFG3R1,
+    CHCFG3R2,
+    AWSCD3R,
+    CHWDAT3R,
+    CHDATIN3R,
+    (),
+    (),
+    (),
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32l4x5",
+    stm32_mcu = "stm32l4x6",
+    stm32_mcu = "stm32l4r5",
+    stm32_mcu = "stm32l4r7",
+    stm32_mcu = "stm32l4r9",
+    stm32_mcu = "stm32l4s5",
+    stm32_mcu = "stm32l4s7",
+    stm32_mcu = "stm32l4s9"
+))]
+map_dfsdm_ch! {
+    "Extracts DFSDM1 channel 4 register tokens.",
+    periph_dfsdm1_ch4,
+    "DFSDM1 channel 4 peripheral variant.",
+    Dfsdm1Ch4,
+    Dfsdm1,
+    DFSDM1,
+    CHCFG4R1,
+    CHCFG4R2,
+    AWSCD4R,
+    CHWDAT4R,
+    CHDATIN4R,
+    (),
+    (),
+    (),
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32l4x5",
+    stm32_mcu = "stm32l4x6",
+    stm32_mcu = "stm32l4r5",
+    stm32_mcu = "stm32l4r7",
+    stm32_mcu = "stm32l4r9",
+    stm32_mcu = "stm32l4s5",
+    stm32_mcu = "stm32l4s7",
+    stm32_mcu = "stm32l4s9"
+))]
+map_dfsdm_ch! {
+    "Extracts DFSDM1 channel 5 register tokens.",
+    periph_dfsdm1_ch5,
+    "DFSDM1 channel 5 peripheral variant.",
+    Dfsdm1Ch5,
+    Dfsdm1,
+    DFSDM1,
+    CHCFG5R1,
+    CHCFG5R2,
+    AWSCD5R,
+    CHWDAT5R,
+    CHDATIN5R,
+    (),
+    (),
+    (),
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32l4x5",
+    stm32_mcu = "stm32l4x6",
+    stm32_mcu = "stm32l4r5",
+    stm32_mcu = "stm32l4r7",
+    stm32_mcu = "stm32l4r9",
+    stm32_mcu = "stm32l4s5",
+    stm32_mcu = "stm32l4s7",
+    stm32_mcu = "stm32l4s9"
+))]
+map_dfsdm_ch! {
+    "Extracts DFSDM1 channel 6 register tokens.",
+    periph_dfsdm1_ch6,
+    "DFSDM1 channel 6 peripheral variant.",
+    Dfsdm1Ch6,
+    Dfsdm1,
+    DFSDM1,
+    CHCFG6R1,
+    CHCFG6R2,
+    AWSCD6R,
+    CHWDAT6R,
+    CHDATIN6R,
+    (),
+    (),
+    (),
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32l4x5",
+    stm32_mcu = "stm32l4x6",
+    stm32_mcu = "stm32l4r5",
+    stm32_mcu = "stm32l4r7",
+    stm32_mcu = "stm32l4r9",
+    stm32_mcu = "stm32l4s5",
+    stm32_mcu = "stm32l4s7",
+    stm32_mcu = "stm32l4s9"
+))]
+map_dfsdm_ch! {
+    "Extracts DFSDM1 channel 7 register tokens.",
+    periph_dfsdm1_ch7,
+    "DFSDM1 channel 7 peripheral variant.",
+    Dfsdm1Ch7,
+    Dfsdm1,
+    DFSDM1,
+    CHCFG7R1,
+    CHCFG7R2,
+    AWSCD7R,
+    CHWDAT7R,
+    CHDATIN7R,
+    (),
+    (),
+    (),
+}
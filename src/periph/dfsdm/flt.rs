@@ -0,0 +1,406 @@
+//! DFSDM digital filters.
+
+use drone_core::periph;
+use drone_cortexm::reg::marker::*;
+
+periph! {
+    /// Generic DFSDM filter peripheral variant.
+    pub trait DfsdmFltMap {
+        /// DFSDM head peripheral variant.
+        type DfsdmMap: super::DfsdmMap;
+    }
+
+    /// Generic DFSDM filter peripheral.
+    pub struct DfsdmFltPeriph;
+
+    DFSDM {
+        CR1 {
+            0x20 RwRegBitBand;
+            AWFSEL { RwRwRegFieldBitBand }
+            DFEN { RwRwRegFieldBitBand }
+            FAST { RwRwRegFieldBitBand }
+            JDMAEN { RwRwRegFieldBitBand }
+            JEXTEN { RwRwRegFieldBits }
+            JEXTSEL { RwRwRegFieldBits }
+            JSCAN { RwRwRegFieldBitBand }
+            JSWSTART { RwRwRegFieldBitBand }
+            JSYNC { RwRwRegFieldBitBand }
+            RCH { RwRwRegFieldBits }
+            RCONT { RwRwRegFieldBitBand }
+            RDMAEN { RwRwRegFieldBitBand }
+            RSWSTART { RwRwRegFieldBitBand }
+            RSYNC { RwRwRegFieldBitBand }
+        }
+        CR2 {
+            0x20 RwRegBitBand;
+            AWDCH { RwRwRegFieldBits }
+            AWDIE { RwRwRegFieldBitBand }
+            CKABIE { RwRwRegFieldBitBand }
+            EXCH { RwRwRegFieldBits }
+            JEOCIE { RwRwRegFieldBitBand }
+            JOVRIE { RwRwRegFieldBitBand }
+            REOCIE { RwRwRegFieldBitBand }
+            ROVRIE { RwRwRegFieldBitBand }
+            SCDIE { RwRwRegFieldBitBand }
+        }
+        ISR {
+            0x20 RoRegBitBand;
+            AWDF { RoRoRegFieldBitBand }
+            CKABF { RoRoRegFieldBits }
+            JCIP { RoRoRegFieldBitBand }
+            JEOCF { RoRoRegFieldBitBand }
+            JOVRF { RoRoRegFieldBitBand }
+            RCIP { RoRoRegFieldBitBand }
+            REOCF { RoRoRegFieldBitBand }
+            ROVRF { RoRoRegFieldBitBand }
+            SCDF { RoRoRegFieldBits }
+        }
+        ICR {
+            0x20 RwRegBitBand;
+            CLRCKABF { RwRwRegFieldBits }
+            CLRJOVRF { RwRwRegFieldBitBand }
+            CLRROVRF { RwRwRegFieldBitBand }
+            CLRSCDF { RwRwRegFieldBits }
+        }
+        JCHGR {
+            0x20 RwRegBitBand;
+            JCHG { RwRwRegFieldBits }
+        }
+        FCR {
+            0x20 RwRegBitBand;
+            FORD { RwRwRegFieldBits }
+            FOSR { RwRwRegFieldBits }
+            IOSR { RwRwRegFieldBits }
+        }
+        JDATAR {
+            0x20 RoRegBitBand;
+            JDATA { RoRoRegFieldBits }
+            JDATACH { RoRoRegFieldBits }
+        }
+        RDATAR {
+            0x20 RoRegBitBand;
+            RDATA { RoRoRegFieldBits }
+            RDATACH { RoRoRegFieldBits }
+            RPEND { RoRoRegFieldBitBand }
+        }
+        AWHTR {
+            0x20 RwRegBitBand;
+            AWHT { RwRwRegFieldBits }
+            BKAWH { RwRwRegFieldBits }
+        }
+        AWLTR {
+            0x20 RwRegBitBand;
+            AWLT { RwRwRegFieldBits }
+            BKAWL { RwRwRegFieldBits }
+        }
+        AWSR {
+            0x20 RoRegBitBand;
+            AWHTF { RoRoRegFieldBits }
+            AWLTF { RoRoRegFieldBits }
+        }
+        AWCFR {
+            0x20 RwRegBitBand;
+            CLRAWHTF { RwRwRegFieldBits }
+            CLRAWLTF { RwRwRegFieldBits }
+        }
+        EXMAX {
+            0x20 RoRegBitBand;
+            EXMAX { RoRoRegFieldBits }
+            EXMAXCH { RoRoRegFieldBits }
+        }
+        EXMIN {
+            0x20 RoRegBitBand;
+            EXMIN { RoRoRegFieldBits }
+            EXMINCH { RoRoRegFieldBits }
+        }
+        CNVTIMR {
+            0x20 RoRegBitBand;
+            CNVCNT { RoRoRegFieldBits }
+        }
+    }
+}
+
+#[allow(unused_macros)]
+macro_rules! map_dfsdm_flt {
+    (
+        $dfsdm_flt_macro_doc:expr,
+        $dfsdm_flt_macro:ident,
+        $dfsdm_flt_ty_doc:expr,
+        $dfsdm_flt_ty:ident,
+        $dfsdm_ty:ident,
+        $dfsdm:ident,
+        $cr1:ident,
+        $cr2:ident,
+        $isr:ident,
+        $icr:ident,
+        $jchgr:ident,
+        $fcr:ident,
+        $jdatar:ident,
+        $rdatar:ident,
+        $awhtr:ident,
+        $awltr:ident,
+        $awsr:ident,
+        $awcfr:ident,
+        $exmax:ident,
+        $exmin:ident,
+        $cnvtimr:ident,
+    ) => {
+        periph::map! {
+            #[doc = $dfsdm_flt_macro_doc]
+            pub macro $dfsdm_flt_macro;
+
+            #[doc = $dfsdm_flt_ty_doc]
+            pub struct $dfsdm_flt_ty;
+
+            impl DfsdmFltMap for $dfsdm_flt_ty {
+                type DfsdmMap = super::$dfsdm_ty;
+            }
+
+            drone_stm32_map_pieces::reg;
+            crate::flt;
+
+            DFSDM {
+                $dfsdm;
+                CR1 {
+                    $cr1;
+                    AWFSEL { AWFSEL }
+                    DFEN { DFEN }
+                    FAST { FAST }
+                    JDMAEN { JDMAEN }
+                    JEXTEN { JEXTEN }
+                    JEXTSEL { JEXTSEL }
+                    JSCAN { JSCAN }
+                    JSWSTART { JSWSTART }
+                    JSYNC { JSYNC }
+                    RCH { RCH }
+                    RCONT { RCONT }
+                    RDMAEN { RDMAEN }
+                    RSWSTART { RSWSTART }
+                    RSYNC { RSYNC }
+                }
+                CR2 {
+                    $cr2;
+                    AWDCH { AWDCH }
+                    AWDIE { AWDIE }
+                    CKABIE { CKABIE }
+                    EXCH { EXCH }
+                    JEOCIE { JEOCIE }
+                    JOVRIE { JOVRIE }
+                    REOCIE { REOCIE }
+                    ROVRIE { ROVRIE }
+                    SCDIE { SCDIE }
+                }
+                ISR {
+                    $isr;
+                    AWDF { AWDF }
+                    CKABF { CKABF }
+                    JCIP { JCIP }
+                    JEOCF { JEOCF }
+                    JOVRF { JOVRF }
+                    RCIP { RCIP }
+                    REOCF { REOCF }
+                    ROVRF { ROVRF }
+                    SCDF { SCDF }
+                }
+                ICR {
+                    $icr;
+                    CLRCKABF { CLRCKABF }
+                    CLRJOVRF { CLRJOVRF }
+                    CLRROVRF { CLRROVRF }
+                    CLRSCDF { CLRSCDF }
+                }
+                JCHGR {
+                    $jchgr;
+                    JCHG { JCHG }
+                }
+                FCR {
+                    $fcr;
+                    FORD { FORD }
+                    FOSR { FOSR }
+                    IOSR { IOSR }
+                }
+                JDATAR {
+                    $jdatar;
+                    JDATA { JDATA }
+                    JDATACH { JDATACH }
+                }
+                RDATAR {
+                    $rdatar;
+                    RDATA { RDATA }
+                    RDATACH { RDATACH }
+                    RPEND { RPEND }
+                }
+                AWHTR {
+                    $awhtr;
+                    AWHT { AWHT }
+                    BKAWH { BKAWH }
+                }
+                AWLTR {
+                    $awltr;
+                    AWLT { AWLT }
+                    BKAWL { BKAWL }
+                }
+                AWSR {
+                    $awsr;
+                    AWHTF { AWHTF }
+                    AWLTF { AWLTF }
+                }
+                AWCFR {
+                    $awcfr;
+                    CLRAWHTF { CLRAWHTF }
+                    CLRAWLTF { CLRAWLTF }
+                }
+                EXMAX {
+                    $exmax;
+                    EXMAX { EXMAX }
+                    EXMAXCH { EXMAXCH }
+                }
+                EXMIN {
+                    $exmin;
+                    EXMIN { EXMIN }
+                    EXMINCH { EXMINCH }
+                }
+                CNVTIMR {
+                    $cnvtimr;
+                    CNVCNT { CNVCNT }
+                }
+            }
+        }
+    };
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32l4x5",
+    stm32_mcu = "stm32l4x6",
+    stm32_mcu = "stm32l4r5",
+    stm32_mcu = "stm32l4r7",
+    stm32_mcu = "stm32l4r9",
+    stm32_mcu = "stm32l4s5",
+    stm32_mcu = "stm32l4s7",
+    stm32_mcu = "stm32l4s9"
+))]
+map_dfsdm_flt! {
+    "Extracts DFSDM1 filter 0 register tokens.",
+    periph_dfsdm1_flt0,
+    "DFSDM1 filter 0 peripheral variant.",
+    Dfsdm1Flt0,
+    Dfsdm1,
+    DFSDM1,
+    DFSDM0_CR1,
+    DFSDM0_CR2,
+    DFSDM0_ISR,
+    DFSDM0_ICR,
+    DFSDM0_JCHGR,
+    DFSDM0_FCR,
+    DFSDM0_JDATAR,
+    DFSDM0_RDATAR,
+    DFSDM0_AWHTR,
+    DFSDM0_AWLTR,
+    DFSDM0_AWSR,
+    DFSDM0_AWCFR,
+    DFSDM0_EXMAX,
+    DFSDM0_EXMIN,
+    DFSDM0_CNVTIMR,
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32l4x5",
+    stm32_mcu = "stm32l4x6",
+    stm32_mcu = "stm32l4r5",
+    stm32_mcu = "stm32l4r7",
+    stm32_mcu = "stm32l4r9",
+    stm32_mcu = "stm32l4s5",
+    stm32_mcu = "stm32l4s7",
+    stm32_mcu = "stm32l4s9"
+))]
+map_dfsdm_flt! {
+    "Extracts DFSDM1 filter 1 register tokens.",
+    periph_dfsdm1_flt1,
+    "DFSDM1 filter 1 peripheral variant.",
+    Dfsdm1Flt1,
+    Dfsdm1,
+    DFSDM1,
+    DFSDM1_CR1,
+    DFSDM1_CR2,
+    DFSDM1_ISR,
+    DFSDM1_ICR,
+    DFSDM1_JCHGR,
+    DFSDM1_FCR,
+    DFSDM1_JDATAR,
+    DFSDM1_RDATAR,
+    DFSDM1_AWHTR,
+    DFSDM1_AWLTR,
+    DFSDM1_AWSR,
+    DFSDM1_AWCFR,
+    DFSDM1_EXMAX,
+    DFSDM1_EXMIN,
+    DFSDM1_CNVTIMR,
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32l4x5",
+    stm32_mcu = "stm32l4x6",
+    stm32_mcu = "stm32l4r5",
+    stm32_mcu = "stm32l4r7",
+    stm32_mcu = "stm32l4r9",
+    stm32_mcu = "stm32l4s5",
+    stm32_mcu = "stm32l4s7",
+    stm32_mcu = "stm32l4s9"
+))]
+map_dfsdm_flt! {
+    "Extracts DFSDM1 filter 2 register tokens.",
+    periph_dfsdm1_flt2,
+    "DFSDM1 filter 2 peripheral variant.",
+    Dfsdm1Flt2,
+    Dfsdm1,
+    DFSDM1,
+    DFSDM2_CR1,
+    DFSDM2_CR2,
+    DFSDM2_ISR,
+    DFSDM2_ICR,
+    DFSDM2_JCHGR,
+    DFSDM2_FCR,
+    DFSDM2_JDATAR,
+    DFSDM2_RDATAR,
+    DFSDM2_AWHTR,
+    DFSDM2_AWLTR,
+    DFSDM2_AWSR,
+    DFSDM2_AWCFR,
+    DFSDM2_EXMAX,
+    DFSDM2_EXMIN,
+    DFSDM2_CNVTIMR,
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32l4x5",
+    stm32_mcu = "stm32l4x6",
+    stm32_mcu = "stm32l4r5",
+    stm32_mcu = "stm32l4r7",
+    stm32_mcu = "stm32l4r9",
+    stm32_mcu = "stm32l4s5",
+    stm32_mcu = "stm32l4s7",
+    stm32_mcu = "stm32l4s9"
+))]
+map_dfsdm_flt! {
+    "Extracts DFSDM1 filter 3 register tokens.",
+    periph_dfsdm1_flt3,
+    "DFSDM1 filter 3 peripheral variant.",
+    Dfsdm1Flt3,
+    Dfsdm1,
+    DFSDM1,
+    DFSDM3_CR1,
+    DFSDM3_CR2,
+    DFSDM3_ISR,
+    DFSDM3_ICR,
+    DFSDM3_JCHGR,
+    DFSDM3_FCR,
+    DFSDM3_JDATAR,
+    DFSDM3_RDATAR,
+    DFSDM3_AWHTR,
+    DFSDM3_AWLTR,
+    DFSDM3_AWSR,
+    DFSDM3_AWCFR,
+    DFSDM3_EXMAX,
+    DFSDM3_EXMIN,
+    DFSDM3_CNVTIMR,
+}
@@ -0,0 +1,79 @@
+//! Digital filter for sigma-delta modulators.
+//!
+//! The peripheral is split into the head, which holds the RCC bits, eight
+//! channels in [`ch`] and four digital filters in [`flt`]. The global
+//! `DFSDMEN`, `CKOUTSRC` and `CKOUTDIV` fields are located in the channel 0
+//! configuration register, so they are present on [`ch::Dfsdm1Ch0`] only.
+
+#![feature(proc_macro_hygiene)]
+#![warn(missing_docs)]
+#![warn(clippy::pedantic)]
+#![allow(clippy::type_repetition_in_bounds, clippy::wildcard_imports)]
+#![no_std]
+
+pub mod ch;
+pub mod flt;
+
+use drone_core::periph;
+use drone_cortexm::reg::marker::*;
+
+periph! {
+    /// Generic DFSDM head peripheral variant.
+    pub trait DfsdmMap {}
+
+    /// Generic DFSDM head peripheral.
+    pub struct DfsdmPeriph;
+
+    RCC {
+        APB2ENR {
+            0x20 RwRegBitBand Shared;
+            DFSDMEN { RwRwRegFieldBitBand }
+        }
+        APB2RSTR {
+            0x20 RwRegBitBand Shared;
+            DFSDMRST { RwRwRegFieldBitBand }
+        }
+        APB2SMENR {
+            0x20 RwRegBitBand Shared;
+            DFSDMSMEN { RwRwRegFieldBitBand }
+        }
+    }
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32l4x5",
+    stm32_mcu = "stm32l4x6",
+    stm32_mcu = "stm32l4r5",
+    stm32_mcu = "stm32l4r7",
+    stm32_mcu = "stm32l4r9",
+    stm32_mcu = "stm32l4s5",
+    stm32_mcu = "stm32l4s7",
+    stm32_mcu = "stm32l4s9"
+))]
+periph::map! {
+    /// Extracts DFSDM1 head register tokens.
+    pub macro periph_dfsdm1;
+
+    /// DFSDM1 head peripheral variant.
+    pub struct Dfsdm1;
+
+    impl DfsdmMap for Dfsdm1 {}
+
+    drone_stm32_map_pieces::reg;
+    crate;
+
+    RCC {
+        APB2ENR {
+            APB2ENR Shared;
+            DFSDMEN { DFSDM1EN }
+        }
+        APB2RSTR {
+            APB2RSTR Shared;
+            DFSDMRST { DFSDM1RST }
+        }
+        APB2SMENR {
+            APB2SMENR Shared;
+            DFSDMSMEN { DFSDM1SMEN }
+        }
+    }
+}
@@ -3,6 +3,18 @@
 use drone_core::periph;
 use drone_cortexm::reg::marker::*;
 
+/// Number of software priority levels selectable through the `CCR.PL` field.
+pub const PRIORITY_LEVELS: u32 = 4;
+
+/// Returns the arbitration rank of a channel within its controller.
+///
+/// Each controller arbitrates on the software priority level `pl` first, and
+/// then on the channel (or stream) index, the lower index winning. A lower
+/// rank is served first.
+pub const fn arbitration_rank(pl: u32, index: u32) -> u32 {
+    (PRIORITY_LEVELS - 1 - pl) * 8 + index
+}
+
 periph! {
     /// Generic DMA channel peripheral variant.
     pub trait DmaChMap {
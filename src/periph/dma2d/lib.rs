@@ -0,0 +1,77 @@
+//! Chrom-ART Accelerator controller.
+//!
+//! The `DMA2D` interrupt is associated with this peripheral in
+//! `thr::INTERRUPT_PERIPHS`.
+
+#![feature(proc_macro_hygiene)]
+#![warn(missing_docs)]
+#![warn(clippy::pedantic)]
+#![allow(clippy::type_repetition_in_bounds, clippy::wildcard_imports)]
+#![no_std]
+
+#[allow(unused_imports)]
+use drone_core::periph;
+
+#[cfg(any(
+    stm32_mcu = "stm32f429",
+    stm32_mcu = "stm32f469",
+    stm32_mcu = "stm32l4r9",
+    stm32_mcu = "stm32l4s9"
+))]
+periph::singular! {
+    /// Extracts DMA2D register tokens.
+    pub macro periph_dma2d;
+
+    /// DMA2D peripheral.
+    pub struct Dma2dPeriph;
+
+    drone_stm32_map_pieces::reg;
+    crate;
+
+    RCC {
+        AHB1ENR {
+            DMA2DEN;
+        }
+        AHB1RSTR {
+            DMA2DRST;
+        }
+        #[cfg(any(
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f469"
+        ))]
+        AHB1LPENR {
+            DMA2DLPEN;
+        }
+        #[cfg(any(
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s9"
+        ))]
+        AHB1SMENR {
+            DMA2DSMEN;
+        }
+    }
+    DMA2D {
+        CR;
+        ISR;
+        IFCR;
+        FGMAR;
+        FGOR;
+        BGMAR;
+        BGOR;
+        FGPFCCR;
+        FGCOLR;
+        BGPFCCR;
+        BGCOLR;
+        FGCMAR;
+        BGCMAR;
+        OPFCCR;
+        OCOLR;
+        OMAR;
+        OOR;
+        NLR;
+        LWR;
+        AMTCR;
+        FGCLUT;
+        BGCLUT;
+    }
+}
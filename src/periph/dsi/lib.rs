@@ -0,0 +1,120 @@
+//! DSI Host.
+
+#![feature(proc_macro_hygiene)]
+#![warn(missing_docs)]
+#![warn(clippy::pedantic)]
+#![allow(clippy::type_repetition_in_bounds, clippy::wildcard_imports)]
+#![no_std]
+
+#[allow(unused_imports)]
+use drone_core::periph;
+
+#[cfg(any(
+    stm32_mcu = "stm32f469",
+    stm32_mcu = "stm32l4r9",
+    stm32_mcu = "stm32l4s9"
+))]
+periph::singular! {
+    /// Extracts DSI Host register tokens.
+    pub macro periph_dsi;
+
+    /// DSI Host peripheral.
+    pub struct DsiPeriph;
+
+    drone_stm32_map_pieces::reg;
+    crate;
+
+    RCC {
+        APB2ENR {
+            DSIEN;
+        }
+        APB2RSTR {
+            DSIRST;
+        }
+        #[cfg(any(stm32_mcu = "stm32f469"))]
+        APB2LPENR {
+            DSILPEN;
+        }
+        #[cfg(any(
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s9"
+        ))]
+        APB2SMENR {
+            DSISMEN;
+        }
+    }
+    DSI {
+        VR;
+        CR;
+        CCR;
+        LVCIDR;
+        LCOLCR;
+        LPCR;
+        LPMCR;
+        PCR;
+        GVCIDR;
+        MCR;
+        VMCR;
+        VPCR;
+        VCCR;
+        VNPCR;
+        VHSACR;
+        VHBPCR;
+        VLCR;
+        VVSACR;
+        VVBPCR;
+        VVFPCR;
+        VVACR;
+        LCCR;
+        CMCR;
+        GHCR;
+        GPDR;
+        GPSR;
+        TCCR0;
+        TCCR1;
+        TCCR2;
+        TCCR3;
+        TCCR4;
+        TCCR5;
+        CLCR;
+        CLTCR;
+        DLTCR;
+        PCTLR;
+        PCONFR;
+        PUCR;
+        PTTCR;
+        PSR;
+        ISR0;
+        ISR1;
+        IER0;
+        IER1;
+        FIR0;
+        FIR1;
+        VSCR;
+        LCVCIDR;
+        LCCCR;
+        LPMCCR;
+        VMCCR;
+        VPCCR;
+        VCCCR;
+        VNPCCR;
+        VHSACCR;
+        VHBPCCR;
+        VLCCR;
+        VVSACCR;
+        VVBPCCR;
+        VVFPCCR;
+        VVACCR;
+        WCFGR;
+        WCR;
+        WIER;
+        WISR;
+        WIFCR;
+        WPCR1;
+        WPCR2;
+        WPCR3;
+        WPCR4;
+        WPCR5;
+        WRPCR;
+    }
+}
@@ -0,0 +1,181 @@
+//! Ethernet media access controller.
+
+#![feature(proc_macro_hygiene)]
+#![warn(missing_docs)]
+#![warn(clippy::pedantic)]
+#![allow(clippy::type_repetition_in_bounds, clippy::wildcard_imports)]
+#![no_std]
+
+#[allow(unused_imports)]
+use drone_core::periph;
+
+#[cfg(any(
+    stm32_mcu = "stm32f107",
+    stm32_mcu = "stm32f407",
+    stm32_mcu = "stm32f427",
+    stm32_mcu = "stm32f429",
+    stm32_mcu = "stm32f469"
+))]
+periph::singular! {
+    /// Extracts Ethernet register tokens.
+    pub macro periph_eth;
+
+    /// Ethernet peripheral.
+    pub struct EthPeriph;
+
+    drone_stm32_map_pieces::reg;
+    crate;
+
+    RCC {
+        #[cfg(any(stm32_mcu = "stm32f107"))]
+        AHBENR {
+            ETHMACEN;
+            ETHMACTXEN;
+            ETHMACRXEN;
+        }
+        #[cfg(any(stm32_mcu = "stm32f107"))]
+        AHBRSTR {
+            ETHMACRST;
+        }
+        #[cfg(any(
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f469"
+        ))]
+        AHB1ENR {
+            ETHMACEN;
+            ETHMACTXEN;
+            ETHMACRXEN;
+            ETHMACPTPEN;
+        }
+        #[cfg(any(
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f469"
+        ))]
+        AHB1RSTR {
+            ETHMACRST;
+        }
+        #[cfg(any(
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f469"
+        ))]
+        AHB1LPENR {
+            ETHMACLPEN;
+            ETHMACTXLPEN;
+            ETHMACRXLPEN;
+            ETHMACPTPLPEN;
+        }
+    }
+    #[cfg(any(stm32_mcu = "stm32f107"))]
+    AFIO {
+        MAPR {
+            MII_RMII_SEL;
+        }
+    }
+    #[cfg(any(
+        stm32_mcu = "stm32f407",
+        stm32_mcu = "stm32f427",
+        stm32_mcu = "stm32f429",
+        stm32_mcu = "stm32f469"
+    ))]
+    SYSCFG {
+        PMC {
+            MII_RMII_SEL;
+        }
+    }
+    ETH_MAC {
+        MACCR;
+        MACFFR;
+        MACHTHR;
+        MACHTLR;
+        MACMIIAR;
+        MACMIIDR;
+        MACFCR;
+        MACVLANTR;
+        #[cfg(any(stm32_mcu = "stm32f107"))]
+        MACRWUFFR;
+        MACPMTCSR;
+        #[cfg(any(
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f469"
+        ))]
+        MACDBGR;
+        MACSR;
+        MACIMR;
+        MACA0HR;
+        MACA0LR;
+        MACA1HR;
+        MACA1LR;
+        MACA2HR;
+        MACA2LR;
+        MACA3HR;
+        MACA3LR;
+    }
+    ETH_MMC {
+        MMCCR;
+        MMCRIR;
+        MMCTIR;
+        MMCRIMR;
+        MMCTIMR;
+        MMCTGFSCCR;
+        MMCTGFMSCCR;
+        MMCTGFCR;
+        MMCRFCECR;
+        MMCRFAECR;
+        MMCRGUFCR;
+    }
+    ETH_PTP {
+        PTPTSCR;
+        PTPSSIR;
+        PTPTSHR;
+        PTPTSLR;
+        PTPTSHUR;
+        PTPTSLUR;
+        PTPTSAR;
+        PTPTTHR;
+        PTPTTLR;
+        #[cfg(any(
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f469"
+        ))]
+        PTPTSSR;
+        #[cfg(any(
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f469"
+        ))]
+        PTPPPSCR;
+    }
+    ETH_DMA {
+        DMABMR;
+        DMATPDR;
+        DMARPDR;
+        DMARDLAR;
+        DMATDLAR;
+        DMASR;
+        DMAOMR;
+        DMAIER;
+        DMAMFBOCR;
+        #[cfg(any(
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f469"
+        ))]
+        DMARSWTR;
+        DMACHTDR;
+        DMACHRDR;
+        DMACHTBAR;
+        DMACHRBAR;
+    }
+}
@@ -1,4 +1,42 @@
 //! Extended interrupts and events controller.
+//!
+//! The `SYSCFG` block of [`ExtiMap`] holds the line's EXTI source selection
+//! field. On STM32F1 it is backed by the `AFIO` external interrupt
+//! configuration registers, so drivers written against [`ExtiMap`] work for
+//! all supported families.
+//!
+//! Lines 16 and above are connected to internal event sources:
+//!
+//! | Line | STM32F1         | STM32F4                | STM32L4                         |
+//! |------|-----------------|------------------------|---------------------------------|
+//! | 16   | PVD             | PVD                    | PVD                             |
+//! | 17   | RTC alarm       | RTC alarm              | USB OTG FS wakeup               |
+//! | 18   | USB wakeup      | USB OTG FS wakeup      | RTC alarms                      |
+//! | 19   | Ethernet wakeup | Ethernet wakeup        | RTC tamper, time-stamp, LSE CSS |
+//! | 20   |                 | USB OTG HS wakeup      | RTC wakeup                      |
+//! | 21   |                 | RTC tamper, time-stamp | COMP1                           |
+//! | 22   |                 | RTC wakeup             | COMP2                           |
+//! | 23   |                 | LPTIM1                 | I2C1                            |
+//! | 24   |                 |                        | I2C2                            |
+//! | 25   |                 |                        | I2C3                            |
+//! | 26   |                 |                        | USART1                          |
+//! | 27   |                 |                        | USART2                          |
+//! | 28   |                 |                        | USART3                          |
+//! | 29   |                 |                        | UART4                           |
+//! | 30   |                 |                        | UART5                           |
+//! | 31   |                 |                        | LPUART1                         |
+//! | 32   |                 |                        | LPTIM1                          |
+//! | 33   |                 |                        | LPTIM2                          |
+//! | 34   |                 |                        | SWPMI1                          |
+//! | 35   |                 |                        | PVM1                            |
+//! | 36   |                 |                        | PVM2                            |
+//! | 37   |                 |                        | PVM3                            |
+//! | 38   |                 |                        | PVM4                            |
+//! | 39   |                 |                        | LCD                             |
+//! | 40   |                 |                        | I2C4                            |
+//!
+//! Direct lines have no trigger selection and pending bit, so the
+//! corresponding fields are absent from their maps.
 
 #![feature(proc_macro_hygiene)]
 #![warn(missing_docs)]
@@ -57,6 +95,7 @@ macro_rules! map_exti {
         $exti_macro:ident,
         $exti_ty_doc:expr,
         $exti_ty:ident,
+        $syscfg:ident,
         $exticr:ident,
         $imr:ident,
         $emr:ident,
@@ -85,6 +124,7 @@ macro_rules! map_exti {
             crate;
 
             SYSCFG {
+                $syscfg;
                 EXTICR {
                     $exticr Shared;
                     EXTI { $($exti Option)* }
@@ -138,6 +178,7 @@ map_exti! {
     periph_exti0,
     "EXTI Line 0 peripheral variant.",
     Exti0,
+    SYSCFG,
     EXTICR1,
     IMR1,
     EMR1,
@@ -172,6 +213,7 @@ map_exti! {
     periph_exti1,
     "EXTI Line 1 peripheral variant.",
     Exti1,
+    SYSCFG,
     EXTICR1,
     IMR1,
     EMR1,
@@ -206,6 +248,7 @@ map_exti! {
     periph_exti2,
     "EXTI Line 2 peripheral variant.",
     Exti2,
+    SYSCFG,
     EXTICR1,
     IMR1,
     EMR1,
@@ -240,6 +283,7 @@ map_exti! {
     periph_exti3,
     "EXTI Line 3 peripheral variant.",
     Exti3,
+    SYSCFG,
     EXTICR1,
     IMR1,
     EMR1,
@@ -274,6 +318,7 @@ map_exti! {
     periph_exti4,
     "EXTI Line 4 peripheral variant.",
     Exti4,
+    SYSCFG,
     EXTICR2,
     IMR1,
     EMR1,
@@ -308,6 +353,7 @@ map_exti! {
     periph_exti5,
     "EXTI Line 5 peripheral variant.",
     Exti5,
+    SYSCFG,
     EXTICR2,
     IMR1,
     EMR1,
@@ -342,6 +388,7 @@ map_exti! {
     periph_exti6,
     "EXTI Line 6 peripheral variant.",
     Exti6,
+    SYSCFG,
     EXTICR2,
     IMR1,
     EMR1,
@@ -376,6 +423,7 @@ map_exti! {
     periph_exti7,
     "EXTI Line 7 peripheral variant.",
     Exti7,
+    SYSCFG,
     EXTICR2,
     IMR1,
     EMR1,
@@ -410,6 +458,7 @@ map_exti! {
     periph_exti8,
     "EXTI Line 8 peripheral variant.",
     Exti8,
+    SYSCFG,
     EXTICR3,
     IMR1,
     EMR1,
@@ -444,6 +493,7 @@ map_exti! {
     periph_exti9,
     "EXTI Line 9 peripheral variant.",
     Exti9,
+    SYSCFG,
     EXTICR3,
     IMR1,
     EMR1,
@@ -478,6 +528,7 @@ map_exti! {
     periph_exti10,
     "EXTI Line 10 peripheral variant.",
     Exti10,
+    SYSCFG,
     EXTICR3,
     IMR1,
     EMR1,
@@ -512,6 +563,7 @@ map_exti! {
     periph_exti11,
     "EXTI Line 11 peripheral variant.",
     Exti11,
+    SYSCFG,
     EXTICR3,
     IMR1,
     EMR1,
@@ -546,6 +598,7 @@ map_exti! {
     periph_exti12,
     "EXTI Line 12.",
     Exti12,
+    SYSCFG,
     EXTICR4,
     IMR1,
     EMR1,
@@ -580,6 +633,7 @@ map_exti! {
     periph_exti13,
     "EXTI Line 13 peripheral variant.",
     Exti13,
+    SYSCFG,
     EXTICR4,
     IMR1,
     EMR1,
@@ -614,6 +668,7 @@ map_exti! {
     periph_exti14,
     "EXTI Line 14 peripheral variant.",
     Exti14,
+    SYSCFG,
     EXTICR4,
     IMR1,
     EMR1,
@@ -648,6 +703,7 @@ map_exti! {
     periph_exti15,
     "EXTI Line 15 peripheral variant.",
     Exti15,
+    SYSCFG,
     EXTICR4,
     IMR1,
     EMR1,
@@ -682,6 +738,7 @@ map_exti! {
     periph_exti16,
     "EXTI Line 16 peripheral variant.",
     Exti16,
+    SYSCFG,
     EXTICR4,
     IMR1,
     EMR1,
@@ -716,6 +773,7 @@ map_exti! {
     periph_exti17,
     "EXTI Line 17 peripheral variant.",
     Exti17,
+    SYSCFG,
     EXTICR4,
     IMR1,
     EMR1,
@@ -750,6 +808,7 @@ map_exti! {
     periph_exti18,
     "EXTI Line 18 peripheral variant.",
     Exti18,
+    SYSCFG,
     EXTICR4,
     IMR1,
     EMR1,
@@ -784,6 +843,7 @@ map_exti! {
     periph_exti19,
     "EXTI Line 19 peripheral variant.",
     Exti19,
+    SYSCFG,
     EXTICR4,
     IMR1,
     EMR1,
@@ -818,6 +878,7 @@ map_exti! {
     periph_exti20,
     "EXTI Line 20 peripheral variant.",
     Exti20,
+    SYSCFG,
     EXTICR4,
     IMR1,
     EMR1,
@@ -852,6 +913,7 @@ map_exti! {
     periph_exti21,
     "EXTI Line 21 peripheral variant.",
     Exti21,
+    SYSCFG,
     EXTICR4,
     IMR1,
     EMR1,
@@ -886,6 +948,7 @@ map_exti! {
     periph_exti22,
     "EXTI Line 22 peripheral variant.",
     Exti22,
+    SYSCFG,
     EXTICR4,
     IMR1,
     EMR1,
@@ -920,6 +983,7 @@ map_exti! {
     periph_exti23,
     "EXTI Line 23 peripheral variant.",
     Exti23,
+    SYSCFG,
     EXTICR4,
     IMR1,
     EMR1,
@@ -954,6 +1018,7 @@ map_exti! {
     periph_exti24,
     "EXTI Line 24 peripheral variant.",
     Exti24,
+    SYSCFG,
     EXTICR4,
     IMR1,
     EMR1,
@@ -988,6 +1053,7 @@ map_exti! {
     periph_exti25,
     "EXTI Line 25 peripheral variant.",
     Exti25,
+    SYSCFG,
     EXTICR4,
     IMR1,
     EMR1,
@@ -1022,6 +1088,7 @@ map_exti! {
     periph_exti26,
     "EXTI Line 26 peripheral variant.",
     Exti26,
+    SYSCFG,
     EXTICR4,
     IMR1,
     EMR1,
@@ -1056,6 +1123,7 @@ map_exti! {
     periph_exti27,
     "EXTI Line 27 peripheral variant.",
     Exti27,
+    SYSCFG,
     EXTICR4,
     IMR1,
     EMR1,
@@ -1090,6 +1158,7 @@ map_exti! {
     periph_exti28,
     "EXTI Line 28 peripheral variant.",
     Exti28,
+    SYSCFG,
     EXTICR4,
     IMR1,
     EMR1,
@@ -1124,6 +1193,7 @@ map_exti! {
     periph_exti29,
     "EXTI Line 29 peripheral variant.",
     Exti29,
+    SYSCFG,
     EXTICR4,
     IMR1,
     EMR1,
@@ -1158,6 +1228,7 @@ map_exti! {
     periph_exti30,
     "EXTI Line 30 peripheral variant.",
     Exti30,
+    SYSCFG,
     EXTICR4,
     IMR1,
     EMR1,
@@ -1192,6 +1263,7 @@ map_exti! {
     periph_exti31,
     "EXTI Line 31 peripheral variant.",
     Exti31,
+    SYSCFG,
     EXTICR4,
     IMR1,
     EMR1,
@@ -1226,6 +1298,7 @@ map_exti! {
     periph_exti32,
     "EXTI Line 32 peripheral variant.",
     Exti32,
+    SYSCFG,
     EXTICR4,
     IMR2,
     EMR2,
@@ -1260,6 +1333,7 @@ map_exti! {
     periph_exti33,
     "EXTI Line 33 peripheral variant.",
     Exti33,
+    SYSCFG,
     EXTICR4,
     IMR2,
     EMR2,
@@ -1294,6 +1368,7 @@ map_exti! {
     periph_exti34,
     "EXTI Line 34 peripheral variant.",
     Exti34,
+    SYSCFG,
     EXTICR4,
     IMR2,
     EMR2,
@@ -1328,6 +1403,7 @@ map_exti! {
     periph_exti35,
     "EXTI Line 35 peripheral variant.",
     Exti35,
+    SYSCFG,
     EXTICR4,
     IMR2,
     EMR2,
@@ -1362,6 +1438,7 @@ map_exti! {
     periph_exti36,
     "EXTI Line 36 peripheral variant.",
     Exti36,
+    SYSCFG,
     EXTICR4,
     IMR2,
     EMR2,
@@ -1396,6 +1473,7 @@ map_exti! {
     periph_exti37,
     "EXTI Line 37 peripheral variant.",
     Exti37,
+    SYSCFG,
     EXTICR4,
     IMR2,
     EMR2,
@@ -1430,6 +1508,7 @@ map_exti! {
     periph_exti38,
     "EXTI Line 38 peripheral variant.",
     Exti38,
+    SYSCFG,
     EXTICR4,
     IMR2,
     EMR2,
@@ -1464,6 +1543,7 @@ map_exti! {
     periph_exti39,
     "EXTI Line 39 peripheral variant.",
     Exti39,
+    SYSCFG,
     EXTICR4,
     IMR2,
     EMR2,
@@ -1495,6 +1575,7 @@ map_exti! {
     periph_exti40,
     "EXTI Line 40 peripheral variant.",
     Exti40,
+    SYSCFG,
     EXTICR4,
     IMR2,
     EMR2,
@@ -1529,6 +1610,7 @@ map_exti! {
     periph_exti0,
     "EXTI Line 0 peripheral variant.",
     Exti0,
+    SYSCFG,
     EXTICR1,
     IMR,
     EMR,
@@ -1563,6 +1645,7 @@ map_exti! {
     periph_exti1,
     "EXTI Line 1 peripheral variant.",
     Exti1,
+    SYSCFG,
     EXTICR1,
     IMR,
     EMR,
@@ -1597,6 +1680,7 @@ map_exti! {
     periph_exti2,
     "EXTI Line 2 peripheral variant.",
     Exti2,
+    SYSCFG,
     EXTICR1,
     IMR,
     EMR,
@@ -1631,6 +1715,7 @@ map_exti! {
     periph_exti3,
     "EXTI Line 3 peripheral variant.",
     Exti3,
+    SYSCFG,
     EXTICR1,
     IMR,
     EMR,
@@ -1665,6 +1750,7 @@ map_exti! {
     periph_exti4,
     "EXTI Line 4 peripheral variant.",
     Exti4,
+    SYSCFG,
     EXTICR2,
     IMR,
     EMR,
@@ -1699,6 +1785,7 @@ map_exti! {
     periph_exti5,
     "EXTI Line 5 peripheral variant.",
     Exti5,
+    SYSCFG,
     EXTICR2,
     IMR,
     EMR,
@@ -1733,6 +1820,7 @@ map_exti! {
     periph_exti6,
     "EXTI Line 6 peripheral variant.",
     Exti6,
+    SYSCFG,
     EXTICR2,
     IMR,
     EMR,
@@ -1767,6 +1855,7 @@ map_exti! {
     periph_exti7,
     "EXTI Line 7 peripheral variant.",
     Exti7,
+    SYSCFG,
     EXTICR2,
     IMR,
     EMR,
@@ -1801,6 +1890,7 @@ map_exti! {
     periph_exti8,
     "EXTI Line 8 peripheral variant.",
     Exti8,
+    SYSCFG,
     EXTICR3,
     IMR,
     EMR,
@@ -1835,6 +1925,7 @@ map_exti! {
     periph_exti9,
     "EXTI Line 9 peripheral variant.",
     Exti9,
+    SYSCFG,
     EXTICR3,
     IMR,
     EMR,
@@ -1869,6 +1960,7 @@ map_exti! {
     periph_exti10,
     "EXTI Line 10 peripheral variant.",
     Exti10,
+    SYSCFG,
     EXTICR3,
     IMR,
     EMR,
@@ -1903,6 +1995,7 @@ map_exti! {
     periph_exti11,
     "EXTI Line 11 peripheral variant.",
     Exti11,
+    SYSCFG,
     EXTICR3,
     IMR,
     EMR,
@@ -1937,6 +2030,7 @@ map_exti! {
     periph_exti12,
     "EXTI Line 12 peripheral variant.",
     Exti12,
+    SYSCFG,
     EXTICR4,
     IMR,
     EMR,
@@ -1971,6 +2065,7 @@ map_exti! {
     periph_exti13,
     "EXTI Line 13 peripheral variant.",
     Exti13,
+    SYSCFG,
     EXTICR4,
     IMR,
     EMR,
@@ -2005,6 +2100,7 @@ map_exti! {
     periph_exti14,
     "EXTI Line 14 peripheral variant.",
     Exti14,
+    SYSCFG,
     EXTICR4,
     IMR,
     EMR,
@@ -2039,6 +2135,7 @@ map_exti! {
     periph_exti15,
     "EXTI Line 15 peripheral variant.",
     Exti15,
+    SYSCFG,
     EXTICR4,
     IMR,
     EMR,
@@ -2073,6 +2170,7 @@ map_exti! {
     periph_exti16,
     "EXTI Line 16 peripheral variant.",
     Exti16,
+    SYSCFG,
     EXTICR4,
     IMR,
     EMR,
@@ -2107,6 +2205,7 @@ map_exti! {
     periph_exti17,
     "EXTI Line 17 peripheral variant.",
     Exti17,
+    SYSCFG,
     EXTICR4,
     IMR,
     EMR,
@@ -2141,6 +2240,7 @@ map_exti! {
     periph_exti18,
     "EXTI Line 18 peripheral variant.",
     Exti18,
+    SYSCFG,
     EXTICR4,
     IMR,
     EMR,
@@ -2168,6 +2268,7 @@ map_exti! {
     periph_exti19,
     "EXTI Line 19 peripheral variant.",
     Exti19,
+    SYSCFG,
     EXTICR4,
     IMR,
     EMR,
@@ -2190,6 +2291,7 @@ map_exti! {
     periph_exti19,
     "EXTI Line 19 peripheral variant.",
     Exti19,
+    SYSCFG,
     EXTICR4,
     IMR,
     EMR,
@@ -2219,6 +2321,7 @@ map_exti! {
     periph_exti20,
     "EXTI Line 20 peripheral variant.",
     Exti20,
+    SYSCFG,
     EXTICR4,
     IMR,
     EMR,
@@ -2253,6 +2356,7 @@ map_exti! {
     periph_exti21,
     "EXTI Line 21 peripheral variant.",
     Exti21,
+    SYSCFG,
     EXTICR4,
     IMR,
     EMR,
@@ -2287,6 +2391,7 @@ map_exti! {
     periph_exti22,
     "EXTI Line 22 peripheral variant.",
     Exti22,
+    SYSCFG,
     EXTICR4,
     IMR,
     EMR,
@@ -2309,6 +2414,7 @@ map_exti! {
     periph_exti23,
     "EXTI Line 23 peripheral variant.",
     Exti23,
+    SYSCFG,
     EXTICR4,
     IMR,
     EMR,
@@ -2324,3 +2430,576 @@ map_exti! {
     (SWIER23),
     (PR23),
 }
+
+#[cfg(any(
+    stm32_mcu = "stm32f100",
+    stm32_mcu = "stm32f101",
+    stm32_mcu = "stm32f102",
+    stm32_mcu = "stm32f103",
+    stm32_mcu = "stm32f107",
+))]
+map_exti! {
+    "Extracts EXTI Line 0 register tokens.",
+    periph_exti0,
+    "EXTI Line 0 peripheral variant.",
+    Exti0,
+    AFIO,
+    EXTICR1,
+    IMR,
+    EMR,
+    RTSR,
+    FTSR,
+    SWIER,
+    PR,
+    MR0,
+    MR0,
+    (EXTI0),
+    (TR0),
+    (TR0),
+    (SWIER0),
+    (PR0),
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32f100",
+    stm32_mcu = "stm32f101",
+    stm32_mcu = "stm32f102",
+    stm32_mcu = "stm32f103",
+    stm32_mcu = "stm32f107",
+))]
+map_exti! {
+    "Extracts EXTI Line 1 register tokens.",
+    periph_exti1,
+    "EXTI Line 1 peripheral variant.",
+    Exti1,
+    AFIO,
+    EXTICR1,
+    IMR,
+    EMR,
+    RTSR,
+    FTSR,
+    SWIER,
+    PR,
+    MR1,
+    MR1,
+    (EXTI1),
+    (TR1),
+    (TR1),
+    (SWIER1),
+    (PR1),
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32f100",
+    stm32_mcu = "stm32f101",
+    stm32_mcu = "stm32f102",
+    stm32_mcu = "stm32f103",
+    stm32_mcu = "stm32f107",
+))]
+map_exti! {
+    "Extracts EXTI Line 2 register tokens.",
+    periph_exti2,
+    "EXTI Line 2 peripheral variant.",
+    Exti2,
+    AFIO,
+    EXTICR1,
+    IMR,
+    EMR,
+    RTSR,
+    FTSR,
+    SWIER,
+    PR,
+    MR2,
+    MR2,
+    (EXTI2),
+    (TR2),
+    (TR2),
+    (SWIER2),
+    (PR2),
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32f100",
+    stm32_mcu = "stm32f101",
+    stm32_mcu = "stm32f102",
+    stm32_mcu = "stm32f103",
+    stm32_mcu = "stm32f107",
+))]
+map_exti! {
+    "Extracts EXTI Line 3 register tokens.",
+    periph_exti3,
+    "EXTI Line 3 peripheral variant.",
+    Exti3,
+    AFIO,
+    EXTICR1,
+    IMR,
+    EMR,
+    RTSR,
+    FTSR,
+    SWIER,
+    PR,
+    MR3,
+    MR3,
+    (EXTI3),
+    (TR3),
+    (TR3),
+    (SWIER3),
+    (PR3),
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32f100",
+    stm32_mcu = "stm32f101",
+    stm32_mcu = "stm32f102",
+    stm32_mcu = "stm32f103",
+    stm32_mcu = "stm32f107",
+))]
+map_exti! {
+    "Extracts EXTI Line 4 register tokens.",
+    periph_exti4,
+    "EXTI Line 4 peripheral variant.",
+    Exti4,
+    AFIO,
+    EXTICR2,
+    IMR,
+    EMR,
+    RTSR,
+    FTSR,
+    SWIER,
+    PR,
+    MR4,
+    MR4,
+    (EXTI4),
+    (TR4),
+    (TR4),
+    (SWIER4),
+    (PR4),
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32f100",
+    stm32_mcu = "stm32f101",
+    stm32_mcu = "stm32f102",
+    stm32_mcu = "stm32f103",
+    stm32_mcu = "stm32f107",
+))]
+map_exti! {
+    "Extracts EXTI Line 5 register tokens.",
+    periph_exti5,
+    "EXTI Line 5 peripheral variant.",
+    Exti5,
+    AFIO,
+    EXTICR2,
+    IMR,
+    EMR,
+    RTSR,
+    FTSR,
+    SWIER,
+    PR,
+    MR5,
+    MR5,
+    (EXTI5),
+    (TR5),
+    (TR5),
+    (SWIER5),
+    (PR5),
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32f100",
+    stm32_mcu = "stm32f101",
+    stm32_mcu = "stm32f102",
+    stm32_mcu = "stm32f103",
+    stm32_mcu = "stm32f107",
+))]
+map_exti! {
+    "Extracts EXTI Line 6 register tokens.",
+    periph_exti6,
+    "EXTI Line 6 peripheral variant.",
+    Exti6,
+    AFIO,
+    EXTICR2,
+    IMR,
+    EMR,
+    RTSR,
+    FTSR,
+    SWIER,
+    PR,
+    MR6,
+    MR6,
+    (EXTI6),
+    (TR6),
+    (TR6),
+    (SWIER6),
+    (PR6),
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32f100",
+    stm32_mcu = "stm32f101",
+    stm32_mcu = "stm32f102",
+    stm32_mcu = "stm32f103",
+    stm32_mcu = "stm32f107",
+))]
+map_exti! {
+    "Extracts EXTI Line 7 register tokens.",
+    periph_exti7,
+    "EXTI Line 7 peripheral variant.",
+    Exti7,
+    AFIO,
+    EXTICR2,
+    IMR,
+    EMR,
+    RTSR,
+    FTSR,
+    SWIER,
+    PR,
+    MR7,
+    MR7,
+    (EXTI7),
+    (TR7),
+    (TR7),
+    (SWIER7),
+    (PR7),
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32f100",
+    stm32_mcu = "stm32f101",
+    stm32_mcu = "stm32f102",
+    stm32_mcu = "stm32f103",
+    stm32_mcu = "stm32f107",
+))]
+map_exti! {
+    "Extracts EXTI Line 8 register tokens.",
+    periph_exti8,
+    "EXTI Line 8 peripheral variant.",
+    Exti8,
+    AFIO,
+    EXTICR3,
+    IMR,
+    EMR,
+    RTSR,
+    FTSR,
+    SWIER,
+    PR,
+    MR8,
+    MR8,
+    (EXTI8),
+    (TR8),
+    (TR8),
+    (SWIER8),
+    (PR8),
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32f100",
+    stm32_mcu = "stm32f101",
+    stm32_mcu = "stm32f102",
+    stm32_mcu = "stm32f103",
+    stm32_mcu = "stm32f107",
+))]
+map_exti! {
+    "Extracts EXTI Line 9 register tokens.",
+    periph_exti9,
+    "EXTI Line 9 peripheral variant.",
+    Exti9,
+    AFIO,
+    EXTICR3,
+    IMR,
+    EMR,
+    RTSR,
+    FTSR,
+    SWIER,
+    PR,
+    MR9,
+    MR9,
+    (EXTI9),
+    (TR9),
+    (TR9),
+    (SWIER9),
+    (PR9),
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32f100",
+    stm32_mcu = "stm32f101",
+    stm32_mcu = "stm32f102",
+    stm32_mcu = "stm32f103",
+    stm32_mcu = "stm32f107",
+))]
+map_exti! {
+    "Extracts EXTI Line 10 register tokens.",
+    periph_exti10,
+    "EXTI Line 10 peripheral variant.",
+    Exti10,
+    AFIO,
+    EXTICR3,
+    IMR,
+    EMR,
+    RTSR,
+    FTSR,
+    SWIER,
+    PR,
+    MR10,
+    MR10,
+    (EXTI10),
+    (TR10),
+    (TR10),
+    (SWIER10),
+    (PR10),
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32f100",
+    stm32_mcu = "stm32f101",
+    stm32_mcu = "stm32f102",
+    stm32_mcu = "stm32f103",
+    stm32_mcu = "stm32f107",
+))]
+map_exti! {
+    "Extracts EXTI Line 11 register tokens.",
+    periph_exti11,
+    "EXTI Line 11 peripheral variant.",
+    Exti11,
+    AFIO,
+    EXTICR3,
+    IMR,
+    EMR,
+    RTSR,
+    FTSR,
+    SWIER,
+    PR,
+    MR11,
+    MR11,
+    (EXTI11),
+    (TR11),
+    (TR11),
+    (SWIER11),
+    (PR11),
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32f100",
+    stm32_mcu = "stm32f101",
+    stm32_mcu = "stm32f102",
+    stm32_mcu = "stm32f103",
+    stm32_mcu = "stm32f107",
+))]
+map_exti! {
+    "Extracts EXTI Line 12 register tokens.",
+    periph_exti12,
+    "EXTI Line 12 peripheral variant.",
+    Exti12,
+    AFIO,
+    EXTICR4,
+    IMR,
+    EMR,
+    RTSR,
+    FTSR,
+    SWIER,
+    PR,
+    MR12,
+    MR12,
+    (EXTI12),
+    (TR12),
+    (TR12),
+    (SWIER12),
+    (PR12),
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32f100",
+    stm32_mcu = "stm32f101",
+    stm32_mcu = "stm32f102",
+    stm32_mcu = "stm32f103",
+    stm32_mcu = "stm32f107",
+))]
+map_exti! {
+    "Extracts EXTI Line 13 register tokens.",
+    periph_exti13,
+    "EXTI Line 13 peripheral variant.",
+    Exti13,
+    AFIO,
+    EXTICR4,
+    IMR,
+    EMR,
+    RTSR,
+    FTSR,
+    SWIER,
+    PR,
+    MR13,
+    MR13,
+    (EXTI13),
+    (TR13),
+    (TR13),
+    (SWIER13),
+    (PR13),
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32f100",
+    stm32_mcu = "stm32f101",
+    stm32_mcu = "stm32f102",
+    stm32_mcu = "stm32f103",
+    stm32_mcu = "stm32f107",
+))]
+map_exti! {
+    "Extracts EXTI Line 14 register tokens.",
+    periph_exti14,
+    "EXTI Line 14 peripheral variant.",
+    Exti14,
+    AFIO,
+    EXTICR4,
+    IMR,
+    EMR,
+    RTSR,
+    FTSR,
+    SWIER,
+    PR,
+    MR14,
+    MR14,
+    (EXTI14),
+    (TR14),
+    (TR14),
+    (SWIER14),
+    (PR14),
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32f100",
+    stm32_mcu = "stm32f101",
+    stm32_mcu = "stm32f102",
+    stm32_mcu = "stm32f103",
+    stm32_mcu = "stm32f107",
+))]
+map_exti! {
+    "Extracts EXTI Line 15 register tokens.",
+    periph_exti15,
+    "EXTI Line 15 peripheral variant.",
+    Exti15,
+    AFIO,
+    EXTICR4,
+    IMR,
+    EMR,
+    RTSR,
+    FTSR,
+    SWIER,
+    PR,
+    MR15,
+    MR15,
+    (EXTI15),
+    (TR15),
+    (TR15),
+    (SWIER15),
+    (PR15),
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32f100",
+    stm32_mcu = "stm32f101",
+    stm32_mcu = "stm32f102",
+    stm32_mcu = "stm32f103",
+    stm32_mcu = "stm32f107",
+))]
+map_exti! {
+    "Extracts EXTI Line 16 register tokens.",
+    periph_exti16,
+    "EXTI Line 16 peripheral variant.",
+    Exti16,
+    AFIO,
+    EXTICR4,
+    IMR,
+    EMR,
+    RTSR,
+    FTSR,
+    SWIER,
+    PR,
+    MR16,
+    MR16,
+    (),
+    (TR16),
+    (TR16),
+    (SWIER16),
+    (PR16),
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32f100",
+    stm32_mcu = "stm32f101",
+    stm32_mcu = "stm32f102",
+    stm32_mcu = "stm32f103",
+    stm32_mcu = "stm32f107",
+))]
+map_exti! {
+    "Extracts EXTI Line 17 register tokens.",
+    periph_exti17,
+    "EXTI Line 17 peripheral variant.",
+    Exti17,
+    AFIO,
+    EXTICR4,
+    IMR,
+    EMR,
+    RTSR,
+    FTSR,
+    SWIER,
+    PR,
+    MR17,
+    MR17,
+    (),
+    (TR17),
+    (TR17),
+    (SWIER17),
+    (PR17),
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32f101",
+    stm32_mcu = "stm32f102",
+    stm32_mcu = "stm32f103",
+    stm32_mcu = "stm32f107",
+))]
+map_exti! {
+    "Extracts EXTI Line 18 register tokens.",
+    periph_exti18,
+    "EXTI Line 18 peripheral variant.",
+    Exti18,
+    AFIO,
+    EXTICR4,
+    IMR,
+    EMR,
+    RTSR,
+    FTSR,
+    SWIER,
+    PR,
+    MR18,
+    MR18,
+    (),
+    (TR18),
+    (TR18),
+    (SWIER18),
+    (PR18),
+}
+
+#[cfg(any(stm32_mcu = "stm32f107"))]
+map_exti! {
+    "Extracts EXTI Line 19 register tokens.",
+    periph_exti19,
+    "EXTI Line 19 peripheral variant.",
+    Exti19,
+    AFIO,
+    EXTICR4,
+    IMR,
+    EMR,
+    RTSR,
+    FTSR,
+    SWIER,
+    PR,
+    MR19,
+    MR19,
+    (),
+    (TR19),
+    (TR19),
+    (SWIER19),
+    (PR19),
+}
@@ -0,0 +1,124 @@
+//! Flexible static memory controller.
+//!
+//! STM32F100 high-density value line devices only have the NOR/PSRAM banks;
+//! the NAND and PC Card banks are available on STM32F101 and STM32F103.
+
+#![feature(proc_macro_hygiene)]
+#![warn(missing_docs)]
+#![warn(clippy::pedantic)]
+#![allow(clippy::type_repetition_in_bounds, clippy::wildcard_imports)]
+#![no_std]
+
+#[allow(unused_imports)]
+use drone_core::periph;
+
+#[cfg(any(
+    stm32_mcu = "stm32f100",
+    stm32_mcu = "stm32f101",
+    stm32_mcu = "stm32f103"
+))]
+periph::singular! {
+    /// Extracts FSMC register tokens.
+    pub macro periph_fsmc;
+
+    /// FSMC peripheral.
+    pub struct FsmcPeriph;
+
+    drone_stm32_map_pieces::reg;
+    crate;
+
+    RCC {
+        AHBENR {
+            FSMCEN;
+        }
+    }
+    FSMC {
+        BCR1;
+        BTR1;
+        BCR2;
+        BTR2;
+        BCR3;
+        BTR3;
+        BCR4;
+        BTR4;
+        #[cfg(any(
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f103"
+        ))]
+        PCR2;
+        #[cfg(any(
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f103"
+        ))]
+        SR2;
+        #[cfg(any(
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f103"
+        ))]
+        PMEM2;
+        #[cfg(any(
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f103"
+        ))]
+        PATT2;
+        #[cfg(any(
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f103"
+        ))]
+        ECCR2;
+        #[cfg(any(
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f103"
+        ))]
+        PCR3;
+        #[cfg(any(
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f103"
+        ))]
+        SR3;
+        #[cfg(any(
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f103"
+        ))]
+        PMEM3;
+        #[cfg(any(
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f103"
+        ))]
+        PATT3;
+        #[cfg(any(
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f103"
+        ))]
+        ECCR3;
+        #[cfg(any(
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f103"
+        ))]
+        PCR4;
+        #[cfg(any(
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f103"
+        ))]
+        SR4;
+        #[cfg(any(
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f103"
+        ))]
+        PMEM4;
+        #[cfg(any(
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f103"
+        ))]
+        PATT4;
+        #[cfg(any(
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f103"
+        ))]
+        PIO4;
+        BWTR1;
+        BWTR2;
+        BWTR3;
+        BWTR4;
+    }
+}
@@ -0,0 +1,52 @@
+//! Firewall.
+//!
+//! The `FWEN` bit can only be set by software. Once the firewall is enabled,
+//! it stays enabled until the next system reset.
+
+#![feature(proc_macro_hygiene)]
+#![warn(missing_docs)]
+#![warn(clippy::pedantic)]
+#![allow(clippy::type_repetition_in_bounds, clippy::wildcard_imports)]
+#![no_std]
+
+#[allow(unused_imports)]
+use drone_core::periph;
+
+#[cfg(any(
+    stm32_mcu = "stm32l4x1",
+    stm32_mcu = "stm32l4x2",
+    stm32_mcu = "stm32l4x3",
+    stm32_mcu = "stm32l4x5",
+    stm32_mcu = "stm32l4x6",
+    stm32_mcu = "stm32l4r5",
+    stm32_mcu = "stm32l4r7",
+    stm32_mcu = "stm32l4r9",
+    stm32_mcu = "stm32l4s5",
+    stm32_mcu = "stm32l4s7",
+    stm32_mcu = "stm32l4s9"
+))]
+periph::singular! {
+    /// Extracts firewall register tokens.
+    pub macro periph_fw;
+
+    /// Firewall peripheral.
+    pub struct FwPeriph;
+
+    drone_stm32_map_pieces::reg;
+    crate;
+
+    RCC {
+        APB2ENR {
+            FWEN;
+        }
+    }
+    FIREWALL {
+        CSSA;
+        CSL;
+        NVDSSA;
+        NVDSL;
+        VDSSA;
+        VDSL;
+        CR;
+    }
+}
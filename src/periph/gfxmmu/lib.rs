@@ -0,0 +1,62 @@
+//! Chrom-GRC graphic memory management unit.
+//!
+//! The LUT entries are not part of the peripheral tokens. Each of the 1024
+//! display lines takes a pair of words starting at [`GFXMMU_LUT_BASE`].
+
+#![feature(proc_macro_hygiene)]
+#![warn(missing_docs)]
+#![warn(clippy::pedantic)]
+#![allow(clippy::type_repetition_in_bounds, clippy::wildcard_imports)]
+#![no_std]
+
+#[allow(unused_imports)]
+use drone_core::periph;
+
+/// Base address of the GFXMMU look-up table.
+pub const GFXMMU_LUT_BASE: usize = 0x4002_D000;
+
+/// Number of GFXMMU look-up table entries.
+pub const GFXMMU_LUT_ENTRIES: usize = 1024;
+
+/// Base address of the GFXMMU virtual buffer region.
+pub const GFXMMU_VIRTUAL_BUFFER_BASE: usize = 0x2400_0000;
+
+#[cfg(any(
+    stm32_mcu = "stm32l4r9",
+    stm32_mcu = "stm32l4s9"
+))]
+periph::singular! {
+    /// Extracts GFXMMU register tokens.
+    pub macro periph_gfxmmu;
+
+    /// GFXMMU peripheral.
+    pub struct GfxmmuPeriph;
+
+    drone_stm32_map_pieces::reg;
+    crate;
+
+    RCC {
+        AHB1ENR {
+            GFXMMUEN;
+        }
+        AHB1RSTR {
+            GFXMMURST;
+        }
+        AHB1SMENR {
+            GFXMMUSMEN;
+        }
+    }
+    GFXMMU {
+        CR;
+        SR;
+        FCR;
+        DVR;
+        B0CR;
+        B1CR;
+        B2CR;
+        B3CR;
+        VERR;
+        IPIDR;
+        SIDR;
+    }
+}
@@ -8,10 +8,87 @@
 
 pub mod head;
 pub mod pin;
+#[cfg(any(
+    stm32_mcu = "stm32f401",
+    stm32_mcu = "stm32f405",
+    stm32_mcu = "stm32f407",
+    stm32_mcu = "stm32f411",
+    stm32_mcu = "stm32f412",
+    stm32_mcu = "stm32f413",
+    stm32_mcu = "stm32f427",
+    stm32_mcu = "stm32f429",
+    stm32_mcu = "stm32f446",
+    stm32_mcu = "stm32f469",
+    stm32_mcu = "stm32l4x1",
+    stm32_mcu = "stm32l4x2",
+    stm32_mcu = "stm32l4x3",
+    stm32_mcu = "stm32l4x5",
+    stm32_mcu = "stm32l4x6",
+    stm32_mcu = "stm32l4r5",
+    stm32_mcu = "stm32l4r7",
+    stm32_mcu = "stm32l4r9",
+    stm32_mcu = "stm32l4s5",
+    stm32_mcu = "stm32l4s7",
+    stm32_mcu = "stm32l4s9"
+))]
+pub mod trace;
 
 use drone_core::periph;
 use drone_cortexm::reg::marker::*;
 
+/// Number of GPIO ports mapped for the selected MCU.
+#[cfg(any(stm32_mcu = "stm32f102", stm32_mcu = "stm32f410"))]
+pub const STM32_GPIO_PORT_COUNT: usize = 4;
+
+/// Number of GPIO ports mapped for the selected MCU.
+#[cfg(any(stm32_mcu = "stm32f107"))]
+pub const STM32_GPIO_PORT_COUNT: usize = 5;
+
+/// Number of GPIO ports mapped for the selected MCU.
+#[cfg(any(
+    stm32_mcu = "stm32f401",
+    stm32_mcu = "stm32f411",
+    stm32_mcu = "stm32l4x1",
+    stm32_mcu = "stm32l4x2",
+    stm32_mcu = "stm32l4x3"
+))]
+pub const STM32_GPIO_PORT_COUNT: usize = 6;
+
+/// Number of GPIO ports mapped for the selected MCU.
+#[cfg(any(stm32_mcu = "stm32f100", stm32_mcu = "stm32f101", stm32_mcu = "stm32f103"))]
+pub const STM32_GPIO_PORT_COUNT: usize = 7;
+
+/// Number of GPIO ports mapped for the selected MCU.
+#[cfg(any(
+    stm32_mcu = "stm32f412",
+    stm32_mcu = "stm32f413",
+    stm32_mcu = "stm32f446",
+    stm32_mcu = "stm32l4x5"
+))]
+pub const STM32_GPIO_PORT_COUNT: usize = 8;
+
+/// Number of GPIO ports mapped for the selected MCU.
+#[cfg(any(
+    stm32_mcu = "stm32l4x6",
+    stm32_mcu = "stm32l4r5",
+    stm32_mcu = "stm32l4r7",
+    stm32_mcu = "stm32l4r9",
+    stm32_mcu = "stm32l4s5",
+    stm32_mcu = "stm32l4s7",
+    stm32_mcu = "stm32l4s9"
+))]
+pub const STM32_GPIO_PORT_COUNT: usize = 9;
+
+/// Number of GPIO ports mapped for the selected MCU.
+#[cfg(any(
+    stm32_mcu = "stm32f405",
+    stm32_mcu = "stm32f407",
+    stm32_mcu = "stm32f427",
+    stm32_mcu = "stm32f429",
+    stm32_mcu = "stm32f469"
+))]
+pub const STM32_GPIO_PORT_COUNT: usize = 11;
+
 periph! {
     /// Generic GPIO port peripheral variant.
     pub trait GpioPortMap {}
@@ -1688,3 +1765,333 @@ map_gpio_port! {
     GPIOKLPEN,
     (),
 }
+
+/// Extracts GPIO port A head and pin register tokens at once.
+///
+/// Expands to a `(head, (pin0, ..., pin15))` tuple.
+#[macro_export]
+macro_rules! periph_gpio_a_split {
+    ($reg:ident) => {
+        (
+            $crate::periph_gpio_a_head!($reg),
+            (
+                $crate::periph_gpio_a0!($reg),
+                $crate::periph_gpio_a1!($reg),
+                $crate::periph_gpio_a2!($reg),
+                $crate::periph_gpio_a3!($reg),
+                $crate::periph_gpio_a4!($reg),
+                $crate::periph_gpio_a5!($reg),
+                $crate::periph_gpio_a6!($reg),
+                $crate::periph_gpio_a7!($reg),
+                $crate::periph_gpio_a8!($reg),
+                $crate::periph_gpio_a9!($reg),
+                $crate::periph_gpio_a10!($reg),
+                $crate::periph_gpio_a11!($reg),
+                $crate::periph_gpio_a12!($reg),
+                $crate::periph_gpio_a13!($reg),
+                $crate::periph_gpio_a14!($reg),
+                $crate::periph_gpio_a15!($reg),
+            ),
+        )
+    };
+}
+
+/// Extracts GPIO port B head and pin register tokens at once.
+///
+/// Expands to a `(head, (pin0, ..., pin15))` tuple.
+#[macro_export]
+macro_rules! periph_gpio_b_split {
+    ($reg:ident) => {
+        (
+            $crate::periph_gpio_b_head!($reg),
+            (
+                $crate::periph_gpio_b0!($reg),
+                $crate::periph_gpio_b1!($reg),
+                $crate::periph_gpio_b2!($reg),
+                $crate::periph_gpio_b3!($reg),
+                $crate::periph_gpio_b4!($reg),
+                $crate::periph_gpio_b5!($reg),
+                $crate::periph_gpio_b6!($reg),
+                $crate::periph_gpio_b7!($reg),
+                $crate::periph_gpio_b8!($reg),
+                $crate::periph_gpio_b9!($reg),
+                $crate::periph_gpio_b10!($reg),
+                $crate::periph_gpio_b11!($reg),
+                $crate::periph_gpio_b12!($reg),
+                $crate::periph_gpio_b13!($reg),
+                $crate::periph_gpio_b14!($reg),
+                $crate::periph_gpio_b15!($reg),
+            ),
+        )
+    };
+}
+
+/// Extracts GPIO port C head and pin register tokens at once.
+///
+/// Expands to a `(head, (pin0, ..., pin15))` tuple.
+#[macro_export]
+macro_rules! periph_gpio_c_split {
+    ($reg:ident) => {
+        (
+            $crate::periph_gpio_c_head!($reg),
+            (
+                $crate::periph_gpio_c0!($reg),
+                $crate::periph_gpio_c1!($reg),
+                $crate::periph_gpio_c2!($reg),
+                $crate::periph_gpio_c3!($reg),
+                $crate::periph_gpio_c4!($reg),
+                $crate::periph_gpio_c5!($reg),
+                $crate::periph_gpio_c6!($reg),
+                $crate::periph_gpio_c7!($reg),
+                $crate::periph_gpio_c8!($reg),
+                $crate::periph_gpio_c9!($reg),
+                $crate::periph_gpio_c10!($reg),
+                $crate::periph_gpio_c11!($reg),
+                $crate::periph_gpio_c12!($reg),
+                $crate::periph_gpio_c13!($reg),
+                $crate::periph_gpio_c14!($reg),
+                $crate::periph_gpio_c15!($reg),
+            ),
+        )
+    };
+}
+
+/// Extracts GPIO port D head and pin register tokens at once.
+///
+/// Expands to a `(head, (pin0, ..., pin15))` tuple.
+#[macro_export]
+macro_rules! periph_gpio_d_split {
+    ($reg:ident) => {
+        (
+            $crate::periph_gpio_d_head!($reg),
+            (
+                $crate::periph_gpio_d0!($reg),
+                $crate::periph_gpio_d1!($reg),
+                $crate::periph_gpio_d2!($reg),
+                $crate::periph_gpio_d3!($reg),
+                $crate::periph_gpio_d4!($reg),
+                $crate::periph_gpio_d5!($reg),
+                $crate::periph_gpio_d6!($reg),
+                $crate::periph_gpio_d7!($reg),
+                $crate::periph_gpio_d8!($reg),
+                $crate::periph_gpio_d9!($reg),
+                $crate::periph_gpio_d10!($reg),
+                $crate::periph_gpio_d11!($reg),
+                $crate::periph_gpio_d12!($reg),
+                $crate::periph_gpio_d13!($reg),
+                $crate::periph_gpio_d14!($reg),
+                $crate::periph_gpio_d15!($reg),
+            ),
+        )
+    };
+}
+
+/// Extracts GPIO port E head and pin register tokens at once.
+///
+/// Expands to a `(head, (pin0, ..., pin15))` tuple.
+#[macro_export]
+macro_rules! periph_gpio_e_split {
+    ($reg:ident) => {
+        (
+            $crate::periph_gpio_e_head!($reg),
+            (
+                $crate::periph_gpio_e0!($reg),
+                $crate::periph_gpio_e1!($reg),
+                $crate::periph_gpio_e2!($reg),
+                $crate::periph_gpio_e3!($reg),
+                $crate::periph_gpio_e4!($reg),
+                $crate::periph_gpio_e5!($reg),
+                $crate::periph_gpio_e6!($reg),
+                $crate::periph_gpio_e7!($reg),
+                $crate::periph_gpio_e8!($reg),
+                $crate::periph_gpio_e9!($reg),
+                $crate::periph_gpio_e10!($reg),
+                $crate::periph_gpio_e11!($reg),
+                $crate::periph_gpio_e12!($reg),
+                $crate::periph_gpio_e13!($reg),
+                $crate::periph_gpio_e14!($reg),
+                $crate::periph_gpio_e15!($reg),
+            ),
+        )
+    };
+}
+
+/// Extracts GPIO port F head and pin register tokens at once.
+///
+/// Expands to a `(head, (pin0, ..., pin15))` tuple.
+#[macro_export]
+macro_rules! periph_gpio_f_split {
+    ($reg:ident) => {
+        (
+            $crate::periph_gpio_f_head!($reg),
+            (
+                $crate::periph_gpio_f0!($reg),
+                $crate::periph_gpio_f1!($reg),
+                $crate::periph_gpio_f2!($reg),
+                $crate::periph_gpio_f3!($reg),
+                $crate::periph_gpio_f4!($reg),
+                $crate::periph_gpio_f5!($reg),
+                $crate::periph_gpio_f6!($reg),
+                $crate::periph_gpio_f7!($reg),
+                $crate::periph_gpio_f8!($reg),
+                $crate::periph_gpio_f9!($reg),
+                $crate::periph_gpio_f10!($reg),
+                $crate::periph_gpio_f11!($reg),
+                $crate::periph_gpio_f12!($reg),
+                $crate::periph_gpio_f13!($reg),
+                $crate::periph_gpio_f14!($reg),
+                $crate::periph_gpio_f15!($reg),
+            ),
+        )
+    };
+}
+
+/// Extracts GPIO port G head and pin register tokens at once.
+///
+/// Expands to a `(head, (pin0, ..., pin15))` tuple.
+#[macro_export]
+macro_rules! periph_gpio_g_split {
+    ($reg:ident) => {
+        (
+            $crate::periph_gpio_g_head!($reg),
+            (
+                $crate::periph_gpio_g0!($reg),
+                $crate::periph_gpio_g1!($reg),
+                $crate::periph_gpio_g2!($reg),
+                $crate::periph_gpio_g3!($reg),
+                $crate::periph_gpio_g4!($reg),
+                $crate::periph_gpio_g5!($reg),
+                $crate::periph_gpio_g6!($reg),
+                $crate::periph_gpio_g7!($reg),
+                $crate::periph_gpio_g8!($reg),
+                $crate::periph_gpio_g9!($reg),
+                $crate::periph_gpio_g10!($reg),
+                $crate::periph_gpio_g11!($reg),
+                $crate::periph_gpio_g12!($reg),
+                $crate::periph_gpio_g13!($reg),
+                $crate::periph_gpio_g14!($reg),
+                $crate::periph_gpio_g15!($reg),
+            ),
+        )
+    };
+}
+
+/// Extracts GPIO port H head and pin register tokens at once.
+///
+/// Expands to a `(head, (pin0, ..., pin15))` tuple.
+#[macro_export]
+macro_rules! periph_gpio_h_split {
+    ($reg:ident) => {
+        (
+            $crate::periph_gpio_h_head!($reg),
+            (
+                $crate::periph_gpio_h0!($reg),
+                $crate::periph_gpio_h1!($reg),
+                $crate::periph_gpio_h2!($reg),
+                $crate::periph_gpio_h3!($reg),
+                $crate::periph_gpio_h4!($reg),
+                $crate::periph_gpio_h5!($reg),
+                $crate::periph_gpio_h6!($reg),
+                $crate::periph_gpio_h7!($reg),
+                $crate::periph_gpio_h8!($reg),
+                $crate::periph_gpio_h9!($reg),
+                $crate::periph_gpio_h10!($reg),
+                $crate::periph_gpio_h11!($reg),
+                $crate::periph_gpio_h12!($reg),
+                $crate::periph_gpio_h13!($reg),
+                $crate::periph_gpio_h14!($reg),
+                $crate::periph_gpio_h15!($reg),
+            ),
+        )
+    };
+}
+
+/// Extracts GPIO port I head and pin register tokens at once.
+///
+/// Expands to a `(head, (pin0, ..., pin15))` tuple.
+#[macro_export]
+macro_rules! periph_gpio_i_split {
+    ($reg:ident) => {
+        (
+            $crate::periph_gpio_i_head!($reg),
+            (
+                $crate::periph_gpio_i0!($reg),
+                $crate::periph_gpio_i1!($reg),
+                $crate::periph_gpio_i2!($reg),
+                $crate::periph_gpio_i3!($reg),
+                $crate::periph_gpio_i4!($reg),
+                $crate::periph_gpio_i5!($reg),
+                $crate::periph_gpio_i6!($reg),
+                $crate::periph_gpio_i7!($reg),
+                $crate::periph_gpio_i8!($reg),
+                $crate::periph_gpio_i9!($reg),
+                $crate::periph_gpio_i10!($reg),
+                $crate::periph_gpio_i11!($reg),
+                $crate::periph_gpio_i12!($reg),
+                $crate::periph_gpio_i13!($reg),
+                $crate::periph_gpio_i14!($reg),
+                $crate::periph_gpio_i15!($reg),
+            ),
+        )
+    };
+}
+
+/// Extracts GPIO port J head and pin register tokens at once.
+///
+/// Expands to a `(head, (pin0, ..., pin15))` tuple.
+#[macro_export]
+macro_rules! periph_gpio_j_split {
+    ($reg:ident) => {
+        (
+            $crate::periph_gpio_j_head!($reg),
+            (
+                $crate::periph_gpio_j0!($reg),
+                $crate::periph_gpio_j1!($reg),
+                $crate::periph_gpio_j2!($reg),
+                $crate::periph_gpio_j3!($reg),
+                $crate::periph_gpio_j4!($reg),
+                $crate::periph_gpio_j5!($reg),
+                $crate::periph_gpio_j6!($reg),
+                $crate::periph_gpio_j7!($reg),
+                $crate::periph_gpio_j8!($reg),
+                $crate::periph_gpio_j9!($reg),
+                $crate::periph_gpio_j10!($reg),
+                $crate::periph_gpio_j11!($reg),
+                $crate::periph_gpio_j12!($reg),
+                $crate::periph_gpio_j13!($reg),
+                $crate::periph_gpio_j14!($reg),
+                $crate::periph_gpio_j15!($reg),
+            ),
+        )
+    };
+}
+
+/// Extracts GPIO port K head and pin register tokens at once.
+///
+/// Expands to a `(head, (pin0, ..., pin15))` tuple.
+#[macro_export]
+macro_rules! periph_gpio_k_split {
+    ($reg:ident) => {
+        (
+            $crate::periph_gpio_k_head!($reg),
+            (
+                $crate::periph_gpio_k0!($reg),
+                $crate::periph_gpio_k1!($reg),
+                $crate::periph_gpio_k2!($reg),
+                $crate::periph_gpio_k3!($reg),
+                $crate::periph_gpio_k4!($reg),
+                $crate::periph_gpio_k5!($reg),
+                $crate::periph_gpio_k6!($reg),
+                $crate::periph_gpio_k7!($reg),
+                $crate::periph_gpio_k8!($reg),
+                $crate::periph_gpio_k9!($reg),
+                $crate::periph_gpio_k10!($reg),
+                $crate::periph_gpio_k11!($reg),
+                $crate::periph_gpio_k12!($reg),
+                $crate::periph_gpio_k13!($reg),
+                $crate::periph_gpio_k14!($reg),
+                $crate::periph_gpio_k15!($reg),
+            ),
+        )
+    };
+}
@@ -0,0 +1,31 @@
+//! Trace port pins.
+//!
+//! The synchronous trace port (`TRACECLK`, `TRACED0`..`TRACED3`) is routed to
+//! fixed pins through alternate function 0. The traits below mark which GPIO
+//! pin peripheral variants carry each trace signal.
+
+use crate::pin::{GpioE2, GpioE3, GpioE4, GpioE5, GpioE6, GpioPinMap};
+
+/// Alternate function number of the trace port signals.
+pub const TRACE_AF: u32 = 0;
+
+/// GPIO pin carrying the `TRACECLK` signal.
+pub trait TraceClkPin: GpioPinMap {}
+
+/// GPIO pin carrying the `TRACED0` signal.
+pub trait TraceD0Pin: GpioPinMap {}
+
+/// GPIO pin carrying the `TRACED1` signal.
+pub trait TraceD1Pin: GpioPinMap {}
+
+/// GPIO pin carrying the `TRACED2` signal.
+pub trait TraceD2Pin: GpioPinMap {}
+
+/// GPIO pin carrying the `TRACED3` signal.
+pub trait TraceD3Pin: GpioPinMap {}
+
+impl TraceClkPin for GpioE2 {}
+impl TraceD0Pin for GpioE3 {}
+impl TraceD1Pin for GpioE4 {}
+impl TraceD2Pin for GpioE5 {}
+impl TraceD3Pin for GpioE6 {}
@@ -347,7 +347,7 @@ map_i2c! {
     I2C4SEL,
 }
 
-#[cfg(any(stm32_mcu = "stm32f412", stm32_mcu = "stm32f413"))]
+#[cfg(any(stm32_mcu = "stm32f412", stm32_mcu = "stm32f413", stm32_mcu = "stm32f446"))]
 map_i2c! {
     "Extracts I2CFMP1 register tokens.",
     periph_i2cfmp1,
@@ -21,7 +21,12 @@
 ))]
 mod fmp;
 
-#[cfg(any(stm32_mcu = "stm32f410", stm32_mcu = "stm32f412", stm32_mcu = "stm32f413"))]
+#[cfg(any(
+    stm32_mcu = "stm32f410",
+    stm32_mcu = "stm32f412",
+    stm32_mcu = "stm32f413",
+    stm32_mcu = "stm32f446"
+))]
 pub mod fmp;
 
 #[cfg(any(
@@ -392,3 +397,23 @@ map_i2c! {
     I2C3RST,
     I2C3LPEN,
 }
+
+/// Extracts I2C register tokens by instance number.
+///
+/// `periph_i2c!(2, reg)` is equivalent to `periph_i2c2!(reg)`. An instance
+/// missing on the selected MCU fails to resolve at the call site.
+#[macro_export]
+macro_rules! periph_i2c {
+    (1, $reg:ident) => {
+        $crate::periph_i2c1!($reg)
+    };
+    (2, $reg:ident) => {
+        $crate::periph_i2c2!($reg)
+    };
+    (3, $reg:ident) => {
+        $crate::periph_i2c3!($reg)
+    };
+    (4, $reg:ident) => {
+        $crate::periph_i2c4!($reg)
+    };
+}
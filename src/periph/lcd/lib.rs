@@ -0,0 +1,52 @@
+//! Liquid crystal display controller.
+//!
+//! The LCD is clocked by `RTCCLK`, which is selected by the `RCC_BDCR.RTCSEL`
+//! field of `periph_rtc!`. Writing `RCC_BDCR` requires the backup domain write
+//! protection to be disabled first with the `PWR_CR1.DBP` bit.
+
+#![feature(proc_macro_hygiene)]
+#![warn(missing_docs)]
+#![warn(clippy::pedantic)]
+#![allow(clippy::type_repetition_in_bounds, clippy::wildcard_imports)]
+#![no_std]
+
+#[allow(unused_imports)]
+use drone_core::periph;
+
+#[cfg(any(stm32_mcu = "stm32l4x3", stm32_mcu = "stm32l4x6"))]
+periph::singular! {
+    /// Extracts LCD register tokens.
+    pub macro periph_lcd;
+
+    /// LCD peripheral.
+    pub struct LcdPeriph;
+
+    drone_stm32_map_pieces::reg;
+    crate;
+
+    RCC {
+        APB1ENR1 {
+            LCDEN;
+        }
+        APB1RSTR1 {
+            LCDRST;
+        }
+        APB1SMENR1 {
+            LCDSMEN;
+        }
+    }
+    LCD {
+        CR;
+        FCR;
+        SR;
+        CLR;
+        RAM_COM0;
+        RAM_COM1;
+        RAM_COM2;
+        RAM_COM3;
+        RAM_COM4;
+        RAM_COM5;
+        RAM_COM6;
+        RAM_COM7;
+    }
+}
@@ -0,0 +1,89 @@
+//! LCD-TFT display controller.
+
+#![feature(proc_macro_hygiene)]
+#![warn(missing_docs)]
+#![warn(clippy::pedantic)]
+#![allow(clippy::type_repetition_in_bounds, clippy::wildcard_imports)]
+#![no_std]
+
+#[allow(unused_imports)]
+use drone_core::periph;
+
+#[cfg(any(
+    stm32_mcu = "stm32f429",
+    stm32_mcu = "stm32f469",
+    stm32_mcu = "stm32l4r9",
+    stm32_mcu = "stm32l4s9"
+))]
+periph::singular! {
+    /// Extracts LTDC register tokens.
+    pub macro periph_ltdc;
+
+    /// LTDC peripheral.
+    pub struct LtdcPeriph;
+
+    drone_stm32_map_pieces::reg;
+    crate;
+
+    RCC {
+        APB2ENR {
+            LTDCEN;
+        }
+        APB2RSTR {
+            LTDCRST;
+        }
+        #[cfg(any(
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f469"
+        ))]
+        APB2LPENR {
+            LTDCLPEN;
+        }
+        #[cfg(any(
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s9"
+        ))]
+        APB2SMENR {
+            LTDCSMEN;
+        }
+    }
+    LTDC {
+        SSCR;
+        BPCR;
+        AWCR;
+        TWCR;
+        GCR;
+        SRCR;
+        BCCR;
+        IER;
+        ISR;
+        ICR;
+        LIPCR;
+        CPSR;
+        CDSR;
+        L1CR;
+        L1WHPCR;
+        L1WVPCR;
+        L1CKCR;
+        L1PFCR;
+        L1CACR;
+        L1DCCR;
+        L1BFCR;
+        L1CFBAR;
+        L1CFBLR;
+        L1CFBLNR;
+        L1CLUTWR;
+        L2CR;
+        L2WHPCR;
+        L2WVPCR;
+        L2CKCR;
+        L2PFCR;
+        L2CACR;
+        L2DCCR;
+        L2BFCR;
+        L2CFBAR;
+        L2CFBLR;
+        L2CFBLNR;
+        L2CLUTWR;
+    }
+}
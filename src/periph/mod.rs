@@ -3,21 +3,98 @@
 #[doc(no_inline)]
 pub use drone_cortexm::map::periph::*;
 
+#[cfg(feature = "dma")]
+pub use drone_stm32_map_periph_dma::{STM32_DMA1_CH_COUNT, STM32_DMA2_CH_COUNT};
+#[cfg(feature = "gpio")]
+pub use drone_stm32_map_periph_gpio::STM32_GPIO_PORT_COUNT;
+#[cfg(feature = "uart")]
+pub use drone_stm32_map_periph_uart::STM32_UART_COUNT;
+
 #[cfg(feature = "adc")]
 pub extern crate drone_stm32_map_periph_adc as adc;
+#[cfg(feature = "aes")]
+pub extern crate drone_stm32_map_periph_aes as aes;
+#[cfg(feature = "bkp")]
+pub extern crate drone_stm32_map_periph_bkp as bkp;
+#[cfg(feature = "can")]
+pub extern crate drone_stm32_map_periph_can as can;
+#[cfg(feature = "cec")]
+pub extern crate drone_stm32_map_periph_cec as cec;
+#[cfg(feature = "comp")]
+pub extern crate drone_stm32_map_periph_comp as comp;
+#[cfg(feature = "crc")]
+pub extern crate drone_stm32_map_periph_crc as crc;
+#[cfg(feature = "crs")]
+pub extern crate drone_stm32_map_periph_crs as crs;
+#[cfg(feature = "cryp")]
+pub extern crate drone_stm32_map_periph_cryp as cryp;
+#[cfg(feature = "dac")]
+pub extern crate drone_stm32_map_periph_dac as dac;
+#[cfg(feature = "dfsdm")]
+pub extern crate drone_stm32_map_periph_dfsdm as dfsdm;
 #[cfg(feature = "dma")]
 pub extern crate drone_stm32_map_periph_dma as dma;
+#[cfg(feature = "dma2d")]
+pub extern crate drone_stm32_map_periph_dma2d as dma2d;
+#[cfg(feature = "dsi")]
+pub extern crate drone_stm32_map_periph_dsi as dsi;
+#[cfg(feature = "eth")]
+pub extern crate drone_stm32_map_periph_eth as eth;
 #[cfg(feature = "exti")]
 pub extern crate drone_stm32_map_periph_exti as exti;
+#[cfg(feature = "fmc")]
+pub extern crate drone_stm32_map_periph_fmc as fmc;
+#[cfg(feature = "fsmc")]
+pub extern crate drone_stm32_map_periph_fsmc as fsmc;
+#[cfg(feature = "fw")]
+pub extern crate drone_stm32_map_periph_fw as fw;
+#[cfg(feature = "gfxmmu")]
+pub extern crate drone_stm32_map_periph_gfxmmu as gfxmmu;
 #[cfg(feature = "gpio")]
 pub extern crate drone_stm32_map_periph_gpio as gpio;
 #[cfg(feature = "i2c")]
 pub extern crate drone_stm32_map_periph_i2c as i2c;
+#[cfg(feature = "lcd")]
+pub extern crate drone_stm32_map_periph_lcd as lcd;
+#[cfg(feature = "ltdc")]
+pub extern crate drone_stm32_map_periph_ltdc as ltdc;
+#[cfg(feature = "octospi")]
+pub extern crate drone_stm32_map_periph_octospi as octospi;
+#[cfg(feature = "opamp")]
+pub extern crate drone_stm32_map_periph_opamp as opamp;
+#[cfg(feature = "osc")]
+pub extern crate drone_stm32_map_periph_osc as osc;
+#[cfg(feature = "otg_fs")]
+pub extern crate drone_stm32_map_periph_otg_fs as otg_fs;
+#[cfg(feature = "otg_hs")]
+pub extern crate drone_stm32_map_periph_otg_hs as otg_hs;
+#[cfg(feature = "pwr")]
+pub extern crate drone_stm32_map_periph_pwr as pwr;
+#[cfg(feature = "quadspi")]
+pub extern crate drone_stm32_map_periph_quadspi as quadspi;
 #[cfg(feature = "rtc")]
 pub extern crate drone_stm32_map_periph_rtc as rtc;
+#[cfg(feature = "sai")]
+pub extern crate drone_stm32_map_periph_sai as sai;
+#[cfg(feature = "sdio")]
+pub extern crate drone_stm32_map_periph_sdio as sdio;
+#[cfg(feature = "sdmmc")]
+pub extern crate drone_stm32_map_periph_sdmmc as sdmmc;
+#[cfg(feature = "spdifrx")]
+pub extern crate drone_stm32_map_periph_spdifrx as spdifrx;
 #[cfg(feature = "spi")]
 pub extern crate drone_stm32_map_periph_spi as spi;
+#[cfg(feature = "swpmi")]
+pub extern crate drone_stm32_map_periph_swpmi as swpmi;
+#[cfg(feature = "syscfg")]
+pub extern crate drone_stm32_map_periph_syscfg as syscfg;
 #[cfg(feature = "tim")]
 pub extern crate drone_stm32_map_periph_tim as tim;
+#[cfg(feature = "tsc")]
+pub extern crate drone_stm32_map_periph_tsc as tsc;
 #[cfg(feature = "uart")]
 pub extern crate drone_stm32_map_periph_uart as uart;
+#[cfg(feature = "usb")]
+pub extern crate drone_stm32_map_periph_usb as usb;
+#[cfg(feature = "vrefbuf")]
+pub extern crate drone_stm32_map_periph_vrefbuf as vrefbuf;
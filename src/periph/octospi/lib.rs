@@ -0,0 +1,494 @@
+//! Octo-SPI interface.
+//!
+//! Both OCTOSPI instances reach the pins through the OCTOSPIM I/O manager,
+//! which is mapped as a separate peripheral.
+
+#![feature(proc_macro_hygiene)]
+#![warn(missing_docs)]
+#![warn(clippy::pedantic)]
+#![allow(clippy::type_repetition_in_bounds, clippy::wildcard_imports)]
+#![no_std]
+
+use drone_core::periph;
+use drone_cortexm::reg::marker::*;
+
+/// Base address of the memory-mapped OCTOSPI1 region.
+pub const OCTOSPI1_BANK_BASE: usize = 0x9000_0000;
+
+/// Base address of the memory-mapped OCTOSPI2 region.
+pub const OCTOSPI2_BANK_BASE: usize = 0x7000_0000;
+
+periph! {
+    /// Generic OCTOSPI peripheral variant.
+    pub trait OctospiMap {}
+
+    /// Generic OCTOSPI peripheral.
+    pub struct OctospiPeriph;
+
+    RCC {
+        BUSENR {
+            0x20 RwRegBitBand Shared;
+            OSPIEN { RwRwRegFieldBitBand }
+        }
+        BUSRSTR {
+            0x20 RwRegBitBand Shared;
+            OSPIRST { RwRwRegFieldBitBand }
+        }
+        BUSSMENR {
+            0x20 RwRegBitBand Shared;
+            OSPISMEN { RwRwRegFieldBitBand }
+        }
+    }
+    OCTOSPI {
+        CR {
+            0x20 RwReg;
+            ABORT { RwRwRegFieldBit }
+            APMS { RwRwRegFieldBit }
+            DMAEN { RwRwRegFieldBit }
+            DQM { RwRwRegFieldBit }
+            EN { RwRwRegFieldBit }
+            FMODE { RwRwRegFieldBits }
+            FSEL { RwRwRegFieldBit }
+            FTHRES { RwRwRegFieldBits }
+            FTIE { RwRwRegFieldBit }
+            PMM { RwRwRegFieldBit }
+            SMIE { RwRwRegFieldBit }
+            TCEN { RwRwRegFieldBit }
+            TCIE { RwRwRegFieldBit }
+            TEIE { RwRwRegFieldBit }
+            TOIE { RwRwRegFieldBit }
+        }
+        DCR1 {
+            0x20 RwReg;
+            CKMODE { RwRwRegFieldBit }
+            CSHT { RwRwRegFieldBits }
+            DEVSIZE { RwRwRegFieldBits }
+            FRCK { RwRwRegFieldBit }
+            MTYP { RwRwRegFieldBits }
+        }
+        DCR2 {
+            0x20 RwReg;
+            PRESCALER { RwRwRegFieldBits }
+            WRAPSIZE { RwRwRegFieldBits }
+        }
+        DCR3 {
+            0x20 RwReg;
+            CSBOUND { RwRwRegFieldBits }
+        }
+        SR {
+            0x20 RoReg;
+            BUSY { RoRoRegFieldBit }
+            FLEVEL { RoRoRegFieldBits }
+            FTF { RoRoRegFieldBit }
+            SMF { RoRoRegFieldBit }
+            TCF { RoRoRegFieldBit }
+            TEF { RoRoRegFieldBit }
+            TOF { RoRoRegFieldBit }
+        }
+        FCR {
+            0x20 WoReg;
+            CSMF { WoWoRegFieldBit }
+            CTCF { WoWoRegFieldBit }
+            CTEF { WoWoRegFieldBit }
+            CTOF { WoWoRegFieldBit }
+        }
+        DLR {
+            0x20 RwReg;
+            DL { RwRwRegFieldBits }
+        }
+        AR {
+            0x20 RwReg;
+            ADDRESS { RwRwRegFieldBits }
+        }
+        DR {
+            0x20 RwReg;
+            DATA { RwRwRegFieldBits }
+        }
+        PSMKR {
+            0x20 RwReg;
+            MASK { RwRwRegFieldBits }
+        }
+        PSMAR {
+            0x20 RwReg;
+            MATCH { RwRwRegFieldBits }
+        }
+        PIR {
+            0x20 RwReg;
+            INTERVAL { RwRwRegFieldBits }
+        }
+        CCR {
+            0x20 RwReg;
+            ABDTR { RwRwRegFieldBit }
+            ABMODE { RwRwRegFieldBits }
+            ABSIZE { RwRwRegFieldBits }
+            ADDTR { RwRwRegFieldBit }
+            ADMODE { RwRwRegFieldBits }
+            ADSIZE { RwRwRegFieldBits }
+            DDTR { RwRwRegFieldBit }
+            DMODE { RwRwRegFieldBits }
+            DQSE { RwRwRegFieldBit }
+            IDTR { RwRwRegFieldBit }
+            IMODE { RwRwRegFieldBits }
+            ISIZE { RwRwRegFieldBits }
+            SIOO { RwRwRegFieldBit }
+        }
+        TCR {
+            0x20 RwReg;
+            DCYC { RwRwRegFieldBits }
+            DHQC { RwRwRegFieldBit }
+            SSHIFT { RwRwRegFieldBit }
+        }
+        IR {
+            0x20 RwReg;
+            INSTRUCTION { RwRwRegFieldBits }
+        }
+        ABR {
+            0x20 RwReg;
+            ALTERNATE { RwRwRegFieldBits }
+        }
+        LPTR {
+            0x20 RwReg;
+            TIMEOUT { RwRwRegFieldBits }
+        }
+        WCCR {
+            0x20 RwReg;
+            ABDTR { RwRwRegFieldBit }
+            ABMODE { RwRwRegFieldBits }
+            ABSIZE { RwRwRegFieldBits }
+            ADDTR { RwRwRegFieldBit }
+            ADMODE { RwRwRegFieldBits }
+            ADSIZE { RwRwRegFieldBits }
+            DDTR { RwRwRegFieldBit }
+            DMODE { RwRwRegFieldBits }
+            DQSE { RwRwRegFieldBit }
+            IDTR { RwRwRegFieldBit }
+            IMODE { RwRwRegFieldBits }
+            ISIZE { RwRwRegFieldBits }
+            SIOO { RwRwRegFieldBit }
+        }
+        WTCR {
+            0x20 RwReg;
+            DCYC { RwRwRegFieldBits }
+        }
+        WIR {
+            0x20 RwReg;
+            INSTRUCTION { RwRwRegFieldBits }
+        }
+        WABR {
+            0x20 RwReg;
+            ALTERNATE { RwRwRegFieldBits }
+        }
+        HLCR {
+            0x20 RwReg;
+            LM { RwRwRegFieldBit }
+            TACC { RwRwRegFieldBits }
+            TRWR { RwRwRegFieldBits }
+            WZL { RwRwRegFieldBit }
+        }
+        HWCFGR {
+            0x20 RoReg;
+            AXI { RoRoRegFieldBits }
+            FIFO { RoRoRegFieldBits }
+            IDL { RoRoRegFieldBits }
+            MMW { RoRoRegFieldBits }
+            MST { RoRoRegFieldBits }
+            PRES { RoRoRegFieldBits }
+        }
+        VER {
+            0x20 RoReg;
+            VER { RoRoRegFieldBits }
+        }
+        ID {
+            0x20 RoReg;
+            ID { RoRoRegFieldBits }
+        }
+        MID {
+            0x20 RoReg;
+            MID { RoRoRegFieldBits }
+        }
+    }
+}
+
+#[allow(unused_macros)]
+macro_rules! map_octospi {
+    (
+        $octospi_macro_doc:expr,
+        $octospi_macro:ident,
+        $octospi_ty_doc:expr,
+        $octospi_ty:ident,
+        $ospien:ident,
+        $ospirst:ident,
+        $ospismen:ident,
+        $octospi:ident,
+    ) => {
+        periph::map! {
+            #[doc = $octospi_macro_doc]
+            pub macro $octospi_macro;
+
+            #[doc = $octospi_ty_doc]
+            pub struct $octospi_ty;
+
+            impl OctospiMap for $octospi_ty {}
+
+            drone_stm32_map_pieces::reg;
+            crate;
+
+            RCC {
+                BUSENR {
+                    AHB3ENR Shared;
+                    OSPIEN { $ospien }
+                }
+                BUSRSTR {
+                    AHB3RSTR Shared;
+                    OSPIRST { $ospirst }
+                }
+                BUSSMENR {
+                    AHB3SMENR Shared;
+                    OSPISMEN { $ospismen }
+                }
+            }
+            OCTOSPI {
+                $octospi;
+                CR {
+                    CR;
+                    ABORT { ABORT }
+                    APMS { APMS }
+                    DMAEN { DMAEN }
+                    DQM { DQM }
+                    EN { EN }
+                    FMODE { FMODE }
+                    FSEL { FSEL }
+                    FTHRES { FTHRES }
+                    FTIE { FTIE }
+                    PMM { PMM }
+                    SMIE { SMIE }
+                    TCEN { TCEN }
+                    TCIE { TCIE }
+                    TEIE { TEIE }
+                    TOIE { TOIE }
+                }
+                DCR1 {
+                    DCR1;
+                    CKMODE { CKMODE }
+                    CSHT { CSHT }
+                    DEVSIZE { DEVSIZE }
+                    FRCK { FRCK }
+                    MTYP { MTYP }
+                }
+                DCR2 {
+                    DCR2;
+                    PRESCALER { PRESCALER }
+                    WRAPSIZE { WRAPSIZE }
+                }
+                DCR3 {
+                    DCR3;
+                    CSBOUND { CSBOUND }
+                }
+                SR {
+                    SR;
+                    BUSY { BUSY }
+                    FLEVEL { FLEVEL }
+                    FTF { FTF }
+                    SMF { SMF }
+                    TCF { TCF }
+                    TEF { TEF }
+                    TOF { TOF }
+                }
+                FCR {
+                    FCR;
+                    CSMF { CSMF }
+                    CTCF { CTCF }
+                    CTEF { CTEF }
+                    CTOF { CTOF }
+                }
+                DLR {
+                    DLR;
+                    DL { DL }
+                }
+                AR {
+                    AR;
+                    ADDRESS { ADDRESS }
+                }
+                DR {
+                    DR;
+                    DATA { DATA }
+                }
+                PSMKR {
+                    PSMKR;
+                    MASK { MASK }
+                }
+                PSMAR {
+                    PSMAR;
+                    MATCH { MATCH }
+                }
+                PIR {
+                    PIR;
+                    INTERVAL { INTERVAL }
+                }
+                CCR {
+                    CCR;
+                    ABDTR { ABDTR }
+                    ABMODE { ABMODE }
+                    ABSIZE { ABSIZE }
+                    ADDTR { ADDTR }
+                    ADMODE { ADMODE }
+                    ADSIZE { ADSIZE }
+                    DDTR { DDTR }
+                    DMODE { DMODE }
+                    DQSE { DQSE }
+                    IDTR { IDTR }
+                    IMODE { IMODE }
+                    ISIZE { ISIZE }
+                    SIOO { SIOO }
+                }
+                TCR {
+                    TCR;
+                    DCYC { DCYC }
+                    DHQC { DHQC }
+                    SSHIFT { SSHIFT }
+                }
+                IR {
+                    IR;
+                    INSTRUCTION { INSTRUCTION }
+                }
+                ABR {
+                    ABR;
+                    ALTERNATE { ALTERNATE }
+                }
+                LPTR {
+                    LPTR;
+                    TIMEOUT { TIMEOUT }
+                }
+                WCCR {
+                    WCCR;
+                    ABDTR { ABDTR }
+                    ABMODE { ABMODE }
+                    ABSIZE { ABSIZE }
+                    ADDTR { ADDTR }
+                    ADMODE { ADMODE }
+                    ADSIZE { ADSIZE }
+                    DDTR { DDTR }
+                    DMODE { DMODE }
+                    DQSE { DQSE }
+                    IDTR { IDTR }
+                    IMODE { IMODE }
+                    ISIZE { ISIZE }
+                    SIOO { SIOO }
+                }
+                WTCR {
+                    WTCR;
+                    DCYC { DCYC }
+                }
+                WIR {
+                    WIR;
+                    INSTRUCTION { INSTRUCTION }
+                }
+                WABR {
+                    WABR;
+                    ALTERNATE { ALTERNATE }
+                }
+                HLCR {
+                    HLCR;
+                    LM { LM }
+                    TACC { TACC }
+                    TRWR { TRWR }
+                    WZL { WZL }
+                }
+                HWCFGR {
+                    HWCFGR;
+                    AXI { AXI }
+                    FIFO { FIFO }
+                    IDL { IDL }
+                    MMW { MMW }
+                    MST { MST }
+                    PRES { PRES }
+                }
+                VER {
+                    VER;
+                    VER { VER }
+                }
+                ID {
+                    ID;
+                    ID { ID }
+                }
+                MID {
+                    MID;
+                    MID { MID }
+                }
+            }
+        }
+    };
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32l4r5",
+    stm32_mcu = "stm32l4r7",
+    stm32_mcu = "stm32l4r9",
+    stm32_mcu = "stm32l4s5",
+    stm32_mcu = "stm32l4s7",
+    stm32_mcu = "stm32l4s9"
+))]
+map_octospi! {
+    "Extracts OCTOSPI1 register tokens.",
+    periph_octospi1,
+    "OCTOSPI1 peripheral variant.",
+    Octospi1,
+    OSPI1EN,
+    OSPI1RST,
+    OSPI1SMEN,
+    OCTOSPI1,
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32l4r5",
+    stm32_mcu = "stm32l4r7",
+    stm32_mcu = "stm32l4r9",
+    stm32_mcu = "stm32l4s5",
+    stm32_mcu = "stm32l4s7",
+    stm32_mcu = "stm32l4s9"
+))]
+map_octospi! {
+    "Extracts OCTOSPI2 register tokens.",
+    periph_octospi2,
+    "OCTOSPI2 peripheral variant.",
+    Octospi2,
+    OSPI2EN,
+    OSPI2RST,
+    OSPI2SMEN,
+    OCTOSPI2,
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32l4r5",
+    stm32_mcu = "stm32l4r7",
+    stm32_mcu = "stm32l4r9",
+    stm32_mcu = "stm32l4s5",
+    stm32_mcu = "stm32l4s7",
+    stm32_mcu = "stm32l4s9"
+))]
+periph::singular! {
+    /// Extracts OCTOSPIM register tokens.
+    pub macro periph_octospim;
+
+    /// OCTOSPIM peripheral.
+    pub struct OctospimPeriph;
+
+    drone_stm32_map_pieces::reg;
+    crate;
+
+    RCC {
+        AHB2ENR {
+            OSPIMEN;
+        }
+        AHB2RSTR {
+            OSPIMRST;
+        }
+        AHB2SMENR {
+            OSPIMSMEN;
+        }
+    }
+    OCTOSPIM {
+        P1CR;
+        P2CR;
+    }
+}
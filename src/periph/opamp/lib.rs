@@ -0,0 +1,162 @@
+//! Operational amplifiers.
+//!
+//! The `OPA_RANGE` bit is located in `OPAMP1_CSR` and applies to both
+//! amplifiers, so it is only present on [`Opamp1`].
+
+#![feature(proc_macro_hygiene)]
+#![warn(missing_docs)]
+#![warn(clippy::pedantic)]
+#![allow(clippy::type_repetition_in_bounds, clippy::wildcard_imports)]
+#![no_std]
+
+use drone_core::periph;
+use drone_cortexm::reg::marker::*;
+
+periph! {
+    /// Generic OPAMP peripheral variant.
+    pub trait OpampMap {}
+
+    /// Generic OPAMP peripheral.
+    pub struct OpampPeriph;
+
+    RCC {
+        APB1ENR1 {
+            0x20 RwRegBitBand Shared;
+            OPAMPEN { RwRwRegFieldBitBand }
+        }
+        APB1RSTR1 {
+            0x20 RwRegBitBand Shared;
+            OPAMPRST { RwRwRegFieldBitBand }
+        }
+        APB1SMENR1 {
+            0x20 RwRegBitBand Shared;
+            OPAMPSMEN { RwRwRegFieldBitBand }
+        }
+    }
+    OPAMP {
+        CSR {
+            0x20 RwRegBitBand;
+            CALON { RwRwRegFieldBitBand }
+            CALOUT { RwRwRegFieldBitBand }
+            CALSEL { RwRwRegFieldBitBand }
+            OPA_RANGE { RwRwRegFieldBitBand Option }
+            OPAEN { RwRwRegFieldBitBand }
+            OPALPM { RwRwRegFieldBitBand }
+            OPAMODE { RwRwRegFieldBits }
+            PGA_GAIN { RwRwRegFieldBits }
+            USERTRIM { RwRwRegFieldBitBand }
+            VM_SEL { RwRwRegFieldBits }
+            VP_SEL { RwRwRegFieldBitBand }
+        }
+        OTR {
+            0x20 RwRegBitBand;
+            TRIMOFFSETN { RwRwRegFieldBits }
+            TRIMOFFSETP { RwRwRegFieldBits }
+        }
+        LPOTR {
+            0x20 RwRegBitBand;
+            TRIMLPOFFSETN { RwRwRegFieldBits }
+            TRIMLPOFFSETP { RwRwRegFieldBits }
+        }
+    }
+}
+
+#[allow(unused_macros)]
+macro_rules! map_opamp {
+    (
+        $opamp_macro_doc:expr,
+        $opamp_macro:ident,
+        $opamp_ty_doc:expr,
+        $opamp_ty:ident,
+        $csr:ident,
+        $otr:ident,
+        $lpotr:ident,
+        ($($opa_range:ident)?),
+    ) => {
+        periph::map! {
+            #[doc = $opamp_macro_doc]
+            pub macro $opamp_macro;
+
+            #[doc = $opamp_ty_doc]
+            pub struct $opamp_ty;
+
+            impl OpampMap for $opamp_ty {}
+
+            drone_stm32_map_pieces::reg;
+            crate;
+
+            RCC {
+                APB1ENR1 {
+                    APB1ENR1 Shared;
+                    OPAMPEN { OPAMPEN }
+                }
+                APB1RSTR1 {
+                    APB1RSTR1 Shared;
+                    OPAMPRST { OPAMPRST }
+                }
+                APB1SMENR1 {
+                    APB1SMENR1 Shared;
+                    OPAMPSMEN { OPAMPSMEN }
+                }
+            }
+            OPAMP {
+                CSR {
+                    $csr;
+                    CALON { CALON }
+                    CALOUT { CALOUT }
+                    CALSEL { CALSEL }
+                    OPA_RANGE { $($opa_range Option)* }
+                    OPAEN { OPAEN }
+                    OPALPM { OPALPM }
+                    OPAMODE { OPAMODE }
+                    PGA_GAIN { PGA_GAIN }
+                    USERTRIM { USERTRIM }
+                    VM_SEL { VM_SEL }
+                    VP_SEL { VP_SEL }
+                }
+                OTR {
+                    $otr;
+                    TRIMOFFSETN { TRIMOFFSETN }
+                    TRIMOFFSETP { TRIMOFFSETP }
+                }
+                LPOTR {
+                    $lpotr;
+                    TRIMLPOFFSETN { TRIMLPOFFSETN }
+                    TRIMLPOFFSETP { TRIMLPOFFSETP }
+                }
+            }
+        }
+    };
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32l4x2",
+    stm32_mcu = "stm32l4x5",
+    stm32_mcu = "stm32l4x6"
+))]
+map_opamp! {
+    "Extracts OPAMP1 register tokens.",
+    periph_opamp1,
+    "OPAMP1 peripheral variant.",
+    Opamp1,
+    OPAMP1_CSR,
+    OPAMP1_OTR,
+    OPAMP1_LPOTR,
+    (OPA_RANGE),
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32l4x2",
+    stm32_mcu = "stm32l4x5",
+    stm32_mcu = "stm32l4x6"
+))]
+map_opamp! {
+    "Extracts OPAMP2 register tokens.",
+    periph_opamp2,
+    "OPAMP2 peripheral variant.",
+    Opamp2,
+    OPAMP2_CSR,
+    OPAMP2_OTR,
+    OPAMP2_LPOTR,
+    (),
+}
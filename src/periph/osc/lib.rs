@@ -0,0 +1,271 @@
+//! Oscillators.
+//!
+//! Each internal and external oscillator is a separate peripheral, so that the
+//! ownership of a clock source is explicit. The internal oscillators also carry
+//! their factory calibration and user trimming fields. The LSE drive strength
+//! field `LSEDRV` belongs to the RTC peripheral on STM32L4.
+
+#![feature(proc_macro_hygiene)]
+#![warn(missing_docs)]
+#![warn(clippy::pedantic)]
+#![allow(clippy::type_repetition_in_bounds, clippy::wildcard_imports)]
+#![no_std]
+
+#[allow(unused_imports)]
+use drone_core::periph;
+
+periph::singular! {
+    /// Extracts HSE register tokens.
+    pub macro periph_hse;
+
+    /// HSE peripheral.
+    pub struct HsePeriph;
+
+    drone_stm32_map_pieces::reg;
+    crate;
+
+    RCC {
+        CR {
+            CSSON;
+            HSEBYP;
+            HSEON;
+            HSERDY;
+        }
+    }
+}
+
+periph::singular! {
+    /// Extracts HSI register tokens.
+    pub macro periph_hsi;
+
+    /// HSI peripheral.
+    pub struct HsiPeriph;
+
+    drone_stm32_map_pieces::reg;
+    crate;
+
+    RCC {
+        CR {
+            #[cfg(any(
+                stm32_mcu = "stm32l4x1",
+                stm32_mcu = "stm32l4x2",
+                stm32_mcu = "stm32l4x3",
+                stm32_mcu = "stm32l4x5",
+                stm32_mcu = "stm32l4x6",
+                stm32_mcu = "stm32l4r5",
+                stm32_mcu = "stm32l4r7",
+                stm32_mcu = "stm32l4r9",
+                stm32_mcu = "stm32l4s5",
+                stm32_mcu = "stm32l4s7",
+                stm32_mcu = "stm32l4s9"
+            ))]
+            HSIASFS;
+            #[cfg(any(
+                stm32_mcu = "stm32f100",
+                stm32_mcu = "stm32f101",
+                stm32_mcu = "stm32f102",
+                stm32_mcu = "stm32f103",
+                stm32_mcu = "stm32f107",
+                stm32_mcu = "stm32f401",
+                stm32_mcu = "stm32f405",
+                stm32_mcu = "stm32f407",
+                stm32_mcu = "stm32f410",
+                stm32_mcu = "stm32f411",
+                stm32_mcu = "stm32f412",
+                stm32_mcu = "stm32f413",
+                stm32_mcu = "stm32f427",
+                stm32_mcu = "stm32f429",
+                stm32_mcu = "stm32f446",
+                stm32_mcu = "stm32f469"
+            ))]
+            HSICAL;
+            #[cfg(any(
+                stm32_mcu = "stm32l4x1",
+                stm32_mcu = "stm32l4x2",
+                stm32_mcu = "stm32l4x3",
+                stm32_mcu = "stm32l4x5",
+                stm32_mcu = "stm32l4x6",
+                stm32_mcu = "stm32l4r5",
+                stm32_mcu = "stm32l4r7",
+                stm32_mcu = "stm32l4r9",
+                stm32_mcu = "stm32l4s5",
+                stm32_mcu = "stm32l4s7",
+                stm32_mcu = "stm32l4s9"
+            ))]
+            HSIKERON;
+            HSION;
+            HSIRDY;
+            #[cfg(any(
+                stm32_mcu = "stm32f100",
+                stm32_mcu = "stm32f101",
+                stm32_mcu = "stm32f102",
+                stm32_mcu = "stm32f103",
+                stm32_mcu = "stm32f107",
+                stm32_mcu = "stm32f401",
+                stm32_mcu = "stm32f405",
+                stm32_mcu = "stm32f407",
+                stm32_mcu = "stm32f410",
+                stm32_mcu = "stm32f411",
+                stm32_mcu = "stm32f412",
+                stm32_mcu = "stm32f413",
+                stm32_mcu = "stm32f427",
+                stm32_mcu = "stm32f429",
+                stm32_mcu = "stm32f446",
+                stm32_mcu = "stm32f469"
+            ))]
+            HSITRIM;
+        }
+        #[cfg(any(
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9"
+        ))]
+        ICSCR {
+            HSICAL;
+            HSITRIM;
+        }
+    }
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32l4x1",
+    stm32_mcu = "stm32l4x2",
+    stm32_mcu = "stm32l4r5",
+    stm32_mcu = "stm32l4r7",
+    stm32_mcu = "stm32l4r9",
+    stm32_mcu = "stm32l4s5",
+    stm32_mcu = "stm32l4s7",
+    stm32_mcu = "stm32l4s9"
+))]
+periph::singular! {
+    /// Extracts HSI48 register tokens.
+    pub macro periph_hsi48;
+
+    /// HSI48 peripheral.
+    pub struct Hsi48Periph;
+
+    drone_stm32_map_pieces::reg;
+    crate;
+
+    RCC {
+        CRRCR {
+            HSI48CAL;
+            HSI48ON;
+            HSI48RDY;
+        }
+    }
+}
+
+periph::singular! {
+    /// Extracts LSE register tokens.
+    pub macro periph_lse;
+
+    /// LSE peripheral.
+    pub struct LsePeriph;
+
+    drone_stm32_map_pieces::reg;
+    crate;
+
+    RCC {
+        BDCR {
+            #[cfg(any(
+                stm32_mcu = "stm32l4x1",
+                stm32_mcu = "stm32l4x2",
+                stm32_mcu = "stm32l4x3",
+                stm32_mcu = "stm32l4x5",
+                stm32_mcu = "stm32l4x6",
+                stm32_mcu = "stm32l4r5",
+                stm32_mcu = "stm32l4r7",
+                stm32_mcu = "stm32l4r9",
+                stm32_mcu = "stm32l4s5",
+                stm32_mcu = "stm32l4s7",
+                stm32_mcu = "stm32l4s9"
+            ))]
+            LSECSSD;
+            #[cfg(any(
+                stm32_mcu = "stm32l4x1",
+                stm32_mcu = "stm32l4x2",
+                stm32_mcu = "stm32l4x3",
+                stm32_mcu = "stm32l4x5",
+                stm32_mcu = "stm32l4x6",
+                stm32_mcu = "stm32l4r5",
+                stm32_mcu = "stm32l4r7",
+                stm32_mcu = "stm32l4r9",
+                stm32_mcu = "stm32l4s5",
+                stm32_mcu = "stm32l4s7",
+                stm32_mcu = "stm32l4s9"
+            ))]
+            LSECSSON;
+            LSEBYP;
+            LSEON;
+            LSERDY;
+        }
+    }
+}
+
+periph::singular! {
+    /// Extracts LSI register tokens.
+    pub macro periph_lsi;
+
+    /// LSI peripheral.
+    pub struct LsiPeriph;
+
+    drone_stm32_map_pieces::reg;
+    crate;
+
+    RCC {
+        CSR {
+            LSION;
+            LSIRDY;
+        }
+    }
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32l4x1",
+    stm32_mcu = "stm32l4x2",
+    stm32_mcu = "stm32l4x3",
+    stm32_mcu = "stm32l4x5",
+    stm32_mcu = "stm32l4x6",
+    stm32_mcu = "stm32l4r5",
+    stm32_mcu = "stm32l4r7",
+    stm32_mcu = "stm32l4r9",
+    stm32_mcu = "stm32l4s5",
+    stm32_mcu = "stm32l4s7",
+    stm32_mcu = "stm32l4s9"
+))]
+periph::singular! {
+    /// Extracts MSI register tokens.
+    pub macro periph_msi;
+
+    /// MSI peripheral.
+    pub struct MsiPeriph;
+
+    drone_stm32_map_pieces::reg;
+    crate;
+
+    RCC {
+        CR {
+            MSION;
+            MSIPLLEN;
+            MSIRANGE;
+            MSIRDY;
+            MSIRGSEL;
+        }
+        CSR {
+            MSISRANGE;
+        }
+        ICSCR {
+            MSICAL;
+            MSITRIM;
+        }
+    }
+}
@@ -0,0 +1,200 @@
+//! USB on-the-go full-speed.
+//!
+//! The core is split into the global, device-mode, host-mode and power and
+//! clock gating register blocks, which are all extracted by `periph_otg_fs!`.
+
+#![feature(proc_macro_hygiene)]
+#![warn(missing_docs)]
+#![warn(clippy::pedantic)]
+#![allow(clippy::type_repetition_in_bounds, clippy::wildcard_imports)]
+#![no_std]
+
+#[allow(unused_imports)]
+use drone_core::periph;
+
+/// Base address of the OTG_FS core.
+pub const OTG_FS_BASE: usize = 0x5000_0000;
+
+/// Returns the address of the data FIFO of the endpoint or host channel `n`.
+///
+/// Each endpoint and host channel has a 4 KiB window for pushing to and popping
+/// from its FIFO, which can be used as a DMA peripheral address.
+#[must_use]
+pub const fn otg_fs_fifo(n: usize) -> usize {
+    OTG_FS_BASE + 0x1000 * (n + 1)
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32f401",
+    stm32_mcu = "stm32f405",
+    stm32_mcu = "stm32f407",
+    stm32_mcu = "stm32f411",
+    stm32_mcu = "stm32f412",
+    stm32_mcu = "stm32f413",
+    stm32_mcu = "stm32f427",
+    stm32_mcu = "stm32f429",
+    stm32_mcu = "stm32f446",
+    stm32_mcu = "stm32f469",
+    stm32_mcu = "stm32l4x6",
+    stm32_mcu = "stm32l4r5",
+    stm32_mcu = "stm32l4r7",
+    stm32_mcu = "stm32l4r9",
+    stm32_mcu = "stm32l4s5",
+    stm32_mcu = "stm32l4s7",
+    stm32_mcu = "stm32l4s9"
+))]
+periph::singular! {
+    /// Extracts OTG_FS register tokens.
+    pub macro periph_otg_fs;
+
+    /// OTG_FS peripheral.
+    pub struct OtgFsPeriph;
+
+    drone_stm32_map_pieces::reg;
+    crate;
+
+    RCC {
+        AHB2ENR {
+            OTGFSEN;
+        }
+        AHB2RSTR {
+            OTGFSRST;
+        }
+        #[cfg(any(
+            stm32_mcu = "stm32f401",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f411",
+            stm32_mcu = "stm32f412",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469"
+        ))]
+        AHB2LPENR {
+            OTGFSLPEN;
+        }
+        #[cfg(any(
+            stm32_mcu = "stm32l4x6",
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9"
+        ))]
+        AHB2SMENR {
+            OTGFSSMEN;
+        }
+    }
+    OTG_FS_GLOBAL {
+        FS_GOTGCTL;
+        FS_GOTGINT;
+        FS_GAHBCFG;
+        FS_GUSBCFG;
+        FS_GRSTCTL;
+        FS_GINTSTS;
+        FS_GINTMSK;
+        FS_GRXSTSR_Device;
+        FS_GRXSTSR_Host;
+        FS_GRXFSIZ;
+        FS_GNPTXFSIZ_Device;
+        FS_GNPTXFSIZ_Host;
+        FS_GNPTXSTS;
+        FS_GCCFG;
+        FS_CID;
+        FS_HPTXFSIZ;
+        FS_DIEPTXF1;
+        FS_DIEPTXF2;
+        FS_DIEPTXF3;
+        #[cfg(any(stm32_mcu = "stm32f469"))]
+        FS_DIEPTXF4;
+        #[cfg(any(stm32_mcu = "stm32f469"))]
+        FS_DIEPTXF5;
+    }
+    OTG_FS_DEVICE {
+        FS_DCFG;
+        FS_DCTL;
+        FS_DSTS;
+        FS_DIEPMSK;
+        FS_DOEPMSK;
+        FS_DAINT;
+        FS_DAINTMSK;
+        DVBUSDIS;
+        DVBUSPULSE;
+        DIEPEMPMSK;
+        FS_DIEPCTL0;
+        DIEPCTL1;
+        DIEPCTL2;
+        DIEPCTL3;
+        DIEPINT0;
+        DIEPINT1;
+        DIEPINT2;
+        DIEPINT3;
+        DIEPTSIZ0;
+        DIEPTSIZ1;
+        DIEPTSIZ2;
+        DIEPTSIZ3;
+        DTXFSTS0;
+        DTXFSTS1;
+        DTXFSTS2;
+        DTXFSTS3;
+        DOEPCTL0;
+        DOEPCTL1;
+        DOEPCTL2;
+        DOEPCTL3;
+        DOEPINT0;
+        DOEPINT1;
+        DOEPINT2;
+        DOEPINT3;
+        DOEPTSIZ0;
+        DOEPTSIZ1;
+        DOEPTSIZ2;
+        DOEPTSIZ3;
+    }
+    OTG_FS_HOST {
+        FS_HCFG;
+        HFIR;
+        FS_HFNUM;
+        FS_HPTXSTS;
+        HAINT;
+        HAINTMSK;
+        FS_HPRT;
+        FS_HCCHAR0;
+        FS_HCINT0;
+        FS_HCINTMSK0;
+        FS_HCTSIZ0;
+        FS_HCCHAR1;
+        FS_HCINT1;
+        FS_HCINTMSK1;
+        FS_HCTSIZ1;
+        FS_HCCHAR2;
+        FS_HCINT2;
+        FS_HCINTMSK2;
+        FS_HCTSIZ2;
+        FS_HCCHAR3;
+        FS_HCINT3;
+        FS_HCINTMSK3;
+        FS_HCTSIZ3;
+        FS_HCCHAR4;
+        FS_HCINT4;
+        FS_HCINTMSK4;
+        FS_HCTSIZ4;
+        FS_HCCHAR5;
+        FS_HCINT5;
+        FS_HCINTMSK5;
+        FS_HCTSIZ5;
+        FS_HCCHAR6;
+        FS_HCINT6;
+        FS_HCINTMSK6;
+        FS_HCTSIZ6;
+        FS_HCCHAR7;
+        FS_HCINT7;
+        FS_HCINTMSK7;
+        FS_HCTSIZ7;
+    }
+    OTG_FS_PWRCLK {
+        FS_PCGCCTL;
+    }
+}
@@ -0,0 +1,227 @@
+//! USB on-the-go high-speed.
+//!
+//! Unlike OTG_FS, the high-speed core has an internal DMA controller (the
+//! `DMAEN` and `HBSTLEN` fields of `OTG_HS_GAHBCFG` and the per-endpoint and
+//! per-channel DMA address registers) and can be connected to an external ULPI
+//! PHY (the `ULPI*` fields of `OTG_HS_GUSBCFG` and the separate ULPI clock
+//! enable bits in RCC).
+
+#![feature(proc_macro_hygiene)]
+#![warn(missing_docs)]
+#![warn(clippy::pedantic)]
+#![allow(clippy::type_repetition_in_bounds, clippy::wildcard_imports)]
+#![no_std]
+
+#[allow(unused_imports)]
+use drone_core::periph;
+
+#[cfg(any(
+    stm32_mcu = "stm32f405",
+    stm32_mcu = "stm32f407",
+    stm32_mcu = "stm32f427",
+    stm32_mcu = "stm32f429",
+    stm32_mcu = "stm32f446",
+    stm32_mcu = "stm32f469"
+))]
+periph::singular! {
+    /// Extracts OTG_HS register tokens.
+    pub macro periph_otg_hs;
+
+    /// OTG_HS peripheral.
+    pub struct OtgHsPeriph;
+
+    drone_stm32_map_pieces::reg;
+    crate;
+
+    RCC {
+        AHB1ENR {
+            OTGHSEN;
+            OTGHSULPIEN;
+        }
+        AHB1RSTR {
+            OTGHSRST;
+        }
+        AHB1LPENR {
+            OTGHSLPEN;
+            OTGHSULPILPEN;
+        }
+    }
+    OTG_HS_GLOBAL {
+        OTG_HS_GOTGCTL;
+        OTG_HS_GOTGINT;
+        OTG_HS_GAHBCFG;
+        OTG_HS_GUSBCFG;
+        OTG_HS_GRSTCTL;
+        OTG_HS_GINTSTS;
+        OTG_HS_GINTMSK;
+        OTG_HS_GRXSTSR_Host;
+        OTG_HS_GRXSTSP_Host;
+        OTG_HS_GRXFSIZ;
+        OTG_HS_GNPTXFSIZ_Host;
+        OTG_HS_TX0FSIZ_Peripheral;
+        OTG_HS_GNPTXSTS;
+        OTG_HS_GCCFG;
+        OTG_HS_CID;
+        OTG_HS_HPTXFSIZ;
+        OTG_HS_DIEPTXF1;
+        OTG_HS_DIEPTXF2;
+        OTG_HS_DIEPTXF3;
+        OTG_HS_DIEPTXF4;
+        OTG_HS_DIEPTXF5;
+        OTG_HS_DIEPTXF6;
+        OTG_HS_DIEPTXF7;
+        OTG_HS_GRXSTSR_Peripheral;
+        OTG_HS_GRXSTSP_Peripheral;
+    }
+    OTG_HS_DEVICE {
+        OTG_HS_DCFG;
+        OTG_HS_DCTL;
+        OTG_HS_DSTS;
+        OTG_HS_DIEPMSK;
+        OTG_HS_DOEPMSK;
+        OTG_HS_DAINT;
+        OTG_HS_DAINTMSK;
+        OTG_HS_DVBUSDIS;
+        OTG_HS_DVBUSPULSE;
+        OTG_HS_DTHRCTL;
+        OTG_HS_DIEPEMPMSK;
+        OTG_HS_DEACHINT;
+        OTG_HS_DEACHINTMSK;
+        OTG_HS_DIEPEACHMSK1;
+        OTG_HS_DOEPEACHMSK1;
+        OTG_HS_DIEPCTL0;
+        OTG_HS_DIEPCTL1;
+        OTG_HS_DIEPCTL2;
+        OTG_HS_DIEPCTL3;
+        OTG_HS_DIEPCTL4;
+        OTG_HS_DIEPCTL5;
+        OTG_HS_DIEPCTL6;
+        OTG_HS_DIEPCTL7;
+        OTG_HS_DIEPINT0;
+        OTG_HS_DIEPINT1;
+        OTG_HS_DIEPINT2;
+        OTG_HS_DIEPINT3;
+        OTG_HS_DIEPINT4;
+        OTG_HS_DIEPINT5;
+        OTG_HS_DIEPINT6;
+        OTG_HS_DIEPINT7;
+        OTG_HS_DIEPTSIZ0;
+        OTG_HS_DIEPDMA1;
+        OTG_HS_DIEPDMA2;
+        OTG_HS_DIEPDMA3;
+        OTG_HS_DIEPDMA4;
+        OTG_HS_DIEPDMA5;
+        OTG_HS_DTXFSTS0;
+        OTG_HS_DTXFSTS1;
+        OTG_HS_DTXFSTS2;
+        OTG_HS_DTXFSTS3;
+        OTG_HS_DTXFSTS4;
+        OTG_HS_DTXFSTS5;
+        OTG_HS_DIEPTSIZ1;
+        OTG_HS_DIEPTSIZ2;
+        OTG_HS_DIEPTSIZ3;
+        OTG_HS_DIEPTSIZ4;
+        OTG_HS_DIEPTSIZ5;
+        OTG_HS_DOEPCTL0;
+        OTG_HS_DOEPCTL1;
+        OTG_HS_DOEPCTL2;
+        OTG_HS_DOEPCTL3;
+        OTG_HS_DOEPINT0;
+        OTG_HS_DOEPINT1;
+        OTG_HS_DOEPINT2;
+        OTG_HS_DOEPINT3;
+        OTG_HS_DOEPINT4;
+        OTG_HS_DOEPINT5;
+        OTG_HS_DOEPINT6;
+        OTG_HS_DOEPINT7;
+        OTG_HS_DOEPTSIZ0;
+        OTG_HS_DOEPTSIZ1;
+        OTG_HS_DOEPTSIZ2;
+        OTG_HS_DOEPTSIZ3;
+        OTG_HS_DOEPTSIZ4;
+    }
+    OTG_HS_HOST {
+        OTG_HS_HCFG;
+        OTG_HS_HFIR;
+        OTG_HS_HFNUM;
+        OTG_HS_HPTXSTS;
+        OTG_HS_HAINT;
+        OTG_HS_HAINTMSK;
+        OTG_HS_HPRT;
+        OTG_HS_HCCHAR0;
+        OTG_HS_HCCHAR1;
+        OTG_HS_HCCHAR2;
+        OTG_HS_HCCHAR3;
+        OTG_HS_HCCHAR4;
+        OTG_HS_HCCHAR5;
+        OTG_HS_HCCHAR6;
+        OTG_HS_HCCHAR7;
+        OTG_HS_HCCHAR8;
+        OTG_HS_HCCHAR9;
+        OTG_HS_HCCHAR10;
+        OTG_HS_HCCHAR11;
+        OTG_HS_HCSPLT0;
+        OTG_HS_HCSPLT1;
+        OTG_HS_HCSPLT2;
+        OTG_HS_HCSPLT3;
+        OTG_HS_HCSPLT4;
+        OTG_HS_HCSPLT5;
+        OTG_HS_HCSPLT6;
+        OTG_HS_HCSPLT7;
+        OTG_HS_HCSPLT8;
+        OTG_HS_HCSPLT9;
+        OTG_HS_HCSPLT10;
+        OTG_HS_HCSPLT11;
+        OTG_HS_HCINT0;
+        OTG_HS_HCINT1;
+        OTG_HS_HCINT2;
+        OTG_HS_HCINT3;
+        OTG_HS_HCINT4;
+        OTG_HS_HCINT5;
+        OTG_HS_HCINT6;
+        OTG_HS_HCINT7;
+        OTG_HS_HCINT8;
+        OTG_HS_HCINT9;
+        OTG_HS_HCINT10;
+        OTG_HS_HCINT11;
+        OTG_HS_HCINTMSK0;
+        OTG_HS_HCINTMSK1;
+        OTG_HS_HCINTMSK2;
+        OTG_HS_HCINTMSK3;
+        OTG_HS_HCINTMSK4;
+        OTG_HS_HCINTMSK5;
+        OTG_HS_HCINTMSK6;
+        OTG_HS_HCINTMSK7;
+        OTG_HS_HCINTMSK8;
+        OTG_HS_HCINTMSK9;
+        OTG_HS_HCINTMSK10;
+        OTG_HS_HCINTMSK11;
+        OTG_HS_HCTSIZ0;
+        OTG_HS_HCTSIZ1;
+        OTG_HS_HCTSIZ2;
+        OTG_HS_HCTSIZ3;
+        OTG_HS_HCTSIZ4;
+        OTG_HS_HCTSIZ5;
+        OTG_HS_HCTSIZ6;
+        OTG_HS_HCTSIZ7;
+        OTG_HS_HCTSIZ8;
+        OTG_HS_HCTSIZ9;
+        OTG_HS_HCTSIZ10;
+        OTG_HS_HCTSIZ11;
+        OTG_HS_HCDMA0;
+        OTG_HS_HCDMA1;
+        OTG_HS_HCDMA2;
+        OTG_HS_HCDMA3;
+        OTG_HS_HCDMA4;
+        OTG_HS_HCDMA5;
+        OTG_HS_HCDMA6;
+        OTG_HS_HCDMA7;
+        OTG_HS_HCDMA8;
+        OTG_HS_HCDMA9;
+        OTG_HS_HCDMA10;
+        OTG_HS_HCDMA11;
+    }
+    OTG_HS_PWRCLK {
+        OTG_HS_PCGCR;
+    }
+}
@@ -0,0 +1,128 @@
+//! Power control.
+//!
+//! The `DBP` bit in `CR` (`CR1` on STM32L4) disables the backup domain write
+//! protection, which is required before configuring the RTC, LCD or backup
+//! registers.
+//!
+//! On STM32F4 the `VOS` field selects the regulator voltage scale. Reaching
+//! the maximum system clock on STM32F427/F429/F446/F469 also requires the
+//! over-drive mode: set `CR.ODEN`, wait for `CSR.ODRDY`, then set `CR.ODSWEN`
+//! and wait for `CSR.ODSWRDY`. The under-drive mode for Stop is enabled with
+//! `CR.UDEN` and reported in `CSR.UDRDY`. The backup SRAM is retained in
+//! Standby and `VBAT` modes when `CSR.BRE` is set and `CSR.BRR` reads as
+//! ready.
+//!
+//! On STM32L4 the wakeup pins are enabled with `CR3.EWUPx` and their polarity
+//! is selected with `CR4.WPx`. The `WUFx` flags in `SR1` are cleared through
+//! the `CWUFx` bits in `SCR`. Setting `CR3.APC` applies the `PUCRx` and `PDCRx`
+//! pull configuration to the I/Os in Standby and Shutdown modes.
+
+#![feature(proc_macro_hygiene)]
+#![warn(missing_docs)]
+#![warn(clippy::pedantic)]
+#![allow(clippy::type_repetition_in_bounds, clippy::wildcard_imports)]
+#![no_std]
+
+#[allow(unused_imports)]
+use drone_core::periph;
+
+#[cfg(any(
+    stm32_mcu = "stm32f401",
+    stm32_mcu = "stm32f405",
+    stm32_mcu = "stm32f407",
+    stm32_mcu = "stm32f410",
+    stm32_mcu = "stm32f411",
+    stm32_mcu = "stm32f412",
+    stm32_mcu = "stm32f413",
+    stm32_mcu = "stm32f427",
+    stm32_mcu = "stm32f429",
+    stm32_mcu = "stm32f446",
+    stm32_mcu = "stm32f469"
+))]
+periph::singular! {
+    /// Extracts PWR register tokens.
+    pub macro periph_pwr;
+
+    /// PWR peripheral.
+    pub struct PwrPeriph;
+
+    drone_stm32_map_pieces::reg;
+    crate;
+
+    RCC {
+        APB1ENR {
+            PWREN;
+        }
+        APB1RSTR {
+            PWRRST;
+        }
+        APB1LPENR {
+            PWRLPEN;
+        }
+    }
+    PWR {
+        CR;
+        CSR;
+    }
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32l4x1",
+    stm32_mcu = "stm32l4x2",
+    stm32_mcu = "stm32l4x3",
+    stm32_mcu = "stm32l4x5",
+    stm32_mcu = "stm32l4x6",
+    stm32_mcu = "stm32l4r5",
+    stm32_mcu = "stm32l4r7",
+    stm32_mcu = "stm32l4r9",
+    stm32_mcu = "stm32l4s5",
+    stm32_mcu = "stm32l4s7",
+    stm32_mcu = "stm32l4s9"
+))]
+periph::singular! {
+    /// Extracts PWR register tokens.
+    pub macro periph_pwr;
+
+    /// PWR peripheral.
+    pub struct PwrPeriph;
+
+    drone_stm32_map_pieces::reg;
+    crate;
+
+    RCC {
+        APB1ENR1 {
+            PWREN;
+        }
+        APB1RSTR1 {
+            PWRRST;
+        }
+        APB1SMENR1 {
+            PWRSMEN;
+        }
+    }
+    PWR {
+        CR1;
+        CR2;
+        CR3;
+        CR4;
+        SR1;
+        SR2;
+        SCR;
+        PUCRA;
+        PDCRA;
+        PUCRB;
+        PDCRB;
+        PUCRC;
+        PDCRC;
+        PUCRD;
+        PDCRD;
+        PUCRE;
+        PDCRE;
+        PUCRF;
+        PDCRF;
+        PUCRG;
+        PDCRG;
+        PUCRH;
+        PDCRH;
+    }
+}
@@ -0,0 +1,79 @@
+//! Quad-SPI interface.
+//!
+//! The dual-flash mode (`CR.DFM` and `CR.FSEL`) is available on STM32F4 and
+//! STM32L4x5/L4x6 only; on STM32L4x1/L4x2 these bits are reserved.
+
+#![feature(proc_macro_hygiene)]
+#![warn(missing_docs)]
+#![warn(clippy::pedantic)]
+#![allow(clippy::type_repetition_in_bounds, clippy::wildcard_imports)]
+#![no_std]
+
+#[allow(unused_imports)]
+use drone_core::periph;
+
+/// Base address of the memory-mapped QUADSPI flash region.
+pub const QUADSPI_BANK_BASE: usize = 0x9000_0000;
+
+#[cfg(any(
+    stm32_mcu = "stm32f412",
+    stm32_mcu = "stm32f413",
+    stm32_mcu = "stm32f446",
+    stm32_mcu = "stm32f469",
+    stm32_mcu = "stm32l4x1",
+    stm32_mcu = "stm32l4x2",
+    stm32_mcu = "stm32l4x5",
+    stm32_mcu = "stm32l4x6"
+))]
+periph::singular! {
+    /// Extracts QUADSPI register tokens.
+    pub macro periph_quadspi;
+
+    /// QUADSPI peripheral.
+    pub struct QuadspiPeriph;
+
+    drone_stm32_map_pieces::reg;
+    crate;
+
+    RCC {
+        AHB3ENR {
+            QSPIEN;
+        }
+        AHB3RSTR {
+            QSPIRST;
+        }
+        #[cfg(any(
+            stm32_mcu = "stm32f412",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469"
+        ))]
+        AHB3LPENR {
+            QSPILPEN;
+        }
+        #[cfg(any(
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6"
+        ))]
+        AHB3SMENR {
+            QSPISMEN;
+        }
+    }
+    QUADSPI {
+        CR;
+        DCR;
+        SR;
+        FCR;
+        DLR;
+        CCR;
+        AR;
+        ABR;
+        DR;
+        PSMKR;
+        PSMAR;
+        PIR;
+        LPTR;
+    }
+}
@@ -1,4 +1,19 @@
 //! Real-time clock.
+//!
+//! The backup domain reset bit `BDRST` is part of this peripheral, because
+//! resetting the backup domain also wipes the RTC and its backup registers.
+//!
+//! The backup registers (`BKP0R`..`BKP19R` on STM32F4, `BKP0R`..`BKP31R` on
+//! STM32L4) keep their contents in Standby mode and, with `VBAT` present,
+//! across power loss. Writes to the backup domain require the `PWR` `DBP` bit
+//! to be set first.
+//!
+//! To wake up from low-power modes on a tamper or time-stamp event, configure
+//! the EXTI line given by [`TAMP_STAMP_EXTI_LINE`] (`periph_exti21!` on
+//! STM32F4, `periph_exti19!` on STM32L4) to trigger on the rising edge. The
+//! periodic wakeup timer (`WUTR`, `CR.WUCKSEL`, `CR.WUTE`, `CR.WUTIE` and
+//! `ISR.WUTF`) uses [`WAKEUP_EXTI_LINE`] (`periph_exti22!` on STM32F4,
+//! `periph_exti20!` on STM32L4) in the same way.
 
 #![feature(proc_macro_hygiene)]
 #![warn(missing_docs)]
@@ -9,6 +24,157 @@
 #[allow(unused_imports)]
 use drone_core::periph;
 
+/// EXTI line connected to the RTC tamper and time-stamp events.
+#[cfg(any(
+    stm32_mcu = "stm32f401",
+    stm32_mcu = "stm32f405",
+    stm32_mcu = "stm32f407",
+    stm32_mcu = "stm32f410",
+    stm32_mcu = "stm32f411",
+    stm32_mcu = "stm32f412",
+    stm32_mcu = "stm32f413",
+    stm32_mcu = "stm32f427",
+    stm32_mcu = "stm32f429",
+    stm32_mcu = "stm32f446",
+    stm32_mcu = "stm32f469"
+))]
+pub const TAMP_STAMP_EXTI_LINE: usize = 21;
+
+/// EXTI line connected to the RTC tamper and time-stamp events.
+#[cfg(any(
+    stm32_mcu = "stm32l4x1",
+    stm32_mcu = "stm32l4x2",
+    stm32_mcu = "stm32l4x3",
+    stm32_mcu = "stm32l4x5",
+    stm32_mcu = "stm32l4x6",
+    stm32_mcu = "stm32l4r5",
+    stm32_mcu = "stm32l4r7",
+    stm32_mcu = "stm32l4r9",
+    stm32_mcu = "stm32l4s5",
+    stm32_mcu = "stm32l4s7",
+    stm32_mcu = "stm32l4s9"
+))]
+pub const TAMP_STAMP_EXTI_LINE: usize = 19;
+
+/// EXTI line connected to the RTC wakeup timer event.
+#[cfg(any(
+    stm32_mcu = "stm32f401",
+    stm32_mcu = "stm32f405",
+    stm32_mcu = "stm32f407",
+    stm32_mcu = "stm32f410",
+    stm32_mcu = "stm32f411",
+    stm32_mcu = "stm32f412",
+    stm32_mcu = "stm32f413",
+    stm32_mcu = "stm32f427",
+    stm32_mcu = "stm32f429",
+    stm32_mcu = "stm32f446",
+    stm32_mcu = "stm32f469"
+))]
+pub const WAKEUP_EXTI_LINE: usize = 22;
+
+/// EXTI line connected to the RTC wakeup timer event.
+#[cfg(any(
+    stm32_mcu = "stm32l4x1",
+    stm32_mcu = "stm32l4x2",
+    stm32_mcu = "stm32l4x3",
+    stm32_mcu = "stm32l4x5",
+    stm32_mcu = "stm32l4x6",
+    stm32_mcu = "stm32l4r5",
+    stm32_mcu = "stm32l4r7",
+    stm32_mcu = "stm32l4r9",
+    stm32_mcu = "stm32l4s5",
+    stm32_mcu = "stm32l4s7",
+    stm32_mcu = "stm32l4s9"
+))]
+pub const WAKEUP_EXTI_LINE: usize = 20;
+
+#[cfg(any(
+    stm32_mcu = "stm32f401",
+    stm32_mcu = "stm32f405",
+    stm32_mcu = "stm32f407",
+    stm32_mcu = "stm32f410",
+    stm32_mcu = "stm32f411",
+    stm32_mcu = "stm32f412",
+    stm32_mcu = "stm32f413",
+    stm32_mcu = "stm32f427",
+    stm32_mcu = "stm32f429",
+    stm32_mcu = "stm32f446",
+    stm32_mcu = "stm32f469"
+))]
+periph::singular! {
+    /// Extracts RTC register tokens.
+    pub macro periph_rtc;
+
+    /// RTC peripheral.
+    pub struct RtcPeriph;
+
+    drone_stm32_map_pieces::reg;
+    crate;
+
+    RCC {
+        #[cfg(any(stm32_mcu = "stm32f410", stm32_mcu = "stm32f413"))]
+        APB1ENR {
+            RTCAPBEN;
+        }
+        #[cfg(any(stm32_mcu = "stm32f410", stm32_mcu = "stm32f413"))]
+        APB1LPENR {
+            RTCAPBLPEN;
+        }
+        BDCR {
+            BDRST;
+            #[cfg(any(
+                stm32_mcu = "stm32f413",
+                stm32_mcu = "stm32f446",
+                stm32_mcu = "stm32f469"
+            ))]
+            LSEMOD;
+            RTCEN;
+            RTCSEL;
+        }
+    }
+    RTC {
+        TR;
+        DR;
+        CR;
+        ISR;
+        PRER;
+        WUTR;
+        CALIBR;
+        ALRMAR;
+        ALRMBR;
+        WPR;
+        SSR;
+        SHIFTR;
+        TSTR;
+        TSDR;
+        TSSSR;
+        CALR;
+        TAFCR;
+        ALRMASSR;
+        ALRMBSSR;
+        BKP0R;
+        BKP1R;
+        BKP2R;
+        BKP3R;
+        BKP4R;
+        BKP5R;
+        BKP6R;
+        BKP7R;
+        BKP8R;
+        BKP9R;
+        BKP10R;
+        BKP11R;
+        BKP12R;
+        BKP13R;
+        BKP14R;
+        BKP15R;
+        BKP16R;
+        BKP17R;
+        BKP18R;
+        BKP19R;
+    }
+}
+
 #[cfg(any(
     stm32_mcu = "stm32l4x1",
     stm32_mcu = "stm32l4x2",
@@ -40,6 +206,8 @@ periph::singular! {
             RTCAPBSMEN;
         }
         BDCR {
+            BDRST;
+            LSEDRV;
             RTCEN;
             RTCSEL;
         }
@@ -0,0 +1,333 @@
+//! SAI audio sub-blocks.
+
+use drone_core::periph;
+use drone_cortexm::reg::marker::*;
+
+periph! {
+    /// Generic SAI sub-block peripheral variant.
+    pub trait SaiBlockMap {
+        /// SAI head peripheral variant.
+        type SaiMap: super::SaiMap;
+    }
+
+    /// Generic SAI sub-block peripheral.
+    pub struct SaiBlockPeriph;
+
+    SAI {
+        CR1 {
+            0x20 RwRegBitBand;
+            CKSTR { RwRwRegFieldBitBand }
+            DMAEN { RwRwRegFieldBitBand }
+            DS { RwRwRegFieldBits }
+            LSBFIRST { RwRwRegFieldBitBand }
+            MCKDIV { RwRwRegFieldBits }
+            MODE { RwRwRegFieldBits }
+            MONO { RwRwRegFieldBitBand }
+            NODIV { RwRwRegFieldBitBand }
+            OUTDRIV { RwRwRegFieldBitBand }
+            PRTCFG { RwRwRegFieldBits }
+            SAIEN { RwRwRegFieldBitBand }
+            SYNCEN { RwRwRegFieldBits }
+        }
+        CR2 {
+            0x20 RwRegBitBand;
+            COMP { RwRwRegFieldBits }
+            CPL { RwRwRegFieldBitBand }
+            FFLUSH { RwRwRegFieldBitBand }
+            FTH { RwRwRegFieldBits }
+            MUTE { RwRwRegFieldBitBand }
+            MUTECNT { RwRwRegFieldBits }
+            MUTEVAL { RwRwRegFieldBitBand }
+            TRIS { RwRwRegFieldBitBand }
+        }
+        FRCR {
+            0x20 RwRegBitBand;
+            FRL { RwRwRegFieldBits }
+            FSALL { RwRwRegFieldBits }
+            FSDEF { RwRwRegFieldBitBand }
+            FSOFF { RwRwRegFieldBitBand }
+            FSPOL { RwRwRegFieldBitBand }
+        }
+        SLOTR {
+            0x20 RwRegBitBand;
+            FBOFF { RwRwRegFieldBits }
+            NBSLOT { RwRwRegFieldBits }
+            SLOTEN { RwRwRegFieldBits }
+            SLOTSZ { RwRwRegFieldBits }
+        }
+        IM {
+            0x20 RwRegBitBand;
+            AFSDETIE { RwRwRegFieldBitBand }
+            CNRDYIE { RwRwRegFieldBitBand }
+            FREQIE { RwRwRegFieldBitBand }
+            LFSDETIE { RwRwRegFieldBitBand }
+            MUTEDETIE { RwRwRegFieldBitBand }
+            OVRUDRIE { RwRwRegFieldBitBand }
+            WCKCFGIE { RwRwRegFieldBitBand }
+        }
+        SR {
+            0x20 RoRegBitBand;
+            AFSDET { RoRoRegFieldBitBand }
+            CNRDY { RoRoRegFieldBitBand }
+            FLVL { RoRoRegFieldBits }
+            FREQ { RoRoRegFieldBitBand }
+            LFSDET { RoRoRegFieldBitBand }
+            MUTEDET { RoRoRegFieldBitBand }
+            OVRUDR { RoRoRegFieldBitBand }
+            WCKCFG { RoRoRegFieldBitBand }
+        }
+        CLRFR {
+            0x20 WoRegBitBand;
+            CAFSDET { WoWoRegFieldBitBand }
+            CCNRDY { WoWoRegFieldBitBand }
+            CLFSDET { WoWoRegFieldBitBand }
+            CMUTEDET { WoWoRegFieldBitBand }
+            COVRUDR { WoWoRegFieldBitBand }
+            CWCKCFG { WoWoRegFieldBitBand }
+        }
+        DR {
+            0x20 RwRegBitBand;
+            DATA { RwRwRegFieldBits }
+        }
+    }
+}
+
+#[allow(unused_macros)]
+macro_rules! map_sai_block {
+    (
+        $sai_block_macro_doc:expr,
+        $sai_block_macro:ident,
+        $sai_block_ty_doc:expr,
+        $sai_block_ty:ident,
+        $sai_ty:ident,
+        $sai:ident,
+        $cr1:ident,
+        $cr2:ident,
+        $frcr:ident,
+        $slotr:ident,
+        $im:ident,
+        $sr:ident,
+        $clrfr:ident,
+        $dr:ident,
+    ) => {
+        periph::map! {
+            #[doc = $sai_block_macro_doc]
+            pub macro $sai_block_macro;
+
+            #[doc = $sai_block_ty_doc]
+            pub struct $sai_block_ty;
+
+            impl SaiBlockMap for $sai_block_ty {
+                type SaiMap = super::$sai_ty;
+            }
+
+            drone_stm32_map_pieces::reg;
+            crate::block;
+
+            SAI {
+                $sai;
+                CR1 {
+                    $cr1;
+                    CKSTR { CKSTR }
+                    DMAEN { DMAEN }
+                    DS { DS }
+                    LSBFIRST { LSBFIRST }
+                    MCKDIV { MCKDIV }
+                    MODE { MODE }
+                    MONO { MONO }
+                    NODIV { NODIV }
+                    OUTDRIV { OUTDRIV }
+                    PRTCFG { PRTCFG }
+                    SAIEN { SAIEN }
+                    SYNCEN { SYNCEN }
+                }
+                CR2 {
+                    $cr2;
+                    COMP { COMP }
+                    CPL { CPL }
+                    FFLUSH { FFLUSH }
+                    FTH { FTH }
+                    MUTE { MUTE }
+                    MUTECNT { MUTECNT }
+                    MUTEVAL { MUTEVAL }
+                    TRIS { TRIS }
+                }
+                FRCR {
+                    $frcr;
+                    FRL { FRL }
+                    FSALL { FSALL }
+                    FSDEF { FSDEF }
+                    FSOFF { FSOFF }
+                    FSPOL { FSPOL }
+                }
+                SLOTR {
+                    $slotr;
+                    FBOFF { FBOFF }
+                    NBSLOT { NBSLOT }
+                    SLOTEN { SLOTEN }
+                    SLOTSZ { SLOTSZ }
+                }
+                IM {
+                    $im;
+                    AFSDETIE { AFSDETIE }
+                    CNRDYIE { CNRDYIE }
+                    FREQIE { FREQIE }
+                    LFSDETIE { LFSDETIE }
+                    MUTEDETIE { MUTEDETIE }
+                    OVRUDRIE { OVRUDRIE }
+                    WCKCFGIE { WCKCFGIE }
+                }
+                SR {
+                    $sr;
+                    AFSDET { AFSDET }
+                    CNRDY { CNRDY }
+                    FLVL { FLVL }
+                    FREQ { FREQ }
+                    LFSDET { LFSDET }
+                    MUTEDET { MUTEDET }
+                    OVRUDR { OVRUDR }
+                    WCKCFG { WCKCFG }
+                }
+                CLRFR {
+                    $clrfr;
+                    CAFSDET { CAFSDET }
+                    CCNRDY { CCNRDY }
+                    CLFSDET { CLFSDET }
+                    CMUTEDET { CMUTEDET }
+                    COVRUDR { COVRUDR }
+                    CWCKCFG { CWCKCFG }
+                }
+                DR {
+                    $dr;
+                    DATA { DATA }
+                }
+            }
+        }
+    };
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32f413",
+    stm32_mcu = "stm32f427",
+    stm32_mcu = "stm32f429",
+    stm32_mcu = "stm32f446",
+    stm32_mcu = "stm32f469",
+    stm32_mcu = "stm32l4x1",
+    stm32_mcu = "stm32l4x2",
+    stm32_mcu = "stm32l4x3",
+    stm32_mcu = "stm32l4x5",
+    stm32_mcu = "stm32l4x6",
+    stm32_mcu = "stm32l4r5",
+    stm32_mcu = "stm32l4r7",
+    stm32_mcu = "stm32l4r9",
+    stm32_mcu = "stm32l4s5",
+    stm32_mcu = "stm32l4s7",
+    stm32_mcu = "stm32l4s9"
+))]
+map_sai_block! {
+    "Extracts SAI1 block A register tokens.",
+    periph_sai1_a,
+    "SAI1 block A peripheral variant.",
+    Sai1A,
+    Sai1,
+    SAI1,
+    ACR1,
+    ACR2,
+    AFRCR,
+    ASLOTR,
+    AIM,
+    ASR,
+    ACLRFR,
+    ADR,
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32f413",
+    stm32_mcu = "stm32f427",
+    stm32_mcu = "stm32f429",
+    stm32_mcu = "stm32f446",
+    stm32_mcu = "stm32f469",
+    stm32_mcu = "stm32l4x1",
+    stm32_mcu = "stm32l4x2",
+    stm32_mcu = "stm32l4x3",
+    stm32_mcu = "stm32l4x5",
+    stm32_mcu = "stm32l4x6",
+    stm32_mcu = "stm32l4r5",
+    stm32_mcu = "stm32l4r7",
+    stm32_mcu = "stm32l4r9",
+    stm32_mcu = "stm32l4s5",
+    stm32_mcu = "stm32l4s7",
+    stm32_mcu = "stm32l4s9"
+))]
+map_sai_block! {
+    "Extracts SAI1 block B register tokens.",
+    periph_sai1_b,
+    "SAI1 block B peripheral variant.",
+    Sai1B,
+    Sai1,
+    SAI1,
+    BCR1,
+    BCR2,
+    BFRCR,
+    BSLOTR,
+    BIM,
+    BSR,
+    BCLRFR,
+    BDR,
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32f446",
+    stm32_mcu = "stm32l4x5",
+    stm32_mcu = "stm32l4x6",
+    stm32_mcu = "stm32l4r5",
+    stm32_mcu = "stm32l4r7",
+    stm32_mcu = "stm32l4r9",
+    stm32_mcu = "stm32l4s5",
+    stm32_mcu = "stm32l4s7",
+    stm32_mcu = "stm32l4s9"
+))]
+map_sai_block! {
+    "Extracts SAI2 block A register tokens.",
+    periph_sai2_a,
+    "SAI2 block A peripheral variant.",
+    Sai2A,
+    Sai2,
+    SAI2,
+    ACR1,
+    ACR2,
+    AFRCR,
+    ASLOTR,
+    AIM,
+    ASR,
+    ACLRFR,
+    ADR,
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32f446",
+    stm32_mcu = "stm32l4x5",
+    stm32_mcu = "stm32l4x6",
+    stm32_mcu = "stm32l4r5",
+    stm32_mcu = "stm32l4r7",
+    stm32_mcu = "stm32l4r9",
+    stm32_mcu = "stm32l4s5",
+    stm32_mcu = "stm32l4s7",
+    stm32_mcu = "stm32l4s9"
+))]
+map_sai_block! {
+    "Extracts SAI2 block B register tokens.",
+    periph_sai2_b,
+    "SAI2 block B peripheral variant.",
+    Sai2B,
+    Sai2,
+    SAI2,
+    BCR1,
+    BCR2,
+    BFRCR,
+    BSLOTR,
+    BIM,
+    BSR,
+    BCLRFR,
+    BDR,
+}
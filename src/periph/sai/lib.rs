@@ -0,0 +1,312 @@
+//! Serial audio interface.
+//!
+//! The peripheral is split into the head, which holds the RCC bits, the
+//! kernel clock selection and the STM32L4+ PDM interface registers, and the
+//! two audio sub-blocks in [`block`]. The PDM interface is present on SAI1
+//! only, so `PDMCR` and `PDMDLY` are optional registers of [`SaiMap`].
+//!
+//! On STM32L4 the sub-blocks are served by the DMA channels below, all with
+//! request number [`SAI_DMA_REQUEST`] in the `CSELR` register:
+//!
+//! | Sub-block | DMA channels                     |
+//! |-----------|----------------------------------|
+//! | SAI1 A    | DMA2 channel 1, DMA2 channel 6   |
+//! | SAI1 B    | DMA2 channel 2, DMA2 channel 7   |
+//! | SAI2 A    | DMA1 channel 6                   |
+//! | SAI2 B    | DMA1 channel 7                   |
+//!
+//! On STM32L4+ the requests are routed through DMAMUX1 instead.
+//!
+//! On STM32F4 the kernel clock is selected in `DCKCFGR`, so the head carries
+//! the RCC enable, reset and low-power bits only.
+
+#![feature(proc_macro_hygiene)]
+#![warn(missing_docs)]
+#![warn(clippy::pedantic)]
+#![allow(clippy::type_repetition_in_bounds, clippy::wildcard_imports)]
+#![no_std]
+
+pub mod block;
+
+use drone_core::periph;
+use drone_cortexm::reg::marker::*;
+
+/// DMA request number of the SAI sub-blocks.
+#[cfg(any(
+    stm32_mcu = "stm32l4x1",
+    stm32_mcu = "stm32l4x2",
+    stm32_mcu = "stm32l4x3",
+    stm32_mcu = "stm32l4x5",
+    stm32_mcu = "stm32l4x6"
+))]
+pub const SAI_DMA_REQUEST: u32 = 1;
+
+periph! {
+    /// Generic SAI head peripheral variant.
+    pub trait SaiMap {}
+
+    /// Generic SAI head peripheral.
+    pub struct SaiPeriph;
+
+    RCC {
+        APB2ENR {
+            0x20 RwRegBitBand Shared;
+            SAIEN { RwRwRegFieldBitBand }
+        }
+        APB2RSTR {
+            0x20 RwRegBitBand Shared;
+            SAIRST { RwRwRegFieldBitBand }
+        }
+        APB2SMENR {
+            0x20 RwRegBitBand Shared;
+            SAISMEN { RwRwRegFieldBitBand }
+        }
+        #[cfg(any(
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9"
+        ))]
+        CCIPR {
+            0x20 RwRegBitBand Shared;
+            SAISEL { RwRwRegFieldBits }
+        }
+    }
+    SAI {
+        PDMCR {
+            0x20 RwRegBitBand Option;
+            CKEN1 { RwRwRegFieldBitBand }
+            CKEN2 { RwRwRegFieldBitBand }
+            CKEN3 { RwRwRegFieldBitBand }
+            CKEN4 { RwRwRegFieldBitBand }
+            MICNBR { RwRwRegFieldBits }
+            PDMEN { RwRwRegFieldBitBand }
+        }
+        PDMDLY {
+            0x20 RwRegBitBand Option;
+            DLYM1L { RwRwRegFieldBits }
+            DLYM1R { RwRwRegFieldBits }
+            DLYM2L { RwRwRegFieldBits }
+            DLYM2R { RwRwRegFieldBits }
+            DLYM3L { RwRwRegFieldBits }
+            DLYM3R { RwRwRegFieldBits }
+            DLYM4L { RwRwRegFieldBits }
+            DLYM4R { RwRwRegFieldBits }
+        }
+    }
+}
+
+#[allow(unused_macros)]
+macro_rules! map_sai {
+    (
+        $sai_macro_doc:expr,
+        $sai_macro:ident,
+        $sai_ty_doc:expr,
+        $sai_ty:ident,
+        $apb2smenr:ident,
+        ($($ccipr:ident)?),
+        $saien:ident,
+        $sairst:ident,
+        $saismen:ident,
+        ($($saisel:ident)?),
+        $sai:ident,
+        ($($pdmcr:ident)?),
+        ($($pdmdly:ident)?),
+    ) => {
+        periph::map! {
+            #[doc = $sai_macro_doc]
+            pub macro $sai_macro;
+
+            #[doc = $sai_ty_doc]
+            pub struct $sai_ty;
+
+            impl SaiMap for $sai_ty {}
+
+            drone_stm32_map_pieces::reg;
+            crate;
+
+            RCC {
+                APB2ENR {
+                    APB2ENR Shared;
+                    SAIEN { $saien }
+                }
+                APB2RSTR {
+                    APB2RSTR Shared;
+                    SAIRST { $sairst }
+                }
+                APB2SMENR {
+                    $apb2smenr Shared;
+                    SAISMEN { $saismen }
+                }
+                $(
+                    CCIPR {
+                        $ccipr Shared;
+                        SAISEL { $saisel }
+                    }
+                )*
+            }
+            SAI {
+                $sai;
+                PDMCR {
+                    $(
+                        $pdmcr Option;
+                        CKEN1 { CKEN1 }
+                        CKEN2 { CKEN2 }
+                        CKEN3 { CKEN3 }
+                        CKEN4 { CKEN4 }
+                        MICNBR { MICNBR }
+                        PDMEN { PDMEN }
+                    )*
+                }
+                PDMDLY {
+                    $(
+                        $pdmdly Option;
+                        DLYM1L { DLYM1L }
+                        DLYM1R { DLYM1R }
+                        DLYM2L { DLYM2L }
+                        DLYM2R { DLYM2R }
+                        DLYM3L { DLYM3L }
+                        DLYM3R { DLYM3R }
+                        DLYM4L { DLYM4L }
+                        DLYM4R { DLYM4R }
+                    )*
+                }
+            }
+        }
+    };
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32f413",
+    stm32_mcu = "stm32f427",
+    stm32_mcu = "stm32f429",
+    stm32_mcu = "stm32f446",
+    stm32_mcu = "stm32f469"
+))]
+map_sai! {
+    "Extracts SAI1 head register tokens.",
+    periph_sai1,
+    "SAI1 head peripheral variant.",
+    Sai1,
+    APB2LPENR,
+    (),
+    SAI1EN,
+    SAI1RST,
+    SAI1LPEN,
+    (),
+    SAI1,
+    (),
+    (),
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32l4x1",
+    stm32_mcu = "stm32l4x2",
+    stm32_mcu = "stm32l4x3",
+    stm32_mcu = "stm32l4x5",
+    stm32_mcu = "stm32l4x6"
+))]
+map_sai! {
+    "Extracts SAI1 head register tokens.",
+    periph_sai1,
+    "SAI1 head peripheral variant.",
+    Sai1,
+    APB2SMENR,
+    (CCIPR),
+    SAI1EN,
+    SAI1RST,
+    SAI1SMEN,
+    (SAI1SEL),
+    SAI1,
+    (),
+    (),
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32l4r5",
+    stm32_mcu = "stm32l4r7",
+    stm32_mcu = "stm32l4r9",
+    stm32_mcu = "stm32l4s5",
+    stm32_mcu = "stm32l4s7",
+    stm32_mcu = "stm32l4s9"
+))]
+map_sai! {
+    "Extracts SAI1 head register tokens.",
+    periph_sai1,
+    "SAI1 head peripheral variant.",
+    Sai1,
+    APB2SMENR,
+    (CCIPR2),
+    SAI1EN,
+    SAI1RST,
+    SAI1SMEN,
+    (SAI1SEL),
+    SAI1,
+    (PDMCR),
+    (PDMDLY),
+}
+
+#[cfg(any(stm32_mcu = "stm32f446"))]
+map_sai! {
+    "Extracts SAI2 head register tokens.",
+    periph_sai2,
+    "SAI2 head peripheral variant.",
+    Sai2,
+    APB2LPENR,
+    (),
+    SAI2EN,
+    SAI2RST,
+    SAI2LPEN,
+    (),
+    SAI2,
+    (),
+    (),
+}
+
+#[cfg(any(stm32_mcu = "stm32l4x5", stm32_mcu = "stm32l4x6"))]
+map_sai! {
+    "Extracts SAI2 head register tokens.",
+    periph_sai2,
+    "SAI2 head peripheral variant.",
+    Sai2,
+    APB2SMENR,
+    (CCIPR),
+    SAI2EN,
+    SAI2RST,
+    SAI2SMEN,
+    (SAI2SEL),
+    SAI2,
+    (),
+    (),
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32l4r5",
+    stm32_mcu = "stm32l4r7",
+    stm32_mcu = "stm32l4r9",
+    stm32_mcu = "stm32l4s5",
+    stm32_mcu = "stm32l4s7",
+    stm32_mcu = "stm32l4s9"
+))]
+map_sai! {
+    "Extracts SAI2 head register tokens.",
+    periph_sai2,
+    "SAI2 head peripheral variant.",
+    Sai2,
+    APB2SMENR,
+    (CCIPR2),
+    SAI2EN,
+    SAI2RST,
+    SAI2SMEN,
+    (SAI2SEL),
+    SAI2,
+    (),
+    (),
+}
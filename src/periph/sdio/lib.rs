@@ -0,0 +1,110 @@
+//! Secure digital input/output interface.
+//!
+//! Only high-density STM32F103 devices carry the SDIO block, but the
+//! `stm32f103` configuration doesn't distinguish densities. STM32F446 is
+//! described as SDMMC in its SVD and is not covered here.
+
+#![feature(proc_macro_hygiene)]
+#![warn(missing_docs)]
+#![warn(clippy::pedantic)]
+#![allow(clippy::type_repetition_in_bounds, clippy::wildcard_imports)]
+#![no_std]
+
+#[allow(unused_imports)]
+use drone_core::periph;
+
+#[cfg(any(
+    stm32_mcu = "stm32f103",
+    stm32_mcu = "stm32f401",
+    stm32_mcu = "stm32f405",
+    stm32_mcu = "stm32f407",
+    stm32_mcu = "stm32f411",
+    stm32_mcu = "stm32f412",
+    stm32_mcu = "stm32f413",
+    stm32_mcu = "stm32f427",
+    stm32_mcu = "stm32f429",
+    stm32_mcu = "stm32f446",
+    stm32_mcu = "stm32f469"
+))]
+periph::singular! {
+    /// Extracts SDIO register tokens.
+    pub macro periph_sdio;
+
+    /// SDIO peripheral.
+    pub struct SdioPeriph;
+
+    drone_stm32_map_pieces::reg;
+    crate;
+
+    RCC {
+        #[cfg(any(stm32_mcu = "stm32f103"))]
+        AHBENR {
+            SDIOEN;
+        }
+        #[cfg(any(
+            stm32_mcu = "stm32f401",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f411",
+            stm32_mcu = "stm32f412",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469"
+        ))]
+        APB2ENR {
+            SDIOEN;
+        }
+        #[cfg(any(
+            stm32_mcu = "stm32f401",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f411",
+            stm32_mcu = "stm32f412",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469"
+        ))]
+        APB2RSTR {
+            SDIORST;
+        }
+        #[cfg(any(
+            stm32_mcu = "stm32f401",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f411",
+            stm32_mcu = "stm32f412",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469"
+        ))]
+        APB2LPENR {
+            SDIOLPEN;
+        }
+    }
+    SDIO {
+        POWER;
+        CLKCR;
+        ARG;
+        CMD;
+        RESPCMD;
+        RESP1;
+        RESP2;
+        RESP3;
+        RESP4;
+        DTIMER;
+        DLEN;
+        DCTRL;
+        DCOUNT;
+        STA;
+        ICR;
+        MASK;
+        FIFOCNT;
+        FIFO;
+    }
+}
@@ -0,0 +1,125 @@
+//! Secure digital input/output MultiMediaCard interface.
+//!
+//! SDMMC1 is kept apart from the SDIO crate because its clock control bits
+//! live in different RCC registers: APB2 on STM32L4 and AHB2 on STM32L4+. The
+//! SVD files describe neither a second SDMMC instance nor the DLYB delay block,
+//! so those are not mapped.
+
+#![feature(proc_macro_hygiene)]
+#![warn(missing_docs)]
+#![warn(clippy::pedantic)]
+#![allow(clippy::type_repetition_in_bounds, clippy::wildcard_imports)]
+#![no_std]
+
+#[allow(unused_imports)]
+use drone_core::periph;
+
+#[cfg(any(
+    stm32_mcu = "stm32l4x1",
+    stm32_mcu = "stm32l4x2",
+    stm32_mcu = "stm32l4x3",
+    stm32_mcu = "stm32l4x5",
+    stm32_mcu = "stm32l4x6",
+    stm32_mcu = "stm32l4r5",
+    stm32_mcu = "stm32l4r7",
+    stm32_mcu = "stm32l4r9",
+    stm32_mcu = "stm32l4s5",
+    stm32_mcu = "stm32l4s7",
+    stm32_mcu = "stm32l4s9"
+))]
+periph::singular! {
+    /// Extracts SDMMC1 register tokens.
+    pub macro periph_sdmmc1;
+
+    /// SDMMC1 peripheral.
+    pub struct Sdmmc1Periph;
+
+    drone_stm32_map_pieces::reg;
+    crate;
+
+    RCC {
+        #[cfg(any(
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6"
+        ))]
+        APB2ENR {
+            SDMMC1EN;
+        }
+        #[cfg(any(
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6"
+        ))]
+        APB2RSTR {
+            SDMMC1RST;
+        }
+        #[cfg(any(
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6"
+        ))]
+        APB2SMENR {
+            SDMMC1SMEN;
+        }
+        #[cfg(any(
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9"
+        ))]
+        AHB2ENR {
+            SDMMC1EN;
+        }
+        #[cfg(any(
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9"
+        ))]
+        AHB2RSTR {
+            SDMMC1RST;
+        }
+        #[cfg(any(
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9"
+        ))]
+        AHB2SMENR {
+            SDMMC1SMEN;
+        }
+    }
+    SDMMC1 {
+        POWER;
+        CLKCR;
+        ARG;
+        CMD;
+        RESPCMD;
+        RESP1;
+        RESP2;
+        RESP3;
+        RESP4;
+        DTIMER;
+        DLEN;
+        DCTRL;
+        DCOUNT;
+        STA;
+        ICR;
+        MASK;
+        FIFOCNT;
+        FIFO;
+    }
+}
@@ -0,0 +1,61 @@
+//! SPDIF receiver interface.
+//!
+//! The received audio samples and the channel status/user information are
+//! read through separate DMA requests, both on channel [`SPDIFRX_DMA_CHANNEL`]
+//! of DMA1:
+//!
+//! | Request      | DMA stream      |
+//! |--------------|-----------------|
+//! | `SPDIFRX_DT` | DMA1 stream 1   |
+//! | `SPDIFRX_CS` | DMA1 stream 6   |
+//!
+//! The kernel clock is selected by `RCC_DCKCFGR2.SPDIFSEL`.
+
+#![feature(proc_macro_hygiene)]
+#![warn(missing_docs)]
+#![warn(clippy::pedantic)]
+#![allow(clippy::type_repetition_in_bounds, clippy::wildcard_imports)]
+#![no_std]
+
+#[allow(unused_imports)]
+use drone_core::periph;
+
+/// DMA1 channel number of the SPDIFRX data and control requests.
+#[cfg(any(stm32_mcu = "stm32f446"))]
+pub const SPDIFRX_DMA_CHANNEL: u32 = 0;
+
+#[cfg(any(stm32_mcu = "stm32f446"))]
+periph::singular! {
+    /// Extracts SPDIFRX register tokens.
+    pub macro periph_spdifrx;
+
+    /// SPDIFRX peripheral.
+    pub struct SpdifrxPeriph;
+
+    drone_stm32_map_pieces::reg;
+    crate;
+
+    RCC {
+        APB1ENR {
+            SPDIFEN;
+        }
+        APB1LPENR {
+            SPDIFLPEN;
+        }
+        APB1RSTR {
+            SPDIFRST;
+        }
+        DCKCFGR2 {
+            SPDIFSEL;
+        }
+    }
+    SPDIFRX {
+        CR;
+        IMR;
+        SR;
+        IFCR;
+        DR;
+        CSR;
+        DIR;
+    }
+}
@@ -0,0 +1,177 @@
+//! I2S extension blocks.
+//!
+//! `I2S2ext` and `I2S3ext` are receive/transmit companions of SPI2 and SPI3
+//! used for full-duplex I2S. An extension block has no clock enable, reset
+//! or clock generator of its own: it runs in slave mode off the bit clock of
+//! its parent instance, which is named by the [`I2sExtOf`] marker trait.
+
+use crate::{Spi2, Spi3, SpiMap};
+use drone_core::periph;
+use drone_cortexm::reg::marker::*;
+
+periph! {
+    /// Generic I2S extension peripheral variant.
+    pub trait I2sExtMap {}
+
+    /// Generic I2S extension peripheral.
+    pub struct I2sExtPeriph;
+
+    I2S_EXT {
+        CR2 {
+            0x20 RwRegBitBand;
+            ERRIE { RwRwRegFieldBitBand }
+            FRF { RwRwRegFieldBitBand }
+            RXDMAEN { RwRwRegFieldBitBand }
+            RXNEIE { RwRwRegFieldBitBand }
+            SSOE { RwRwRegFieldBitBand }
+            TXDMAEN { RwRwRegFieldBitBand }
+            TXEIE { RwRwRegFieldBitBand }
+        }
+        SR {
+            0x20 RwRegBitBand;
+            BSY { RoRwRegFieldBitBand }
+            CHSIDE { RoRwRegFieldBitBand }
+            CRCERR { RwRwRegFieldBitBand }
+            MODF { RoRwRegFieldBitBand }
+            OVR { RoRwRegFieldBitBand }
+            RXNE { RoRwRegFieldBitBand }
+            TIFRFE { RoRwRegFieldBitBand }
+            TXE { RoRwRegFieldBitBand }
+            UDR { RoRwRegFieldBitBand }
+        }
+        DR {
+            0x20 RwRegBitBand;
+            DR { RwRwRegFieldBits }
+        }
+        I2SCFGR {
+            0x20 RwRegBitBand;
+            CHLEN { RwRwRegFieldBitBand }
+            CKPOL { RwRwRegFieldBitBand }
+            DATLEN { RwRwRegFieldBits }
+            I2SCFG { RwRwRegFieldBits }
+            I2SE { RwRwRegFieldBitBand }
+            I2SMOD { RwRwRegFieldBitBand }
+            I2SSTD { RwRwRegFieldBits }
+            PCMSYNC { RwRwRegFieldBitBand }
+        }
+        I2SPR {
+            0x20 RwRegBitBand;
+            I2SDIV { RwRwRegFieldBits }
+            MCKOE { RwRwRegFieldBitBand }
+            ODD { RwRwRegFieldBitBand }
+        }
+    }
+}
+
+#[allow(unused_macros)]
+macro_rules! map_i2s_ext {
+    (
+        $i2s_ext_macro_doc:expr,
+        $i2s_ext_macro:ident,
+        $i2s_ext_ty_doc:expr,
+        $i2s_ext_ty:ident,
+        $i2s_ext:ident,
+    ) => {
+        periph::map! {
+            #[doc = $i2s_ext_macro_doc]
+            pub macro $i2s_ext_macro;
+
+            #[doc = $i2s_ext_ty_doc]
+            pub struct $i2s_ext_ty;
+
+            impl I2sExtMap for $i2s_ext_ty {}
+
+            drone_stm32_map_pieces::reg;
+            crate::i2s_ext;
+
+            I2S_EXT {
+                $i2s_ext;
+                CR2 {
+                    CR2;
+                    ERRIE { ERRIE }
+                    FRF { FRF }
+                    RXDMAEN { RXDMAEN }
+                    RXNEIE { RXNEIE }
+                    SSOE { SSOE }
+                    TXDMAEN { TXDMAEN }
+                    TXEIE { TXEIE }
+                }
+                SR {
+                    SR;
+                    BSY { BSY }
+                    CHSIDE { CHSIDE }
+                    CRCERR { CRCERR }
+                    MODF { MODF }
+                    OVR { OVR }
+                    RXNE { RXNE }
+                    TIFRFE { TIFRFE }
+                    TXE { TXE }
+                    UDR { UDR }
+                }
+                DR {
+                    DR;
+                    DR { DR }
+                }
+                I2SCFGR {
+                    I2SCFGR;
+                    CHLEN { CHLEN }
+                    CKPOL { CKPOL }
+                    DATLEN { DATLEN }
+                    I2SCFG { I2SCFG }
+                    I2SE { I2SE }
+                    I2SMOD { I2SMOD }
+                    I2SSTD { I2SSTD }
+                    PCMSYNC { PCMSYNC }
+                }
+                I2SPR {
+                    I2SPR;
+                    I2SDIV { I2SDIV }
+                    MCKOE { MCKOE }
+                    ODD { ODD }
+                }
+            }
+        }
+    };
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32f401",
+    stm32_mcu = "stm32f405",
+    stm32_mcu = "stm32f407",
+    stm32_mcu = "stm32f411",
+    stm32_mcu = "stm32f412",
+    stm32_mcu = "stm32f427",
+    stm32_mcu = "stm32f429",
+    stm32_mcu = "stm32f469"
+))]
+map_i2s_ext! {
+    "Extracts I2S2ext register tokens.",
+    periph_i2s2ext,
+    "I2S2ext peripheral variant.",
+    I2s2Ext,
+    I2S2ext,
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32f401",
+    stm32_mcu = "stm32f405",
+    stm32_mcu = "stm32f407",
+    stm32_mcu = "stm32f411",
+    stm32_mcu = "stm32f412",
+    stm32_mcu = "stm32f427",
+    stm32_mcu = "stm32f429",
+    stm32_mcu = "stm32f469"
+))]
+map_i2s_ext! {
+    "Extracts I2S3ext register tokens.",
+    periph_i2s3ext,
+    "I2S3ext peripheral variant.",
+    I2s3Ext,
+    I2S3ext,
+}
+
+/// I2S extension peripheral variant paired with the SPI/I2S instance `T`.
+pub trait I2sExtOf<T: SpiMap>: I2sExtMap {}
+
+impl I2sExtOf<Spi2> for I2s2Ext {}
+impl I2sExtOf<Spi3> for I2s3Ext {}
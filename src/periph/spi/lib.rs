@@ -1,4 +1,8 @@
 //! Serial Peripheral Interface.
+//!
+//! On STM32F1 and STM32F4 the instances that double as I2S interfaces carry
+//! the optional `I2SCFGR` and `I2SPR` registers. STM32L4 has no I2S on its
+//! SPI instances and uses the SAI for audio instead.
 
 #![feature(proc_macro_hygiene)]
 #![warn(missing_docs)]
@@ -6,6 +10,18 @@
 #![allow(clippy::type_repetition_in_bounds, clippy::wildcard_imports)]
 #![no_std]
 
+#[cfg(any(
+    stm32_mcu = "stm32f401",
+    stm32_mcu = "stm32f405",
+    stm32_mcu = "stm32f407",
+    stm32_mcu = "stm32f411",
+    stm32_mcu = "stm32f412",
+    stm32_mcu = "stm32f427",
+    stm32_mcu = "stm32f429",
+    stm32_mcu = "stm32f469"
+))]
+pub mod i2s_ext;
+
 use drone_core::periph;
 use drone_cortexm::reg::marker::*;
 
@@ -26,6 +42,17 @@ periph! {
             SPIRST { RwRwRegFieldBitBand }
         }
         #[cfg(any(
+            stm32_mcu = "stm32f401",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f410",
+            stm32_mcu = "stm32f411",
+            stm32_mcu = "stm32f412",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469",
             stm32_mcu = "stm32l4x1",
             stm32_mcu = "stm32l4x2",
             stm32_mcu = "stm32l4x3",
@@ -53,6 +80,38 @@ periph! {
             CPOL { RwRwRegFieldBitBand }
             CRCEN { RwRwRegFieldBitBand }
             CRCNEXT { RwRwRegFieldBitBand }
+            #[cfg(any(
+                stm32_mcu = "stm32l4x1",
+                stm32_mcu = "stm32l4x2",
+                stm32_mcu = "stm32l4x3",
+                stm32_mcu = "stm32l4x5",
+                stm32_mcu = "stm32l4x6",
+                stm32_mcu = "stm32l4r5",
+                stm32_mcu = "stm32l4r7",
+                stm32_mcu = "stm32l4r9",
+                stm32_mcu = "stm32l4s5",
+                stm32_mcu = "stm32l4s7",
+                stm32_mcu = "stm32l4s9"
+            ))]
+            CRCL { RwRwRegFieldBitBand }
+            #[cfg(any(
+                stm32_mcu = "stm32f100",
+                stm32_mcu = "stm32f101",
+                stm32_mcu = "stm32f102",
+                stm32_mcu = "stm32f103",
+                stm32_mcu = "stm32f107",
+                stm32_mcu = "stm32f401",
+                stm32_mcu = "stm32f405",
+                stm32_mcu = "stm32f407",
+                stm32_mcu = "stm32f410",
+                stm32_mcu = "stm32f411",
+                stm32_mcu = "stm32f412",
+                stm32_mcu = "stm32f413",
+                stm32_mcu = "stm32f427",
+                stm32_mcu = "stm32f429",
+                stm32_mcu = "stm32f446",
+                stm32_mcu = "stm32f469"
+            ))]
             DFF { RwRwRegFieldBitBand }
             LSBFIRST { RwRwRegFieldBitBand }
             MSTR { RwRwRegFieldBitBand }
@@ -79,6 +138,17 @@ periph! {
             DS { RwRwRegFieldBits }
             ERRIE { RwRwRegFieldBitBand }
             #[cfg(any(
+                stm32_mcu = "stm32f401",
+                stm32_mcu = "stm32f405",
+                stm32_mcu = "stm32f407",
+                stm32_mcu = "stm32f410",
+                stm32_mcu = "stm32f411",
+                stm32_mcu = "stm32f412",
+                stm32_mcu = "stm32f413",
+                stm32_mcu = "stm32f427",
+                stm32_mcu = "stm32f429",
+                stm32_mcu = "stm32f446",
+                stm32_mcu = "stm32f469",
                 stm32_mcu = "stm32l4x1",
                 stm32_mcu = "stm32l4x2",
                 stm32_mcu = "stm32l4x3",
@@ -162,6 +232,17 @@ periph! {
                 stm32_mcu = "stm32f102",
                 stm32_mcu = "stm32f103",
                 stm32_mcu = "stm32f107",
+                stm32_mcu = "stm32f401",
+                stm32_mcu = "stm32f405",
+                stm32_mcu = "stm32f407",
+                stm32_mcu = "stm32f410",
+                stm32_mcu = "stm32f411",
+                stm32_mcu = "stm32f412",
+                stm32_mcu = "stm32f413",
+                stm32_mcu = "stm32f427",
+                stm32_mcu = "stm32f429",
+                stm32_mcu = "stm32f446",
+                stm32_mcu = "stm32f469"
             ))]
             CHSIDE { RoRwRegFieldBitBand }
             CRCERR { RwRwRegFieldBitBand }
@@ -197,6 +278,17 @@ periph! {
             OVR { RoRwRegFieldBitBand }
             RXNE { RoRwRegFieldBitBand }
             #[cfg(any(
+                stm32_mcu = "stm32f401",
+                stm32_mcu = "stm32f405",
+                stm32_mcu = "stm32f407",
+                stm32_mcu = "stm32f410",
+                stm32_mcu = "stm32f411",
+                stm32_mcu = "stm32f412",
+                stm32_mcu = "stm32f413",
+                stm32_mcu = "stm32f427",
+                stm32_mcu = "stm32f429",
+                stm32_mcu = "stm32f446",
+                stm32_mcu = "stm32f469",
                 stm32_mcu = "stm32l4x1",
                 stm32_mcu = "stm32l4x2",
                 stm32_mcu = "stm32l4x3",
@@ -216,6 +308,17 @@ periph! {
                 stm32_mcu = "stm32f102",
                 stm32_mcu = "stm32f103",
                 stm32_mcu = "stm32f107",
+                stm32_mcu = "stm32f401",
+                stm32_mcu = "stm32f405",
+                stm32_mcu = "stm32f407",
+                stm32_mcu = "stm32f410",
+                stm32_mcu = "stm32f411",
+                stm32_mcu = "stm32f412",
+                stm32_mcu = "stm32f413",
+                stm32_mcu = "stm32f427",
+                stm32_mcu = "stm32f429",
+                stm32_mcu = "stm32f446",
+                stm32_mcu = "stm32f469"
             ))]
             UDR { RoRwRegFieldBitBand }
         }
@@ -235,6 +338,59 @@ periph! {
             0x20 RoRegBitBand;
             TxCRC { RoRoRegFieldBits }
         }
+        #[cfg(any(
+            stm32_mcu = "stm32f100",
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f102",
+            stm32_mcu = "stm32f103",
+            stm32_mcu = "stm32f107",
+            stm32_mcu = "stm32f401",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f410",
+            stm32_mcu = "stm32f411",
+            stm32_mcu = "stm32f412",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469"
+        ))]
+        I2SCFGR {
+            0x20 RwRegBitBand Option;
+            CHLEN { RwRwRegFieldBitBand }
+            CKPOL { RwRwRegFieldBitBand }
+            DATLEN { RwRwRegFieldBits }
+            I2SCFG { RwRwRegFieldBits }
+            I2SE { RwRwRegFieldBitBand }
+            I2SMOD { RwRwRegFieldBitBand }
+            I2SSTD { RwRwRegFieldBits }
+            PCMSYNC { RwRwRegFieldBitBand }
+        }
+        #[cfg(any(
+            stm32_mcu = "stm32f100",
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f102",
+            stm32_mcu = "stm32f103",
+            stm32_mcu = "stm32f107",
+            stm32_mcu = "stm32f401",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f410",
+            stm32_mcu = "stm32f411",
+            stm32_mcu = "stm32f412",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469"
+        ))]
+        I2SPR {
+            0x20 RwRegBitBand Option;
+            I2SDIV { RwRwRegFieldBits }
+            MCKOE { RwRwRegFieldBitBand }
+            ODD { RwRwRegFieldBitBand }
+        }
     }
 }
 
@@ -252,6 +408,8 @@ macro_rules! map_spi {
         $spirst:ident,
         $spismen:ident,
         $spi:ident,
+        ($($i2scfgr:ident)?),
+        ($($i2spr:ident)?),
     ) => {
         periph::map! {
             #[doc = $spi_macro_doc]
@@ -275,6 +433,17 @@ macro_rules! map_spi {
                     SPIRST { $spirst }
                 }
                 #[cfg(any(
+                    stm32_mcu = "stm32f401",
+                    stm32_mcu = "stm32f405",
+                    stm32_mcu = "stm32f407",
+                    stm32_mcu = "stm32f410",
+                    stm32_mcu = "stm32f411",
+                    stm32_mcu = "stm32f412",
+                    stm32_mcu = "stm32f413",
+                    stm32_mcu = "stm32f427",
+                    stm32_mcu = "stm32f429",
+                    stm32_mcu = "stm32f446",
+                    stm32_mcu = "stm32f469",
                     stm32_mcu = "stm32l4x1",
                     stm32_mcu = "stm32l4x2",
                     stm32_mcu = "stm32l4x3",
@@ -303,6 +472,38 @@ macro_rules! map_spi {
                     CPOL { CPOL }
                     CRCEN { CRCEN }
                     CRCNEXT { CRCNEXT }
+                    #[cfg(any(
+                        stm32_mcu = "stm32l4x1",
+                        stm32_mcu = "stm32l4x2",
+                        stm32_mcu = "stm32l4x3",
+                        stm32_mcu = "stm32l4x5",
+                        stm32_mcu = "stm32l4x6",
+                        stm32_mcu = "stm32l4r5",
+                        stm32_mcu = "stm32l4r7",
+                        stm32_mcu = "stm32l4r9",
+                        stm32_mcu = "stm32l4s5",
+                        stm32_mcu = "stm32l4s7",
+                        stm32_mcu = "stm32l4s9"
+                    ))]
+                    CRCL { CRCL }
+                    #[cfg(any(
+                        stm32_mcu = "stm32f100",
+                        stm32_mcu = "stm32f101",
+                        stm32_mcu = "stm32f102",
+                        stm32_mcu = "stm32f103",
+                        stm32_mcu = "stm32f107",
+                        stm32_mcu = "stm32f401",
+                        stm32_mcu = "stm32f405",
+                        stm32_mcu = "stm32f407",
+                        stm32_mcu = "stm32f410",
+                        stm32_mcu = "stm32f411",
+                        stm32_mcu = "stm32f412",
+                        stm32_mcu = "stm32f413",
+                        stm32_mcu = "stm32f427",
+                        stm32_mcu = "stm32f429",
+                        stm32_mcu = "stm32f446",
+                        stm32_mcu = "stm32f469"
+                    ))]
                     DFF { DFF }
                     LSBFIRST { LSBFIRST }
                     MSTR { MSTR }
@@ -329,6 +530,17 @@ macro_rules! map_spi {
                     DS { DS }
                     ERRIE { ERRIE }
                     #[cfg(any(
+                        stm32_mcu = "stm32f401",
+                        stm32_mcu = "stm32f405",
+                        stm32_mcu = "stm32f407",
+                        stm32_mcu = "stm32f410",
+                        stm32_mcu = "stm32f411",
+                        stm32_mcu = "stm32f412",
+                        stm32_mcu = "stm32f413",
+                        stm32_mcu = "stm32f427",
+                        stm32_mcu = "stm32f429",
+                        stm32_mcu = "stm32f446",
+                        stm32_mcu = "stm32f469",
                         stm32_mcu = "stm32l4x1",
                         stm32_mcu = "stm32l4x2",
                         stm32_mcu = "stm32l4x3",
@@ -412,6 +624,17 @@ macro_rules! map_spi {
                         stm32_mcu = "stm32f102",
                         stm32_mcu = "stm32f103",
                         stm32_mcu = "stm32f107",
+                        stm32_mcu = "stm32f401",
+                        stm32_mcu = "stm32f405",
+                        stm32_mcu = "stm32f407",
+                        stm32_mcu = "stm32f410",
+                        stm32_mcu = "stm32f411",
+                        stm32_mcu = "stm32f412",
+                        stm32_mcu = "stm32f413",
+                        stm32_mcu = "stm32f427",
+                        stm32_mcu = "stm32f429",
+                        stm32_mcu = "stm32f446",
+                        stm32_mcu = "stm32f469"
                     ))]
                     CHSIDE { CHSIDE }
                     CRCERR { CRCERR }
@@ -447,6 +670,17 @@ macro_rules! map_spi {
                     OVR { OVR }
                     RXNE { RXNE }
                     #[cfg(any(
+                        stm32_mcu = "stm32f401",
+                        stm32_mcu = "stm32f405",
+                        stm32_mcu = "stm32f407",
+                        stm32_mcu = "stm32f410",
+                        stm32_mcu = "stm32f411",
+                        stm32_mcu = "stm32f412",
+                        stm32_mcu = "stm32f413",
+                        stm32_mcu = "stm32f427",
+                        stm32_mcu = "stm32f429",
+                        stm32_mcu = "stm32f446",
+                        stm32_mcu = "stm32f469",
                         stm32_mcu = "stm32l4x1",
                         stm32_mcu = "stm32l4x2",
                         stm32_mcu = "stm32l4x3",
@@ -466,6 +700,17 @@ macro_rules! map_spi {
                         stm32_mcu = "stm32f102",
                         stm32_mcu = "stm32f103",
                         stm32_mcu = "stm32f107",
+                        stm32_mcu = "stm32f401",
+                        stm32_mcu = "stm32f405",
+                        stm32_mcu = "stm32f407",
+                        stm32_mcu = "stm32f410",
+                        stm32_mcu = "stm32f411",
+                        stm32_mcu = "stm32f412",
+                        stm32_mcu = "stm32f413",
+                        stm32_mcu = "stm32f427",
+                        stm32_mcu = "stm32f429",
+                        stm32_mcu = "stm32f446",
+                        stm32_mcu = "stm32f469"
                     ))]
                     UDR { UDR }
                 }
@@ -485,6 +730,63 @@ macro_rules! map_spi {
                     TXCRCR;
                     TxCRC { TxCRC }
                 }
+                #[cfg(any(
+                    stm32_mcu = "stm32f100",
+                    stm32_mcu = "stm32f101",
+                    stm32_mcu = "stm32f102",
+                    stm32_mcu = "stm32f103",
+                    stm32_mcu = "stm32f107",
+                    stm32_mcu = "stm32f401",
+                    stm32_mcu = "stm32f405",
+                    stm32_mcu = "stm32f407",
+                    stm32_mcu = "stm32f410",
+                    stm32_mcu = "stm32f411",
+                    stm32_mcu = "stm32f412",
+                    stm32_mcu = "stm32f413",
+                    stm32_mcu = "stm32f427",
+                    stm32_mcu = "stm32f429",
+                    stm32_mcu = "stm32f446",
+                    stm32_mcu = "stm32f469"
+                ))]
+                I2SCFGR {
+                    $(
+                        $i2scfgr Option;
+                        CHLEN { CHLEN }
+                        CKPOL { CKPOL }
+                        DATLEN { DATLEN }
+                        I2SCFG { I2SCFG }
+                        I2SE { I2SE }
+                        I2SMOD { I2SMOD }
+                        I2SSTD { I2SSTD }
+                        PCMSYNC { PCMSYNC }
+                    )*
+                }
+                #[cfg(any(
+                    stm32_mcu = "stm32f100",
+                    stm32_mcu = "stm32f101",
+                    stm32_mcu = "stm32f102",
+                    stm32_mcu = "stm32f103",
+                    stm32_mcu = "stm32f107",
+                    stm32_mcu = "stm32f401",
+                    stm32_mcu = "stm32f405",
+                    stm32_mcu = "stm32f407",
+                    stm32_mcu = "stm32f410",
+                    stm32_mcu = "stm32f411",
+                    stm32_mcu = "stm32f412",
+                    stm32_mcu = "stm32f413",
+                    stm32_mcu = "stm32f427",
+                    stm32_mcu = "stm32f429",
+                    stm32_mcu = "stm32f446",
+                    stm32_mcu = "stm32f469"
+                ))]
+                I2SPR {
+                    $(
+                        $i2spr Option;
+                        I2SDIV { I2SDIV }
+                        MCKOE { MCKOE }
+                        ODD { ODD }
+                    )*
+                }
             }
         }
     };
@@ -520,15 +822,11 @@ map_spi! {
     SPI1RST,
     SPI1SMEN,
     SPI1,
+    (),
+    (),
 }
 
-#[cfg(any(
-    stm32_mcu = "stm32f100",
-    stm32_mcu = "stm32f101",
-    stm32_mcu = "stm32f102",
-    stm32_mcu = "stm32f103",
-    stm32_mcu = "stm32f107",
-))]
+#[cfg(any(stm32_mcu = "stm32f100", stm32_mcu = "stm32f101", stm32_mcu = "stm32f102"))]
 map_spi! {
     "Extracts SPI2 register tokens.",
     periph_spi2,
@@ -541,14 +839,45 @@ map_spi! {
     SPI2RST,
     SPI2SMEN,
     SPI2,
+    (),
+    (),
 }
 
-#[cfg(any(
-    stm32_mcu = "stm32f100",
-    stm32_mcu = "stm32f101",
-    stm32_mcu = "stm32f103",
-    stm32_mcu = "stm32f107",
-))]
+#[cfg(any(stm32_mcu = "stm32f103", stm32_mcu = "stm32f107"))]
+map_spi! {
+    "Extracts SPI2 register tokens.",
+    periph_spi2,
+    "SPI2 peripheral variant.",
+    Spi2,
+    APB1ENR,
+    APB1RSTR,
+    APB1SMENR,
+    SPI2EN,
+    SPI2RST,
+    SPI2SMEN,
+    SPI2,
+    (I2SCFGR),
+    (I2SPR),
+}
+
+#[cfg(any(stm32_mcu = "stm32f100", stm32_mcu = "stm32f101"))]
+map_spi! {
+    "Extracts SPI3 register tokens.",
+    periph_spi3,
+    "SPI3 peripheral variant.",
+    Spi3,
+    APB1ENR,
+    APB1RSTR,
+    APB1SMENR,
+    SPI3EN,
+    SPI3RST,
+    SPI3SMEN,
+    SPI3,
+    (),
+    (),
+}
+
+#[cfg(any(stm32_mcu = "stm32f103", stm32_mcu = "stm32f107"))]
 map_spi! {
     "Extracts SPI3 register tokens.",
     periph_spi3,
@@ -561,6 +890,8 @@ map_spi! {
     SPI3RST,
     SPI3SMEN,
     SPI3,
+    (I2SCFGR),
+    (I2SPR),
 }
 
 #[cfg(any(
@@ -588,6 +919,8 @@ map_spi! {
     SPI2RST,
     SPI2SMEN,
     SPI2,
+    (),
+    (),
 }
 
 #[cfg(any(
@@ -615,4 +948,127 @@ map_spi! {
     SPI3RST,
     SPI3SMEN,
     SPI3,
+    (),
+    (),
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32f401",
+    stm32_mcu = "stm32f405",
+    stm32_mcu = "stm32f407",
+    stm32_mcu = "stm32f427",
+    stm32_mcu = "stm32f429",
+    stm32_mcu = "stm32f469"
+))]
+map_spi! {
+    "Extracts SPI1 register tokens.",
+    periph_spi1,
+    "SPI1 peripheral variant.",
+    Spi1,
+    APB2ENR,
+    APB2RSTR,
+    APB2LPENR,
+    SPI1EN,
+    SPI1RST,
+    SPI1LPEN,
+    SPI1,
+    (),
+    (),
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32f410",
+    stm32_mcu = "stm32f411",
+    stm32_mcu = "stm32f412",
+    stm32_mcu = "stm32f413",
+    stm32_mcu = "stm32f446"
+))]
+map_spi! {
+    "Extracts SPI1 register tokens.",
+    periph_spi1,
+    "SPI1 peripheral variant.",
+    Spi1,
+    APB2ENR,
+    APB2RSTR,
+    APB2LPENR,
+    SPI1EN,
+    SPI1RST,
+    SPI1LPEN,
+    SPI1,
+    (I2SCFGR),
+    (I2SPR),
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32f401",
+    stm32_mcu = "stm32f405",
+    stm32_mcu = "stm32f407",
+    stm32_mcu = "stm32f410",
+    stm32_mcu = "stm32f411",
+    stm32_mcu = "stm32f412",
+    stm32_mcu = "stm32f413",
+    stm32_mcu = "stm32f427",
+    stm32_mcu = "stm32f429",
+    stm32_mcu = "stm32f446",
+    stm32_mcu = "stm32f469"
+))]
+map_spi! {
+    "Extracts SPI2 register tokens.",
+    periph_spi2,
+    "SPI2 peripheral variant.",
+    Spi2,
+    APB1ENR,
+    APB1RSTR,
+    APB1LPENR,
+    SPI2EN,
+    SPI2RST,
+    SPI2LPEN,
+    SPI2,
+    (I2SCFGR),
+    (I2SPR),
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32f401",
+    stm32_mcu = "stm32f405",
+    stm32_mcu = "stm32f407",
+    stm32_mcu = "stm32f411",
+    stm32_mcu = "stm32f412",
+    stm32_mcu = "stm32f413",
+    stm32_mcu = "stm32f427",
+    stm32_mcu = "stm32f429",
+    stm32_mcu = "stm32f446",
+    stm32_mcu = "stm32f469"
+))]
+map_spi! {
+    "Extracts SPI3 register tokens.",
+    periph_spi3,
+    "SPI3 peripheral variant.",
+    Spi3,
+    APB1ENR,
+    APB1RSTR,
+    APB1LPENR,
+    SPI3EN,
+    SPI3RST,
+    SPI3LPEN,
+    SPI3,
+    (I2SCFGR),
+    (I2SPR),
+}
+
+/// Extracts SPI register tokens by instance number.
+///
+/// `periph_spi!(2, reg)` is equivalent to `periph_spi2!(reg)`. An instance
+/// missing on the selected MCU fails to resolve at the call site.
+#[macro_export]
+macro_rules! periph_spi {
+    (1, $reg:ident) => {
+        $crate::periph_spi1!($reg)
+    };
+    (2, $reg:ident) => {
+        $crate::periph_spi2!($reg)
+    };
+    (3, $reg:ident) => {
+        $crate::periph_spi3!($reg)
+    };
 }
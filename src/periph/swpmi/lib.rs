@@ -0,0 +1,61 @@
+//! Single wire protocol master interface.
+//!
+//! The `SWPMI1` interrupt is associated with this peripheral in
+//! `thr::INTERRUPT_PERIPHS`.
+
+#![feature(proc_macro_hygiene)]
+#![warn(missing_docs)]
+#![warn(clippy::pedantic)]
+#![allow(clippy::type_repetition_in_bounds, clippy::wildcard_imports)]
+#![no_std]
+
+#[allow(unused_imports)]
+use drone_core::periph;
+
+#[cfg(any(
+    stm32_mcu = "stm32l4x1",
+    stm32_mcu = "stm32l4x2",
+    stm32_mcu = "stm32l4x3",
+    stm32_mcu = "stm32l4x5",
+    stm32_mcu = "stm32l4x6"
+))]
+periph::singular! {
+    /// Extracts SWPMI1 register tokens.
+    pub macro periph_swpmi1;
+
+    /// SWPMI1 peripheral.
+    pub struct Swpmi1Periph;
+
+    drone_stm32_map_pieces::reg;
+    crate;
+
+    RCC {
+        APB1ENR2 {
+            SWPMI1EN;
+        }
+        APB1RSTR2 {
+            SWPMI1RST;
+        }
+        APB1SMENR2 {
+            SWPMI1SMEN;
+        }
+        CCIPR {
+            SWPMI1SEL;
+        }
+    }
+    SWPMI1 {
+        CR;
+        BRR;
+        ISR;
+        ICR;
+        IER;
+        RFL;
+        TDR;
+        RDR;
+        #[cfg(any(
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6"
+        ))]
+        OR;
+    }
+}
@@ -0,0 +1,185 @@
+//! System configuration controller.
+//!
+//! The `EXTICR1`..`EXTICR4` source selection fields are not part of these
+//! peripherals. Each of them belongs to the corresponding EXTI line in the
+//! `exti` crate, so that acquiring a line also selects its GPIO port. The
+//! `MII_RMII_SEL` field belongs to the Ethernet peripheral.
+//!
+//! On STM32L4 the `SYSCFGEN` clock also gates the comparators and the voltage
+//! reference buffer.
+
+#![feature(proc_macro_hygiene)]
+#![warn(missing_docs)]
+#![warn(clippy::pedantic)]
+#![allow(clippy::type_repetition_in_bounds, clippy::wildcard_imports)]
+#![no_std]
+
+#[allow(unused_imports)]
+use drone_core::periph;
+
+#[cfg(any(
+    stm32_mcu = "stm32f100",
+    stm32_mcu = "stm32f101",
+    stm32_mcu = "stm32f102",
+    stm32_mcu = "stm32f103",
+    stm32_mcu = "stm32f107"
+))]
+periph::singular! {
+    /// Extracts AFIO register tokens.
+    pub macro periph_afio;
+
+    /// Alternate function I/O peripheral.
+    pub struct AfioPeriph;
+
+    drone_stm32_map_pieces::reg;
+    crate;
+
+    RCC {
+        APB2ENR {
+            AFIOEN;
+        }
+        APB2RSTR {
+            AFIORST;
+        }
+    }
+    AFIO {
+        EVCR;
+        #[cfg(any(
+            stm32_mcu = "stm32f100",
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f102",
+            stm32_mcu = "stm32f103"
+        ))]
+        MAPR;
+        #[cfg(any(stm32_mcu = "stm32f107"))]
+        MAPR {
+            SPI1_REMAP;
+            I2C1_REMAP;
+            USART1_REMAP;
+            USART2_REMAP;
+            USART3_REMAP;
+            TIM1_REMAP;
+            TIM2_REMAP;
+            TIM3_REMAP;
+            TIM4_REMAP;
+            CAN1_REMAP;
+            PD01_REMAP;
+            TIM5CH4_IREMAP;
+            ETH_REMAP;
+            CAN2_REMAP;
+            SWJ_CFG;
+            SPI3_REMAP;
+            TIM2ITR1_IREMAP;
+            PTP_PPS_REMAP;
+        }
+        MAPR2;
+    }
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32f401",
+    stm32_mcu = "stm32f405",
+    stm32_mcu = "stm32f407",
+    stm32_mcu = "stm32f410",
+    stm32_mcu = "stm32f411",
+    stm32_mcu = "stm32f412",
+    stm32_mcu = "stm32f413",
+    stm32_mcu = "stm32f427",
+    stm32_mcu = "stm32f429",
+    stm32_mcu = "stm32f446",
+    stm32_mcu = "stm32f469"
+))]
+periph::singular! {
+    /// Extracts SYSCFG register tokens.
+    pub macro periph_syscfg;
+
+    /// SYSCFG peripheral.
+    pub struct SyscfgPeriph;
+
+    drone_stm32_map_pieces::reg;
+    crate;
+
+    RCC {
+        APB2ENR {
+            SYSCFGEN;
+        }
+        APB2RSTR {
+            SYSCFGRST;
+        }
+        APB2LPENR {
+            SYSCFGLPEN;
+        }
+    }
+    SYSCFG {
+        MEMRM;
+        #[cfg(any(
+            stm32_mcu = "stm32f401",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f410",
+            stm32_mcu = "stm32f411",
+            stm32_mcu = "stm32f412",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f446"
+        ))]
+        PMC;
+        #[cfg(any(
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f469"
+        ))]
+        PMC {
+            ADC1DC2;
+            ADC2DC2;
+            ADC3DC2;
+        }
+        CMPCR;
+        #[cfg(any(stm32_mcu = "stm32f410"))]
+        CFGR;
+        #[cfg(any(stm32_mcu = "stm32f412", stm32_mcu = "stm32f413"))]
+        I2C_BUFOUT;
+    }
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32l4x1",
+    stm32_mcu = "stm32l4x2",
+    stm32_mcu = "stm32l4x3",
+    stm32_mcu = "stm32l4x5",
+    stm32_mcu = "stm32l4x6",
+    stm32_mcu = "stm32l4r5",
+    stm32_mcu = "stm32l4r7",
+    stm32_mcu = "stm32l4r9",
+    stm32_mcu = "stm32l4s5",
+    stm32_mcu = "stm32l4s7",
+    stm32_mcu = "stm32l4s9"
+))]
+periph::singular! {
+    /// Extracts SYSCFG register tokens.
+    pub macro periph_syscfg;
+
+    /// SYSCFG peripheral.
+    pub struct SyscfgPeriph;
+
+    drone_stm32_map_pieces::reg;
+    crate;
+
+    RCC {
+        APB2ENR {
+            SYSCFGEN;
+        }
+        APB2RSTR {
+            SYSCFGRST;
+        }
+        APB2SMENR {
+            SYSCFGSMEN;
+        }
+    }
+    SYSCFG {
+        MEMRMP;
+        CFGR1;
+        SCSR;
+        CFGR2;
+        SWPR;
+        SKR;
+    }
+}
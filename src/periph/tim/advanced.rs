@@ -19,6 +19,30 @@ periph! {
             0x20 RwRegBitBand Shared;
             TIMRST { RwRwRegFieldBitBand }
         }
+        #[cfg(any(
+            stm32_mcu = "stm32f401",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f410",
+            stm32_mcu = "stm32f411",
+            stm32_mcu = "stm32f412",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469",
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9"
+        ))]
         BUSSMENR {
             0x20 RwRegBitBand Shared;
             TIMSMEN { RwRwRegFieldBitBand }
@@ -384,6 +408,30 @@ macro_rules! map_advanced_tim {
                     $busrstr Shared;
                     TIMRST { $timrst }
                 }
+                #[cfg(any(
+                    stm32_mcu = "stm32f401",
+                    stm32_mcu = "stm32f405",
+                    stm32_mcu = "stm32f407",
+                    stm32_mcu = "stm32f410",
+                    stm32_mcu = "stm32f411",
+                    stm32_mcu = "stm32f412",
+                    stm32_mcu = "stm32f413",
+                    stm32_mcu = "stm32f427",
+                    stm32_mcu = "stm32f429",
+                    stm32_mcu = "stm32f446",
+                    stm32_mcu = "stm32f469",
+                    stm32_mcu = "stm32l4x1",
+                    stm32_mcu = "stm32l4x2",
+                    stm32_mcu = "stm32l4x3",
+                    stm32_mcu = "stm32l4x5",
+                    stm32_mcu = "stm32l4x6",
+                    stm32_mcu = "stm32l4r5",
+                    stm32_mcu = "stm32l4r7",
+                    stm32_mcu = "stm32l4r9",
+                    stm32_mcu = "stm32l4s5",
+                    stm32_mcu = "stm32l4s7",
+                    stm32_mcu = "stm32l4s9"
+                ))]
                 BUSSMENR {
                     $bussmenr Shared;
                     TIMSMEN { $timsmen }
@@ -713,6 +761,42 @@ macro_rules! map_advanced_tim {
     };
 }
 
+#[cfg(any(stm32_mcu = "stm32f100", stm32_mcu = "stm32f103", stm32_mcu = "stm32f107"))]
+map_advanced_tim! {
+    "Extracts TIM1 register tokens.",
+    periph_tim1,
+    "TIM1 peripheral variant",
+    Tim1,
+    TIM1EN,
+    TIM1RST,
+    TIM1SMEN,
+    TIM1,
+    APB2ENR,
+    APB2RSTR,
+    APB2SMENR,
+    BKDFBK0E,
+    BK2DFBK0E,
+    (),
+}
+
+#[cfg(any(stm32_mcu = "stm32f103"))]
+map_advanced_tim! {
+    "Extracts TIM8 register tokens.",
+    periph_tim8,
+    "TIM8 peripheral variant",
+    Tim8,
+    TIM8EN,
+    TIM8RST,
+    TIM8SMEN,
+    TIM8,
+    APB2ENR,
+    APB2RSTR,
+    APB2SMENR,
+    BKDFBK0E,
+    BK2DFBK0E,
+    (),
+}
+
 #[cfg(any(
     stm32_mcu = "stm32l4x1",
     stm32_mcu = "stm32l4x2",
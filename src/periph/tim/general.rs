@@ -77,6 +77,7 @@ periph! {
             ))]
             OPM { RwRwRegFieldBitBand }
             #[cfg(any(
+                stm32_mcu = "stm32f100",
                 stm32_mcu = "stm32f410",
                 stm32_mcu = "stm32f412",
                 stm32_mcu = "stm32f413",
@@ -102,6 +103,7 @@ periph! {
             URS { RwRwRegFieldBitBand }
         }
         #[cfg(any(
+            stm32_mcu = "stm32f100",
             stm32_mcu = "stm32f401",
             stm32_mcu = "stm32f405",
             stm32_mcu = "stm32f407",
@@ -161,6 +163,7 @@ periph! {
             ))]
             SMS3 { RwRwRegFieldBitBand }
             #[cfg(any(
+                stm32_mcu = "stm32f100",
                 stm32_mcu = "stm32f401",
                 stm32_mcu = "stm32f405",
                 stm32_mcu = "stm32f407",
@@ -185,6 +188,7 @@ periph! {
             ))]
             ETP { RwRwRegFieldBitBand Option }
             #[cfg(any(
+                stm32_mcu = "stm32f100",
                 stm32_mcu = "stm32f401",
                 stm32_mcu = "stm32f405",
                 stm32_mcu = "stm32f407",
@@ -209,6 +213,7 @@ periph! {
             ))]
             ECE { RwRwRegFieldBitBand Option }
             #[cfg(any(
+                stm32_mcu = "stm32f100",
                 stm32_mcu = "stm32f401",
                 stm32_mcu = "stm32f405",
                 stm32_mcu = "stm32f407",
@@ -233,6 +238,7 @@ periph! {
             ))]
             ETPS { RwRwRegFieldBits Option }
             #[cfg(any(
+                stm32_mcu = "stm32f100",
                 stm32_mcu = "stm32f401",
                 stm32_mcu = "stm32f405",
                 stm32_mcu = "stm32f407",
@@ -277,6 +283,7 @@ periph! {
             ))]
             BIE { RwRwRegFieldBitBand Option }
             #[cfg(any(
+                stm32_mcu = "stm32f100",
                 stm32_mcu = "stm32f401",
                 stm32_mcu = "stm32f405",
                 stm32_mcu = "stm32f407",
@@ -342,6 +349,7 @@ periph! {
             TDE { RwRwRegFieldBitBand Option }
             TIE { RwRwRegFieldBitBand Option }
             #[cfg(any(
+                stm32_mcu = "stm32f100",
                 stm32_mcu = "stm32f401",
                 stm32_mcu = "stm32f405",
                 stm32_mcu = "stm32f407",
@@ -454,6 +462,7 @@ periph! {
             CC1S { RwRwRegFieldBits }
             CC2S { RwRwRegFieldBits Option }
             #[cfg(any(
+                stm32_mcu = "stm32f100",
                 stm32_mcu = "stm32f401",
                 stm32_mcu = "stm32f405",
                 stm32_mcu = "stm32f407",
@@ -495,6 +504,7 @@ periph! {
             OC1M3 { RwRwRegFieldBitBand }
             OC1PE { RwRwRegFieldBitBand }
             #[cfg(any(
+                stm32_mcu = "stm32f100",
                 stm32_mcu = "stm32f401",
                 stm32_mcu = "stm32f405",
                 stm32_mcu = "stm32f407",
@@ -548,6 +558,7 @@ periph! {
             CC3S { RwRwRegFieldBits }
             CC4S { RwRwRegFieldBits }
             #[cfg(any(
+                stm32_mcu = "stm32f100",
                 stm32_mcu = "stm32f401",
                 stm32_mcu = "stm32f405",
                 stm32_mcu = "stm32f407",
@@ -575,6 +586,7 @@ periph! {
             OC3M { RwRwRegFieldBits }
             OC3PE { RwRwRegFieldBitBand }
             #[cfg(any(
+                stm32_mcu = "stm32f100",
                 stm32_mcu = "stm32f401",
                 stm32_mcu = "stm32f405",
                 stm32_mcu = "stm32f407",
@@ -736,6 +748,7 @@ periph! {
             OSSR { RwRwRegFieldBitBand }
         }
         #[cfg(any(
+            stm32_mcu = "stm32f100",
             stm32_mcu = "stm32f401",
             stm32_mcu = "stm32f405",
             stm32_mcu = "stm32f407",
@@ -772,6 +785,7 @@ periph! {
             DBL { RwRwRegFieldBits }
         }
         #[cfg(any(
+            stm32_mcu = "stm32f100",
             stm32_mcu = "stm32f401",
             stm32_mcu = "stm32f405",
             stm32_mcu = "stm32f407",
@@ -836,6 +850,7 @@ periph! {
             ))]
             ETR1_RMP { RwRwRegFieldBitBand Option }
             #[cfg(any(
+                stm32_mcu = "stm32f100",
                 stm32_mcu = "stm32f401",
                 stm32_mcu = "stm32f405",
                 stm32_mcu = "stm32f407",
@@ -1107,6 +1122,7 @@ macro_rules! map_general_tim {
                     ))]
                     OPM { OPM }
                     #[cfg(any(
+                        stm32_mcu = "stm32f100",
                         stm32_mcu = "stm32f410",
                         stm32_mcu = "stm32f412",
                         stm32_mcu = "stm32f413",
@@ -1132,6 +1148,7 @@ macro_rules! map_general_tim {
                     URS { URS }
                 }
                 #[cfg(any(
+                    stm32_mcu = "stm32f100",
                     stm32_mcu = "stm32f401",
                     stm32_mcu = "stm32f405",
                     stm32_mcu = "stm32f407",
@@ -1196,6 +1213,7 @@ macro_rules! map_general_tim {
                         ))]
                         SMS3 { SMS3 }
                         #[cfg(any(
+                            stm32_mcu = "stm32f100",
                             stm32_mcu = "stm32f401",
                             stm32_mcu = "stm32f405",
                             stm32_mcu = "stm32f407",
@@ -1220,6 +1238,7 @@ macro_rules! map_general_tim {
                         ))]
                         ETP { $($etp Option)* }
                         #[cfg(any(
+                            stm32_mcu = "stm32f100",
                             stm32_mcu = "stm32f401",
                             stm32_mcu = "stm32f405",
                             stm32_mcu = "stm32f407",
@@ -1244,6 +1263,7 @@ macro_rules! map_general_tim {
                         ))]
                         ECE { $($ece Option)* }
                         #[cfg(any(
+                            stm32_mcu = "stm32f100",
                             stm32_mcu = "stm32f401",
                             stm32_mcu = "stm32f405",
                             stm32_mcu = "stm32f407",
@@ -1268,6 +1288,7 @@ macro_rules! map_general_tim {
                         ))]
                         ETPS { $($etps Option)* }
                         #[cfg(any(
+                            stm32_mcu = "stm32f100",
                             stm32_mcu = "stm32f401",
                             stm32_mcu = "stm32f405",
                             stm32_mcu = "stm32f407",
@@ -1294,6 +1315,7 @@ macro_rules! map_general_tim {
                         MSM { MSM }
                         TS { TS }
                         #[cfg(any(
+                            stm32_mcu = "stm32f100",
                             stm32_mcu = "stm32f401",
                             stm32_mcu = "stm32f405",
                             stm32_mcu = "stm32f407",
@@ -1340,6 +1362,7 @@ macro_rules! map_general_tim {
                     ))]
                     BIE { $($bie Option)* }
                     #[cfg(any(
+                        stm32_mcu = "stm32f100",
                         stm32_mcu = "stm32f401",
                         stm32_mcu = "stm32f405",
                         stm32_mcu = "stm32f407",
@@ -1405,6 +1428,7 @@ macro_rules! map_general_tim {
                     TDE { $($tde Option)* }
                     TIE { $($tie Option)* }
                     #[cfg(any(
+                        stm32_mcu = "stm32f100",
                         stm32_mcu = "stm32f401",
                         stm32_mcu = "stm32f405",
                         stm32_mcu = "stm32f407",
@@ -1517,6 +1541,7 @@ macro_rules! map_general_tim {
                     CC1S { CC1S }
                     CC2S { $($cc2s Option)* }
                     #[cfg(any(
+                        stm32_mcu = "stm32f100",
                         stm32_mcu = "stm32f401",
                         stm32_mcu = "stm32f405",
                         stm32_mcu = "stm32f407",
@@ -1542,6 +1567,7 @@ macro_rules! map_general_tim {
                     OC1CE { $($oc1ce Option)* }
                     OC1FE { OC1FE }
                     #[cfg(any(
+                        stm32_mcu = "stm32f100",
                         stm32_mcu = "stm32f401",
                         stm32_mcu = "stm32f405",
                         stm32_mcu = "stm32f407",
@@ -1585,6 +1611,7 @@ macro_rules! map_general_tim {
                     OC1M3 { OC1M3 }
                     OC1PE { OC1PE }
                     #[cfg(any(
+                        stm32_mcu = "stm32f100",
                         stm32_mcu = "stm32f401",
                         stm32_mcu = "stm32f405",
                         stm32_mcu = "stm32f407",
@@ -1639,6 +1666,7 @@ macro_rules! map_general_tim {
                         CC3S { CC3S }
                         CC4S { CC4S }
                         #[cfg(any(
+                            stm32_mcu = "stm32f100",
                             stm32_mcu = "stm32f401",
                             stm32_mcu = "stm32f405",
                             stm32_mcu = "stm32f407",
@@ -1666,6 +1694,7 @@ macro_rules! map_general_tim {
                         OC3M { OC3M }
                         OC3PE { OC3PE }
                         #[cfg(any(
+                            stm32_mcu = "stm32f100",
                             stm32_mcu = "stm32f401",
                             stm32_mcu = "stm32f405",
                             stm32_mcu = "stm32f407",
@@ -1840,6 +1869,7 @@ macro_rules! map_general_tim {
                     )*
                 }
                 #[cfg(any(
+                    stm32_mcu = "stm32f100",
                     stm32_mcu = "stm32f401",
                     stm32_mcu = "stm32f405",
                     stm32_mcu = "stm32f407",
@@ -1878,6 +1908,7 @@ macro_rules! map_general_tim {
                     DBL { DBL }
                 }
                 #[cfg(any(
+                    stm32_mcu = "stm32f100",
                     stm32_mcu = "stm32f401",
                     stm32_mcu = "stm32f405",
                     stm32_mcu = "stm32f407",
@@ -1945,6 +1976,7 @@ macro_rules! map_general_tim {
                         ))]
                         ETR1_RMP { $($etr1_rmp Option)* }
                         #[cfg(any(
+                            stm32_mcu = "stm32f100",
                             stm32_mcu = "stm32f401",
                             stm32_mcu = "stm32f405",
                             stm32_mcu = "stm32f407",
@@ -2017,6 +2049,102 @@ macro_rules! map_general_tim {
     };
 }
 
+#[cfg(any(stm32_mcu = "stm32f100"))]
+map_general_tim! {
+    "Extracts TIM12 register tokens.",
+    periph_tim12,
+    "TIM12 peripheral variant.",
+    Tim12,
+    APB1ENR,
+    APB1RSTR,
+    APB1SMENR,
+    TIM12EN,
+    TIM12RST,
+    TIM12SMEN,
+    TIM12,
+    (,, OPM),
+    (),
+    (SMCR,,,,),
+    (,,, CC2IE,,,,,,,, TIE,),
+    (, CC2IF, CC2OF,,,,,, TIF),
+    (, CC2G,,,, TG),
+    (CC2S,,, OC2FE, OC2M,, OC2PE, IC2F, IC2PSC),
+    (,),
+    (, CC2E, CC2NP, CC2P,,,,,,),
+    (,),
+    (),
+    (CCR2,,),
+    (),
+    (),
+    (),
+    (),
+    (),
+}
+
+#[cfg(any(stm32_mcu = "stm32f100"))]
+map_general_tim! {
+    "Extracts TIM13 register tokens.",
+    periph_tim13,
+    "TIM13 peripheral variant.",
+    Tim13,
+    APB1ENR,
+    APB1RSTR,
+    APB1SMENR,
+    TIM13EN,
+    TIM13RST,
+    TIM13SMEN,
+    TIM13,
+    (,,),
+    (),
+    (),
+    (,,,,,,,,,,,,),
+    (,,,,,,,,),
+    (,,,,,),
+    (,,,,,,,,),
+    (,),
+    (,,,,,,,,,),
+    (,),
+    (),
+    (,,),
+    (),
+    (),
+    (),
+    (),
+    (),
+}
+
+#[cfg(any(stm32_mcu = "stm32f100"))]
+map_general_tim! {
+    "Extracts TIM14 register tokens.",
+    periph_tim14,
+    "TIM14 peripheral variant.",
+    Tim14,
+    APB1ENR,
+    APB1RSTR,
+    APB1SMENR,
+    TIM14EN,
+    TIM14RST,
+    TIM14SMEN,
+    TIM14,
+    (,,),
+    (),
+    (),
+    (,,,,,,,,,,,,),
+    (,,,,,,,,),
+    (,,,,,),
+    (,,,,,,,,),
+    (,),
+    (,,,,,,,,,),
+    (,),
+    (),
+    (,,),
+    (),
+    (),
+    (),
+    (),
+    (),
+}
+
 #[cfg(any(
     stm32_mcu = "stm32l4x1",
     stm32_mcu = "stm32l4x2",
@@ -2741,3 +2869,58 @@ map_general_tim! {
     (),
     (),
 }
+
+/// General-purpose timer peripheral variant with a 32-bit counter.
+///
+/// Implemented for the TIM2 and TIM5 instances, whose `CNT`, `ARR` and `CCRx`
+/// registers are 32 bits wide. Other general-purpose timers have 16-bit
+/// counters.
+pub trait GeneralTim32Map: GeneralTimMap {}
+
+#[cfg(any(
+    stm32_mcu = "stm32f401",
+    stm32_mcu = "stm32f405",
+    stm32_mcu = "stm32f407",
+    stm32_mcu = "stm32f411",
+    stm32_mcu = "stm32f412",
+    stm32_mcu = "stm32f413",
+    stm32_mcu = "stm32f427",
+    stm32_mcu = "stm32f429",
+    stm32_mcu = "stm32f446",
+    stm32_mcu = "stm32f469",
+    stm32_mcu = "stm32l4x1",
+    stm32_mcu = "stm32l4x2",
+    stm32_mcu = "stm32l4x3",
+    stm32_mcu = "stm32l4x5",
+    stm32_mcu = "stm32l4x6",
+    stm32_mcu = "stm32l4r5",
+    stm32_mcu = "stm32l4r7",
+    stm32_mcu = "stm32l4r9",
+    stm32_mcu = "stm32l4s5",
+    stm32_mcu = "stm32l4s7",
+    stm32_mcu = "stm32l4s9"
+))]
+impl GeneralTim32Map for Tim2 {}
+
+#[cfg(any(
+    stm32_mcu = "stm32f401",
+    stm32_mcu = "stm32f405",
+    stm32_mcu = "stm32f407",
+    stm32_mcu = "stm32f410",
+    stm32_mcu = "stm32f411",
+    stm32_mcu = "stm32f412",
+    stm32_mcu = "stm32f413",
+    stm32_mcu = "stm32f427",
+    stm32_mcu = "stm32f429",
+    stm32_mcu = "stm32f446",
+    stm32_mcu = "stm32f469",
+    stm32_mcu = "stm32l4x5",
+    stm32_mcu = "stm32l4x6",
+    stm32_mcu = "stm32l4r5",
+    stm32_mcu = "stm32l4r7",
+    stm32_mcu = "stm32l4r9",
+    stm32_mcu = "stm32l4s5",
+    stm32_mcu = "stm32l4s7",
+    stm32_mcu = "stm32l4s9"
+))]
+impl GeneralTim32Map for Tim5 {}
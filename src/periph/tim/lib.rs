@@ -7,6 +7,9 @@
 #![no_std]
 
 #[cfg(any(
+    stm32_mcu = "stm32f100",
+    stm32_mcu = "stm32f103",
+    stm32_mcu = "stm32f107",
     stm32_mcu = "stm32f401",
     stm32_mcu = "stm32f405",
     stm32_mcu = "stm32f407",
@@ -59,6 +62,7 @@ pub mod advanced;
 ))]
 pub mod basic;
 #[cfg(any(
+    stm32_mcu = "stm32f100",
     stm32_mcu = "stm32f401",
     stm32_mcu = "stm32f405",
     stm32_mcu = "stm32f407",
@@ -97,3 +101,76 @@ pub mod general;
     stm32_mcu = "stm32l4s9"
 ))]
 pub mod low_power;
+
+/// Extracts TIM register tokens by instance number.
+///
+/// `periph_tim!(2, reg)` is equivalent to `periph_tim2!(reg)`. An instance
+/// missing on the selected MCU fails to resolve at the call site.
+#[macro_export]
+macro_rules! periph_tim {
+    (1, $reg:ident) => {
+        $crate::periph_tim1!($reg)
+    };
+    (2, $reg:ident) => {
+        $crate::periph_tim2!($reg)
+    };
+    (3, $reg:ident) => {
+        $crate::periph_tim3!($reg)
+    };
+    (4, $reg:ident) => {
+        $crate::periph_tim4!($reg)
+    };
+    (5, $reg:ident) => {
+        $crate::periph_tim5!($reg)
+    };
+    (6, $reg:ident) => {
+        $crate::periph_tim6!($reg)
+    };
+    (7, $reg:ident) => {
+        $crate::periph_tim7!($reg)
+    };
+    (8, $reg:ident) => {
+        $crate::periph_tim8!($reg)
+    };
+    (9, $reg:ident) => {
+        $crate::periph_tim9!($reg)
+    };
+    (10, $reg:ident) => {
+        $crate::periph_tim10!($reg)
+    };
+    (11, $reg:ident) => {
+        $crate::periph_tim11!($reg)
+    };
+    (12, $reg:ident) => {
+        $crate::periph_tim12!($reg)
+    };
+    (13, $reg:ident) => {
+        $crate::periph_tim13!($reg)
+    };
+    (14, $reg:ident) => {
+        $crate::periph_tim14!($reg)
+    };
+    (15, $reg:ident) => {
+        $crate::periph_tim15!($reg)
+    };
+    (16, $reg:ident) => {
+        $crate::periph_tim16!($reg)
+    };
+    (17, $reg:ident) => {
+        $crate::periph_tim17!($reg)
+    };
+}
+
+/// Extracts LPTIM register tokens by instance number.
+///
+/// `periph_lptim!(2, reg)` is equivalent to `periph_lptim2!(reg)`. An instance
+/// missing on the selected MCU fails to resolve at the call site.
+#[macro_export]
+macro_rules! periph_lptim {
+    (1, $reg:ident) => {
+        $crate::periph_lptim1!($reg)
+    };
+    (2, $reg:ident) => {
+        $crate::periph_lptim2!($reg)
+    };
+}
@@ -0,0 +1,65 @@
+//! Touch sensing controller.
+
+#![feature(proc_macro_hygiene)]
+#![warn(missing_docs)]
+#![warn(clippy::pedantic)]
+#![allow(clippy::type_repetition_in_bounds, clippy::wildcard_imports)]
+#![no_std]
+
+#[allow(unused_imports)]
+use drone_core::periph;
+
+#[cfg(any(
+    stm32_mcu = "stm32l4x1",
+    stm32_mcu = "stm32l4x2",
+    stm32_mcu = "stm32l4x3",
+    stm32_mcu = "stm32l4x5",
+    stm32_mcu = "stm32l4x6",
+    stm32_mcu = "stm32l4r5",
+    stm32_mcu = "stm32l4r7",
+    stm32_mcu = "stm32l4r9",
+    stm32_mcu = "stm32l4s5",
+    stm32_mcu = "stm32l4s7",
+    stm32_mcu = "stm32l4s9"
+))]
+periph::singular! {
+    /// Extracts TSC register tokens.
+    pub macro periph_tsc;
+
+    /// TSC peripheral.
+    pub struct TscPeriph;
+
+    drone_stm32_map_pieces::reg;
+    crate;
+
+    RCC {
+        AHB1ENR {
+            TSCEN;
+        }
+        AHB1RSTR {
+            TSCRST;
+        }
+        AHB1SMENR {
+            TSCSMEN;
+        }
+    }
+    TSC {
+        CR;
+        IER;
+        ICR;
+        ISR;
+        IOHCR;
+        IOASCR;
+        IOSCR;
+        IOCCR;
+        IOGCSR;
+        IOG1CR;
+        IOG2CR;
+        IOG3CR;
+        IOG4CR;
+        IOG5CR;
+        IOG6CR;
+        IOG7CR;
+        IOG8CR;
+    }
+}
@@ -1,4 +1,11 @@
 //! Universal Asynchronous Receiver/Transmitter.
+//!
+//! STM32F1 and STM32F4 share the older `SR`/`DR` register layout, mapped in
+//! a separate module and re-exported under the same names. The smartcard
+//! (`GTPR`, `SCEN`, `NACK`), synchronous clock (`CLKEN`, `CPOL`, `CPHA`,
+//! `LBCL`) and hardware flow control fields exist only on USART instances,
+//! so they are optional for the UARTs. IrDA and LIN fields are present on
+//! every instance except LPUART1.
 
 #![feature(proc_macro_hygiene)]
 #![warn(missing_docs)]
@@ -6,9 +13,107 @@
 #![allow(clippy::type_repetition_in_bounds, clippy::wildcard_imports)]
 #![no_std]
 
+#[cfg(any(
+    stm32_mcu = "stm32f100",
+    stm32_mcu = "stm32f101",
+    stm32_mcu = "stm32f102",
+    stm32_mcu = "stm32f103",
+    stm32_mcu = "stm32f107",
+    stm32_mcu = "stm32f401",
+    stm32_mcu = "stm32f405",
+    stm32_mcu = "stm32f407",
+    stm32_mcu = "stm32f410",
+    stm32_mcu = "stm32f411",
+    stm32_mcu = "stm32f412",
+    stm32_mcu = "stm32f413",
+    stm32_mcu = "stm32f427",
+    stm32_mcu = "stm32f429",
+    stm32_mcu = "stm32f446",
+    stm32_mcu = "stm32f469"
+))]
+mod v1;
+
+#[cfg(any(
+    stm32_mcu = "stm32f100",
+    stm32_mcu = "stm32f101",
+    stm32_mcu = "stm32f102",
+    stm32_mcu = "stm32f103",
+    stm32_mcu = "stm32f107",
+    stm32_mcu = "stm32f401",
+    stm32_mcu = "stm32f405",
+    stm32_mcu = "stm32f407",
+    stm32_mcu = "stm32f410",
+    stm32_mcu = "stm32f411",
+    stm32_mcu = "stm32f412",
+    stm32_mcu = "stm32f413",
+    stm32_mcu = "stm32f427",
+    stm32_mcu = "stm32f429",
+    stm32_mcu = "stm32f446",
+    stm32_mcu = "stm32f469"
+))]
+pub use self::v1::*;
+
+#[allow(unused_imports)]
 use drone_core::periph;
+#[allow(unused_imports)]
 use drone_cortexm::reg::marker::*;
 
+/// Number of USART, UART and LPUART instances mapped for the selected MCU.
+#[cfg(any(stm32_mcu = "stm32f102"))]
+pub const STM32_UART_COUNT: usize = 2;
+
+/// Number of USART, UART and LPUART instances mapped for the selected MCU.
+#[cfg(any(stm32_mcu = "stm32f401", stm32_mcu = "stm32f410", stm32_mcu = "stm32f411"))]
+pub const STM32_UART_COUNT: usize = 3;
+
+/// Number of USART, UART and LPUART instances mapped for the selected MCU.
+#[cfg(any(stm32_mcu = "stm32f412", stm32_mcu = "stm32l4x3"))]
+pub const STM32_UART_COUNT: usize = 4;
+
+/// Number of USART, UART and LPUART instances mapped for the selected MCU.
+#[cfg(any(
+    stm32_mcu = "stm32f100",
+    stm32_mcu = "stm32f101",
+    stm32_mcu = "stm32f103",
+    stm32_mcu = "stm32f107",
+    stm32_mcu = "stm32l4x1",
+    stm32_mcu = "stm32l4x2"
+))]
+pub const STM32_UART_COUNT: usize = 5;
+
+/// Number of USART, UART and LPUART instances mapped for the selected MCU.
+#[cfg(any(
+    stm32_mcu = "stm32f405",
+    stm32_mcu = "stm32f407",
+    stm32_mcu = "stm32f413",
+    stm32_mcu = "stm32f427",
+    stm32_mcu = "stm32f429",
+    stm32_mcu = "stm32f446",
+    stm32_mcu = "stm32f469",
+    stm32_mcu = "stm32l4x5",
+    stm32_mcu = "stm32l4x6",
+    stm32_mcu = "stm32l4r5",
+    stm32_mcu = "stm32l4r7",
+    stm32_mcu = "stm32l4r9",
+    stm32_mcu = "stm32l4s5",
+    stm32_mcu = "stm32l4s7",
+    stm32_mcu = "stm32l4s9"
+))]
+pub const STM32_UART_COUNT: usize = 6;
+
+#[cfg(any(
+    stm32_mcu = "stm32l4x1",
+    stm32_mcu = "stm32l4x2",
+    stm32_mcu = "stm32l4x3",
+    stm32_mcu = "stm32l4x5",
+    stm32_mcu = "stm32l4x6",
+    stm32_mcu = "stm32l4r5",
+    stm32_mcu = "stm32l4r7",
+    stm32_mcu = "stm32l4r9",
+    stm32_mcu = "stm32l4s5",
+    stm32_mcu = "stm32l4s7",
+    stm32_mcu = "stm32l4s9"
+))]
 periph! {
     /// Generic UART peripheral variant.
     pub trait UartMap {}
@@ -813,3 +918,37 @@ map_uart! {
     (),
     (),
 }
+
+/// Extracts USART register tokens by instance number.
+///
+/// `periph_usart!(2, reg)` is equivalent to `periph_usart2!(reg)`. An instance
+/// missing on the selected MCU fails to resolve at the call site.
+#[macro_export]
+macro_rules! periph_usart {
+    (1, $reg:ident) => {
+        $crate::periph_usart1!($reg)
+    };
+    (2, $reg:ident) => {
+        $crate::periph_usart2!($reg)
+    };
+    (3, $reg:ident) => {
+        $crate::periph_usart3!($reg)
+    };
+    (6, $reg:ident) => {
+        $crate::periph_usart6!($reg)
+    };
+}
+
+/// Extracts UART register tokens by instance number.
+///
+/// `periph_uart!(5, reg)` is equivalent to `periph_uart5!(reg)`. An instance
+/// missing on the selected MCU fails to resolve at the call site.
+#[macro_export]
+macro_rules! periph_uart {
+    (4, $reg:ident) => {
+        $crate::periph_uart4!($reg)
+    };
+    (5, $reg:ident) => {
+        $crate::periph_uart5!($reg)
+    };
+}
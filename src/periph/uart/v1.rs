@@ -0,0 +1,740 @@
+//! USART with the `SR`/`DR` register layout of STM32F1 and STM32F4.
+
+use drone_core::periph;
+use drone_cortexm::reg::marker::*;
+
+periph! {
+    /// Generic UART peripheral variant.
+    pub trait UartMap {}
+
+    /// Generic UART peripheral.
+    pub struct UartPeriph;
+
+    RCC {
+        BUSENR {
+            0x20 RwRegBitBand Shared;
+            UARTEN { RwRwRegFieldBitBand }
+        }
+        BUSRSTR {
+            0x20 RwRegBitBand Shared;
+            UARTRST { RwRwRegFieldBitBand }
+        }
+        #[cfg(any(
+            stm32_mcu = "stm32f401",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f410",
+            stm32_mcu = "stm32f411",
+            stm32_mcu = "stm32f412",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469"
+        ))]
+        BUSSMENR {
+            0x20 RwRegBitBand Shared;
+            UARTSMEN { RwRwRegFieldBitBand }
+        }
+    }
+    UART {
+        SR {
+            0x20 RwRegBitBand;
+            CTS { RwRwRegFieldBitBand Option }
+            FE { RoRwRegFieldBitBand }
+            IDLE { RoRwRegFieldBitBand }
+            LBD { RwRwRegFieldBitBand }
+            #[cfg(any(
+                stm32_mcu = "stm32f100",
+                stm32_mcu = "stm32f101",
+                stm32_mcu = "stm32f102",
+                stm32_mcu = "stm32f103",
+                stm32_mcu = "stm32f107"
+            ))]
+            NE { RoRwRegFieldBitBand }
+            #[cfg(any(
+                stm32_mcu = "stm32f401",
+                stm32_mcu = "stm32f405",
+                stm32_mcu = "stm32f407",
+                stm32_mcu = "stm32f410",
+                stm32_mcu = "stm32f411",
+                stm32_mcu = "stm32f412",
+                stm32_mcu = "stm32f413",
+                stm32_mcu = "stm32f427",
+                stm32_mcu = "stm32f429",
+                stm32_mcu = "stm32f446",
+                stm32_mcu = "stm32f469"
+            ))]
+            NF { RoRwRegFieldBitBand }
+            ORE { RoRwRegFieldBitBand }
+            PE { RoRwRegFieldBitBand }
+            RXNE { RwRwRegFieldBitBand }
+            TC { RwRwRegFieldBitBand }
+            TXE { RoRwRegFieldBitBand }
+        }
+        DR {
+            0x20 RwRegBitBand;
+            DR { RwRwRegFieldBits }
+        }
+        BRR {
+            0x20 RwRegBitBand;
+            DIV_Fraction { RwRwRegFieldBits }
+            DIV_Mantissa { RwRwRegFieldBits }
+        }
+        CR1 {
+            0x20 RwRegBitBand;
+            IDLEIE { RwRwRegFieldBitBand }
+            M { RwRwRegFieldBitBand }
+            #[cfg(any(
+                stm32_mcu = "stm32f401",
+                stm32_mcu = "stm32f405",
+                stm32_mcu = "stm32f407",
+                stm32_mcu = "stm32f410",
+                stm32_mcu = "stm32f411",
+                stm32_mcu = "stm32f412",
+                stm32_mcu = "stm32f413",
+                stm32_mcu = "stm32f427",
+                stm32_mcu = "stm32f429",
+                stm32_mcu = "stm32f446",
+                stm32_mcu = "stm32f469"
+            ))]
+            OVER8 { RwRwRegFieldBitBand }
+            PCE { RwRwRegFieldBitBand }
+            PEIE { RwRwRegFieldBitBand }
+            PS { RwRwRegFieldBitBand }
+            RE { RwRwRegFieldBitBand }
+            RWU { RwRwRegFieldBitBand }
+            RXNEIE { RwRwRegFieldBitBand }
+            SBK { RwRwRegFieldBitBand }
+            TCIE { RwRwRegFieldBitBand }
+            TE { RwRwRegFieldBitBand }
+            TXEIE { RwRwRegFieldBitBand }
+            UE { RwRwRegFieldBitBand }
+            WAKE { RwRwRegFieldBitBand }
+        }
+        CR2 {
+            0x20 RwRegBitBand;
+            ADD { RwRwRegFieldBits }
+            CLKEN { RwRwRegFieldBitBand Option }
+            CPHA { RwRwRegFieldBitBand Option }
+            CPOL { RwRwRegFieldBitBand Option }
+            LBCL { RwRwRegFieldBitBand Option }
+            LBDIE { RwRwRegFieldBitBand }
+            LBDL { RwRwRegFieldBitBand }
+            LINEN { RwRwRegFieldBitBand }
+            STOP { RwRwRegFieldBits }
+        }
+        CR3 {
+            0x20 RwRegBitBand;
+            CTSE { RwRwRegFieldBitBand Option }
+            CTSIE { RwRwRegFieldBitBand Option }
+            DMAR { RwRwRegFieldBitBand Option }
+            DMAT { RwRwRegFieldBitBand }
+            EIE { RwRwRegFieldBitBand }
+            HDSEL { RwRwRegFieldBitBand }
+            IREN { RwRwRegFieldBitBand }
+            IRLP { RwRwRegFieldBitBand }
+            NACK { RwRwRegFieldBitBand Option }
+            #[cfg(any(
+                stm32_mcu = "stm32f401",
+                stm32_mcu = "stm32f405",
+                stm32_mcu = "stm32f407",
+                stm32_mcu = "stm32f410",
+                stm32_mcu = "stm32f411",
+                stm32_mcu = "stm32f412",
+                stm32_mcu = "stm32f413",
+                stm32_mcu = "stm32f427",
+                stm32_mcu = "stm32f429",
+                stm32_mcu = "stm32f446",
+                stm32_mcu = "stm32f469"
+            ))]
+            ONEBIT { RwRwRegFieldBitBand }
+            RTSE { RwRwRegFieldBitBand Option }
+            SCEN { RwRwRegFieldBitBand Option }
+        }
+        GTPR {
+            0x20 RwRegBitBand Option;
+            GT { RwRwRegFieldBits }
+            PSC { RwRwRegFieldBits }
+        }
+    }
+}
+
+#[allow(unused_macros)]
+macro_rules! map_uart {
+    (
+        $uart_macro_doc:expr,
+        $uart_macro:ident,
+        $uart_ty_doc:expr,
+        $uart_ty:ident,
+        $busenr:ident,
+        $busrstr:ident,
+        $bussmenr:ident,
+        $uarten:ident,
+        $uartrst:ident,
+        $uartsmen:ident,
+        $uart:ident,
+        ($($cts:ident)?),
+        ($($clken:ident)?),
+        ($($cpha:ident)?),
+        ($($cpol:ident)?),
+        ($($lbcl:ident)?),
+        ($($ctse:ident)?),
+        ($($ctsie:ident)?),
+        ($($dmar:ident)?),
+        ($($nack:ident)?),
+        ($($rtse:ident)?),
+        ($($scen:ident)?),
+        ($($gtpr:ident)?),
+    ) => {
+        periph::map! {
+            #[doc = $uart_macro_doc]
+            pub macro $uart_macro;
+
+            #[doc = $uart_ty_doc]
+            pub struct $uart_ty;
+
+            impl UartMap for $uart_ty {}
+
+            drone_stm32_map_pieces::reg;
+            crate;
+
+            RCC {
+                BUSENR {
+                    $busenr Shared;
+                    UARTEN { $uarten }
+                }
+                BUSRSTR {
+                    $busrstr Shared;
+                    UARTRST { $uartrst }
+                }
+                #[cfg(any(
+                    stm32_mcu = "stm32f401",
+                    stm32_mcu = "stm32f405",
+                    stm32_mcu = "stm32f407",
+                    stm32_mcu = "stm32f410",
+                    stm32_mcu = "stm32f411",
+                    stm32_mcu = "stm32f412",
+                    stm32_mcu = "stm32f413",
+                    stm32_mcu = "stm32f427",
+                    stm32_mcu = "stm32f429",
+                    stm32_mcu = "stm32f446",
+                    stm32_mcu = "stm32f469"
+                ))]
+                BUSSMENR {
+                    $bussmenr Shared;
+                    UARTSMEN { $uartsmen }
+                }
+            }
+            UART {
+                $uart;
+                SR {
+                    SR;
+                    CTS { $($cts Option)* }
+                    FE { FE }
+                    IDLE { IDLE }
+                    LBD { LBD }
+                    #[cfg(any(
+                        stm32_mcu = "stm32f100",
+                        stm32_mcu = "stm32f101",
+                        stm32_mcu = "stm32f102",
+                        stm32_mcu = "stm32f103",
+                        stm32_mcu = "stm32f107"
+                    ))]
+                    NE { NE }
+                    #[cfg(any(
+                        stm32_mcu = "stm32f401",
+                        stm32_mcu = "stm32f405",
+                        stm32_mcu = "stm32f407",
+                        stm32_mcu = "stm32f410",
+                        stm32_mcu = "stm32f411",
+                        stm32_mcu = "stm32f412",
+                        stm32_mcu = "stm32f413",
+                        stm32_mcu = "stm32f427",
+                        stm32_mcu = "stm32f429",
+                        stm32_mcu = "stm32f446",
+                        stm32_mcu = "stm32f469"
+                    ))]
+                    NF { NF }
+                    ORE { ORE }
+                    PE { PE }
+                    RXNE { RXNE }
+                    TC { TC }
+                    TXE { TXE }
+                }
+                DR {
+                    DR;
+                    DR { DR }
+                }
+                BRR {
+                    BRR;
+                    DIV_Fraction { DIV_Fraction }
+                    DIV_Mantissa { DIV_Mantissa }
+                }
+                CR1 {
+                    CR1;
+                    IDLEIE { IDLEIE }
+                    M { M }
+                    #[cfg(any(
+                        stm32_mcu = "stm32f401",
+                        stm32_mcu = "stm32f405",
+                        stm32_mcu = "stm32f407",
+                        stm32_mcu = "stm32f410",
+                        stm32_mcu = "stm32f411",
+                        stm32_mcu = "stm32f412",
+                        stm32_mcu = "stm32f413",
+                        stm32_mcu = "stm32f427",
+                        stm32_mcu = "stm32f429",
+                        stm32_mcu = "stm32f446",
+                        stm32_mcu = "stm32f469"
+                    ))]
+                    OVER8 { OVER8 }
+                    PCE { PCE }
+                    PEIE { PEIE }
+                    PS { PS }
+                    RE { RE }
+                    RWU { RWU }
+                    RXNEIE { RXNEIE }
+                    SBK { SBK }
+                    TCIE { TCIE }
+                    TE { TE }
+                    TXEIE { TXEIE }
+                    UE { UE }
+                    WAKE { WAKE }
+                }
+                CR2 {
+                    CR2;
+                    ADD { ADD }
+                    CLKEN { $($clken Option)* }
+                    CPHA { $($cpha Option)* }
+                    CPOL { $($cpol Option)* }
+                    LBCL { $($lbcl Option)* }
+                    LBDIE { LBDIE }
+                    LBDL { LBDL }
+                    LINEN { LINEN }
+                    STOP { STOP }
+                }
+                CR3 {
+                    CR3;
+                    CTSE { $($ctse Option)* }
+                    CTSIE { $($ctsie Option)* }
+                    DMAR { $($dmar Option)* }
+                    DMAT { DMAT }
+                    EIE { EIE }
+                    HDSEL { HDSEL }
+                    IREN { IREN }
+                    IRLP { IRLP }
+                    NACK { $($nack Option)* }
+                    #[cfg(any(
+                        stm32_mcu = "stm32f401",
+                        stm32_mcu = "stm32f405",
+                        stm32_mcu = "stm32f407",
+                        stm32_mcu = "stm32f410",
+                        stm32_mcu = "stm32f411",
+                        stm32_mcu = "stm32f412",
+                        stm32_mcu = "stm32f413",
+                        stm32_mcu = "stm32f427",
+                        stm32_mcu = "stm32f429",
+                        stm32_mcu = "stm32f446",
+                        stm32_mcu = "stm32f469"
+                    ))]
+                    ONEBIT { ONEBIT }
+                    RTSE { $($rtse Option)* }
+                    SCEN { $($scen Option)* }
+                }
+                GTPR {
+                    $(
+                        $gtpr Option;
+                        GT { GT }
+                        PSC { PSC }
+                    )*
+                }
+            }
+        }
+    };
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32f100",
+    stm32_mcu = "stm32f101",
+    stm32_mcu = "stm32f102",
+    stm32_mcu = "stm32f103",
+    stm32_mcu = "stm32f107"
+))]
+map_uart! {
+    "Extracts USART1 register tokens.",
+    periph_usart1,
+    "USART1 peripheral variant.",
+    Usart1,
+    APB2ENR,
+    APB2RSTR,
+    APB2SMENR,
+    USART1EN,
+    USART1RST,
+    USART1SMEN,
+    USART1,
+    (CTS),
+    (CLKEN),
+    (CPHA),
+    (CPOL),
+    (LBCL),
+    (CTSE),
+    (CTSIE),
+    (DMAR),
+    (NACK),
+    (RTSE),
+    (SCEN),
+    (GTPR),
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32f100",
+    stm32_mcu = "stm32f101",
+    stm32_mcu = "stm32f102",
+    stm32_mcu = "stm32f103",
+    stm32_mcu = "stm32f107"
+))]
+map_uart! {
+    "Extracts USART2 register tokens.",
+    periph_usart2,
+    "USART2 peripheral variant.",
+    Usart2,
+    APB1ENR,
+    APB1RSTR,
+    APB1SMENR,
+    USART2EN,
+    USART2RST,
+    USART2SMEN,
+    USART2,
+    (CTS),
+    (CLKEN),
+    (CPHA),
+    (CPOL),
+    (LBCL),
+    (CTSE),
+    (CTSIE),
+    (DMAR),
+    (NACK),
+    (RTSE),
+    (SCEN),
+    (GTPR),
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32f100",
+    stm32_mcu = "stm32f101",
+    stm32_mcu = "stm32f103",
+    stm32_mcu = "stm32f107"
+))]
+map_uart! {
+    "Extracts USART3 register tokens.",
+    periph_usart3,
+    "USART3 peripheral variant.",
+    Usart3,
+    APB1ENR,
+    APB1RSTR,
+    APB1SMENR,
+    USART3EN,
+    USART3RST,
+    USART3SMEN,
+    USART3,
+    (CTS),
+    (CLKEN),
+    (CPHA),
+    (CPOL),
+    (LBCL),
+    (CTSE),
+    (CTSIE),
+    (DMAR),
+    (NACK),
+    (RTSE),
+    (SCEN),
+    (GTPR),
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32f100",
+    stm32_mcu = "stm32f101",
+    stm32_mcu = "stm32f103",
+    stm32_mcu = "stm32f107"
+))]
+map_uart! {
+    "Extracts UART4 register tokens.",
+    periph_uart4,
+    "UART4 peripheral variant.",
+    Uart4,
+    APB1ENR,
+    APB1RSTR,
+    APB1SMENR,
+    UART4EN,
+    UART4RST,
+    UART4SMEN,
+    UART4,
+    (),
+    (),
+    (),
+    (),
+    (),
+    (),
+    (),
+    (DMAR),
+    (),
+    (),
+    (),
+    (),
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32f100",
+    stm32_mcu = "stm32f101",
+    stm32_mcu = "stm32f103",
+    stm32_mcu = "stm32f107"
+))]
+map_uart! {
+    "Extracts UART5 register tokens.",
+    periph_uart5,
+    "UART5 peripheral variant.",
+    Uart5,
+    APB1ENR,
+    APB1RSTR,
+    APB1SMENR,
+    UART5EN,
+    UART5RST,
+    UART5SMEN,
+    UART5,
+    (),
+    (),
+    (),
+    (),
+    (),
+    (),
+    (),
+    (),
+    (),
+    (),
+    (),
+    (),
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32f401",
+    stm32_mcu = "stm32f405",
+    stm32_mcu = "stm32f407",
+    stm32_mcu = "stm32f410",
+    stm32_mcu = "stm32f411",
+    stm32_mcu = "stm32f412",
+    stm32_mcu = "stm32f413",
+    stm32_mcu = "stm32f427",
+    stm32_mcu = "stm32f429",
+    stm32_mcu = "stm32f446",
+    stm32_mcu = "stm32f469"
+))]
+map_uart! {
+    "Extracts USART1 register tokens.",
+    periph_usart1,
+    "USART1 peripheral variant.",
+    Usart1,
+    APB2ENR,
+    APB2RSTR,
+    APB2LPENR,
+    USART1EN,
+    USART1RST,
+    USART1LPEN,
+    USART1,
+    (CTS),
+    (CLKEN),
+    (CPHA),
+    (CPOL),
+    (LBCL),
+    (CTSE),
+    (CTSIE),
+    (DMAR),
+    (NACK),
+    (RTSE),
+    (SCEN),
+    (GTPR),
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32f401",
+    stm32_mcu = "stm32f405",
+    stm32_mcu = "stm32f407",
+    stm32_mcu = "stm32f410",
+    stm32_mcu = "stm32f411",
+    stm32_mcu = "stm32f412",
+    stm32_mcu = "stm32f413",
+    stm32_mcu = "stm32f427",
+    stm32_mcu = "stm32f429",
+    stm32_mcu = "stm32f446",
+    stm32_mcu = "stm32f469"
+))]
+map_uart! {
+    "Extracts USART2 register tokens.",
+    periph_usart2,
+    "USART2 peripheral variant.",
+    Usart2,
+    APB1ENR,
+    APB1RSTR,
+    APB1LPENR,
+    USART2EN,
+    USART2RST,
+    USART2LPEN,
+    USART2,
+    (CTS),
+    (CLKEN),
+    (CPHA),
+    (CPOL),
+    (LBCL),
+    (CTSE),
+    (CTSIE),
+    (DMAR),
+    (NACK),
+    (RTSE),
+    (SCEN),
+    (GTPR),
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32f405",
+    stm32_mcu = "stm32f407",
+    stm32_mcu = "stm32f412",
+    stm32_mcu = "stm32f413",
+    stm32_mcu = "stm32f427",
+    stm32_mcu = "stm32f429",
+    stm32_mcu = "stm32f446",
+    stm32_mcu = "stm32f469"
+))]
+map_uart! {
+    "Extracts USART3 register tokens.",
+    periph_usart3,
+    "USART3 peripheral variant.",
+    Usart3,
+    APB1ENR,
+    APB1RSTR,
+    APB1LPENR,
+    USART3EN,
+    USART3RST,
+    USART3LPEN,
+    USART3,
+    (CTS),
+    (CLKEN),
+    (CPHA),
+    (CPOL),
+    (LBCL),
+    (CTSE),
+    (CTSIE),
+    (DMAR),
+    (NACK),
+    (RTSE),
+    (SCEN),
+    (GTPR),
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32f405",
+    stm32_mcu = "stm32f407",
+    stm32_mcu = "stm32f413",
+    stm32_mcu = "stm32f427",
+    stm32_mcu = "stm32f429",
+    stm32_mcu = "stm32f446",
+    stm32_mcu = "stm32f469"
+))]
+map_uart! {
+    "Extracts UART4 register tokens.",
+    periph_uart4,
+    "UART4 peripheral variant.",
+    Uart4,
+    APB1ENR,
+    APB1RSTR,
+    APB1LPENR,
+    UART4EN,
+    UART4RST,
+    UART4LPEN,
+    UART4,
+    (),
+    (),
+    (),
+    (),
+    (),
+    (),
+    (),
+    (DMAR),
+    (),
+    (),
+    (),
+    (),
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32f405",
+    stm32_mcu = "stm32f407",
+    stm32_mcu = "stm32f413",
+    stm32_mcu = "stm32f427",
+    stm32_mcu = "stm32f429",
+    stm32_mcu = "stm32f446",
+    stm32_mcu = "stm32f469"
+))]
+map_uart! {
+    "Extracts UART5 register tokens.",
+    periph_uart5,
+    "UART5 peripheral variant.",
+    Uart5,
+    APB1ENR,
+    APB1RSTR,
+    APB1LPENR,
+    UART5EN,
+    UART5RST,
+    UART5LPEN,
+    UART5,
+    (),
+    (),
+    (),
+    (),
+    (),
+    (),
+    (),
+    (DMAR),
+    (),
+    (),
+    (),
+    (),
+}
+
+#[cfg(any(
+    stm32_mcu = "stm32f401",
+    stm32_mcu = "stm32f405",
+    stm32_mcu = "stm32f407",
+    stm32_mcu = "stm32f410",
+    stm32_mcu = "stm32f411",
+    stm32_mcu = "stm32f412",
+    stm32_mcu = "stm32f413",
+    stm32_mcu = "stm32f427",
+    stm32_mcu = "stm32f429",
+    stm32_mcu = "stm32f446",
+    stm32_mcu = "stm32f469"
+))]
+map_uart! {
+    "Extracts USART6 register tokens.",
+    periph_usart6,
+    "USART6 peripheral variant.",
+    Usart6,
+    APB2ENR,
+    APB2RSTR,
+    APB2LPENR,
+    USART6EN,
+    USART6RST,
+    USART6LPEN,
+    USART6,
+    (CTS),
+    (CLKEN),
+    (CPHA),
+    (CPOL),
+    (LBCL),
+    (CTSE),
+    (CTSIE),
+    (DMAR),
+    (NACK),
+    (RTSE),
+    (SCEN),
+    (GTPR),
+}
@@ -0,0 +1,129 @@
+//! Universal Serial Bus full-speed device interface.
+//!
+//! The endpoint buffers and the buffer descriptor table live in a dedicated
+//! packet memory area. On STM32F1 the CPU sees it as 16-bit words, each
+//! followed by a 16-bit gap, so a USB-local offset maps to twice that offset on
+//! the APB bus. On STM32F103 this memory is shared with CAN1. On STM32L4 the
+//! packet memory is mapped contiguously.
+
+#![feature(proc_macro_hygiene)]
+#![warn(missing_docs)]
+#![warn(clippy::pedantic)]
+#![allow(clippy::type_repetition_in_bounds, clippy::wildcard_imports)]
+#![no_std]
+
+#[allow(unused_imports)]
+use drone_core::periph;
+
+/// Address of the packet memory area as seen by the CPU.
+#[cfg(any(stm32_mcu = "stm32f102", stm32_mcu = "stm32f103"))]
+pub const USB_PMA_BASE: usize = 0x4000_6000;
+
+/// Address of the packet memory area as seen by the CPU.
+#[cfg(any(stm32_mcu = "stm32l4x2", stm32_mcu = "stm32l4x3"))]
+pub const USB_PMA_BASE: usize = 0x4000_6C00;
+
+/// Size of the packet memory area in bytes, as seen by the USB peripheral.
+#[cfg(any(stm32_mcu = "stm32f102", stm32_mcu = "stm32f103"))]
+pub const USB_PMA_SIZE: usize = 512;
+
+/// Size of the packet memory area in bytes, as seen by the USB peripheral.
+#[cfg(any(stm32_mcu = "stm32l4x2", stm32_mcu = "stm32l4x3"))]
+pub const USB_PMA_SIZE: usize = 1024;
+
+/// Returns the CPU address of the packet memory `offset`.
+///
+/// The `offset` is the USB-local byte offset, as written to `BTABLE` and to
+/// the buffer descriptor table entries.
+#[cfg(any(stm32_mcu = "stm32f102", stm32_mcu = "stm32f103"))]
+#[must_use]
+pub const fn usb_pma_addr(offset: usize) -> usize {
+    USB_PMA_BASE + offset * 2
+}
+
+/// Returns the CPU address of the packet memory `offset`.
+///
+/// The `offset` is the USB-local byte offset, as written to `BTABLE` and to
+/// the buffer descriptor table entries.
+#[cfg(any(stm32_mcu = "stm32l4x2", stm32_mcu = "stm32l4x3"))]
+#[must_use]
+pub const fn usb_pma_addr(offset: usize) -> usize {
+    USB_PMA_BASE + offset
+}
+
+#[cfg(any(stm32_mcu = "stm32f102", stm32_mcu = "stm32f103"))]
+periph::singular! {
+    /// Extracts USB register tokens.
+    pub macro periph_usb;
+
+    /// USB peripheral.
+    pub struct UsbPeriph;
+
+    drone_stm32_map_pieces::reg;
+    crate;
+
+    RCC {
+        APB1ENR {
+            USBEN;
+        }
+        APB1RSTR {
+            USBRST;
+        }
+    }
+    USB {
+        EP0R;
+        EP1R;
+        EP2R;
+        EP3R;
+        EP4R;
+        EP5R;
+        EP6R;
+        EP7R;
+        CNTR;
+        ISTR;
+        FNR;
+        DADDR;
+        BTABLE;
+    }
+}
+
+#[cfg(any(stm32_mcu = "stm32l4x2", stm32_mcu = "stm32l4x3"))]
+periph::singular! {
+    /// Extracts USB register tokens.
+    pub macro periph_usb;
+
+    /// USB peripheral.
+    pub struct UsbPeriph;
+
+    drone_stm32_map_pieces::reg;
+    crate;
+
+    RCC {
+        APB1ENR1 {
+            USBFSEN;
+        }
+        APB1RSTR1 {
+            USBFSRST;
+        }
+        APB1SMENR1 {
+            USBFSSMEN;
+        }
+    }
+    USB_FS {
+        EP0R;
+        EP1R;
+        EP2R;
+        EP3R;
+        EP4R;
+        EP5R;
+        EP6R;
+        EP7R;
+        CNTR;
+        ISTR;
+        FNR;
+        DADDR;
+        BTABLE;
+        LPMCSR;
+        BCDR;
+    }
+}
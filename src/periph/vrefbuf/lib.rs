@@ -0,0 +1,46 @@
+//! Voltage reference buffer.
+//!
+//! VREFBUF lives in the SYSCFG address range at `0x4001_0030`. It has no
+//! clock enable bit of its own and is clocked through the
+//! `RCC_APB2ENR.SYSCFGEN` bit, which is owned by the SYSCFG peripheral.
+//!
+//! When enabled, the buffer drives the `VREF+` pin used as the ADC and DAC
+//! reference.
+
+#![feature(proc_macro_hygiene)]
+#![warn(missing_docs)]
+#![warn(clippy::pedantic)]
+#![allow(clippy::type_repetition_in_bounds, clippy::wildcard_imports)]
+#![no_std]
+
+#[allow(unused_imports)]
+use drone_core::periph;
+
+#[cfg(any(
+    stm32_mcu = "stm32l4x1",
+    stm32_mcu = "stm32l4x2",
+    stm32_mcu = "stm32l4x3",
+    stm32_mcu = "stm32l4x5",
+    stm32_mcu = "stm32l4x6",
+    stm32_mcu = "stm32l4r5",
+    stm32_mcu = "stm32l4r7",
+    stm32_mcu = "stm32l4r9",
+    stm32_mcu = "stm32l4s5",
+    stm32_mcu = "stm32l4s7",
+    stm32_mcu = "stm32l4s9"
+))]
+periph::singular! {
+    /// Extracts VREFBUF register tokens.
+    pub macro periph_vrefbuf;
+
+    /// VREFBUF peripheral.
+    pub struct VrefbufPeriph;
+
+    drone_stm32_map_pieces::reg;
+    crate;
+
+    VREFBUF {
+        CSR;
+        CCR;
+    }
+}
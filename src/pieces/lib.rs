@@ -40,5 +40,7 @@ pub mod thr {
         include!(concat!(env!("OUT_DIR"), "/svd_interrupts.rs"));
     }
 
+    include!(concat!(env!("OUT_DIR"), "/svd_interrupt_periphs.rs"));
+
     pub use self::map::*;
 }
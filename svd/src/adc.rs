@@ -1,40 +1,110 @@
 //! ADC peripheral patches.
 
+use crate::parse_svd;
 use anyhow::Result;
 use drone_svd::Device;
 
 pub fn fix_adc1_1(dev: &mut Device) -> Result<()> {
-    dev.periph("ADC1").reg("SMPR1").remove_field("SMPx_x");
-    for i in 0..=8 {
-        dev.periph("ADC1").reg("SMPR1").new_field(|field| {
+    split_smpr(dev, "ADC1", 9);
+    Ok(())
+}
+
+pub fn fix_adc_1(dev: &mut Device) -> Result<()> {
+    dev.periph("ADC").reg("SMPR1").remove_field("SMPPLUS");
+    fix_jdata(dev, "ADC");
+    Ok(())
+}
+
+pub fn fix_adc_2(dev: &mut Device) -> Result<()> {
+    fix_adc_l4(dev, "ADC");
+    Ok(())
+}
+
+pub fn fix_adc1_2(dev: &mut Device) -> Result<()> {
+    fix_adc_l4(dev, "ADC1");
+    Ok(())
+}
+
+pub fn fix_adc1_3(dev: &mut Device) -> Result<()> {
+    dev.periph("ADC1").reg("SMPR1").remove_field("SMPPLUS");
+    fix_jdata(dev, "ADC1");
+    Ok(())
+}
+
+pub fn fix_adc1_4(dev: &mut Device) -> Result<()> {
+    split_smpr(dev, "ADC1", 8);
+    Ok(())
+}
+
+pub fn fix_adc_com(dev: &mut Device) -> Result<()> {
+    dev.periph("C_ADC").name = "ADC_Common".to_string();
+    Ok(())
+}
+
+pub fn fix_adc_com_2(dev: &mut Device) -> Result<()> {
+    dev.periph("ADC123_Common").name = "ADC_Common".to_string();
+    let ccr = dev.periph("ADC_Common").reg("CCR");
+    ccr.field("MULT").name = "DUAL".to_string();
+    ccr.field("TSEN").name = "CH17SEL".to_string();
+    ccr.field("VBATEN").name = "CH18SEL".to_string();
+    ccr.new_field(|field| {
+        field.name = "PRESC".to_string();
+        field.description = "ADC prescaler".to_string();
+        field.bit_offset = Some(18);
+        field.bit_width = Some(4);
+    });
+    Ok(())
+}
+
+pub fn add_adc_com(dev: &mut Device) -> Result<()> {
+    let l4x6 = parse_svd("STM32L4x6.svd")?;
+    dev.add_periph(l4x6.periph("ADC_Common").clone());
+    Ok(())
+}
+
+fn split_smpr(dev: &mut Device, periph_name: &str, smpr1_count: u32) {
+    dev.periph(periph_name).reg("SMPR1").remove_field("SMPx_x");
+    for i in 0..smpr1_count {
+        dev.periph(periph_name).reg("SMPR1").new_field(|field| {
             field.name = format!("SMP{}", 10 + i);
             field.description = "Channel x sampling time selection".to_string();
             field.bit_offset = Some(i * 3);
             field.bit_width = Some(3);
         });
     }
-    dev.periph("ADC1").reg("SMPR2").remove_field("SMPx_x");
+    dev.periph(periph_name).reg("SMPR2").remove_field("SMPx_x");
     for i in 0..=9 {
-        dev.periph("ADC1").reg("SMPR2").new_field(|field| {
+        dev.periph(periph_name).reg("SMPR2").new_field(|field| {
             field.name = format!("SMP{}", i);
             field.description = "Channel x sampling time selection".to_string();
             field.bit_offset = Some(i * 3);
             field.bit_width = Some(3);
         });
     }
-    Ok(())
 }
 
-pub fn fix_adc_1(dev: &mut Device) -> Result<()> {
-    dev.periph("ADC").reg("SMPR1").remove_field("SMPPLUS");
+fn fix_adc_l4(dev: &mut Device, periph_name: &str) {
+    dev.periph(periph_name).reg("SQR1").field("L3").name = "L".to_string();
+    dev.periph(periph_name).reg("DR").field("regularDATA").name = "RDATA".to_string();
+    dev.periph(periph_name).reg("CFGR2").field("TOVS").name = "TROVS".to_string();
+    dev.periph(periph_name).reg("CFGR").new_field(|field| {
+        field.name = "JQDIS".to_string();
+        field.description = "Injected Queue disable".to_string();
+        field.bit_offset = Some(31);
+        field.bit_width = Some(1);
+    });
+    dev.periph(periph_name).reg("SMPR1").new_field(|field| {
+        field.name = "SMP0".to_string();
+        field.description = "Channel 0 sampling time selection".to_string();
+        field.bit_offset = Some(0);
+        field.bit_width = Some(3);
+    });
+    fix_jdata(dev, periph_name);
+}
+
+fn fix_jdata(dev: &mut Device, periph_name: &str) {
     for i in 1..=4 {
-        dev.periph("ADC").reg(&format!("JDR{}", i)).field(&format!("JDATA{}", i)).name =
+        dev.periph(periph_name).reg(&format!("JDR{}", i)).field(&format!("JDATA{}", i)).name =
             "JDATA".to_string();
     }
-    Ok(())
-}
-
-pub fn fix_adc_com(dev: &mut Device) -> Result<()> {
-    dev.periph("C_ADC").name = "ADC_Common".to_string();
-    Ok(())
 }
@@ -0,0 +1,54 @@
+//! AES peripheral patches.
+
+use anyhow::Result;
+use drone_svd::{Access, Device};
+
+pub fn fix_aes(dev: &mut Device) -> Result<()> {
+    let periph = dev.periph("AES");
+    let cr = periph.reg("CR");
+    for &(name, description, offset, width) in &[
+        ("GCMPH", "GCM or CCM phase selection", 13, 2),
+        ("CHMOD2", "Chaining mode selection, bit 2", 16, 1),
+        ("KEYSIZE", "Key size selection", 18, 1),
+    ] {
+        cr.new_field(|field| {
+            field.name = name.to_string();
+            field.description = description.to_string();
+            field.bit_offset = Some(offset);
+            field.bit_width = Some(width);
+        });
+    }
+    for i in 0..4 {
+        periph.new_reg(|reg| {
+            reg.name = format!("KEYR{}", i + 4);
+            reg.description = format!("AES key register {}", i + 4);
+            reg.address_offset = 0x30 + i * 4;
+            reg.size = Some(0x20);
+            reg.access = Some(Access::ReadWrite);
+            reg.reset_value = Some(0);
+            reg.new_field(|field| {
+                field.name = format!("AES_KEYR{}", i + 4);
+                field.description = "Cryptographic key".to_string();
+                field.bit_offset = Some(0);
+                field.bit_width = Some(32);
+            });
+        });
+    }
+    for i in 0..8 {
+        periph.new_reg(|reg| {
+            reg.name = format!("SUSP{}R", i);
+            reg.description = format!("AES suspend register {}", i);
+            reg.address_offset = 0x40 + i * 4;
+            reg.size = Some(0x20);
+            reg.access = Some(Access::ReadWrite);
+            reg.reset_value = Some(0);
+            reg.new_field(|field| {
+                field.name = format!("SUSP{}", i);
+                field.description = "AES suspend".to_string();
+                field.bit_offset = Some(0);
+                field.bit_width = Some(32);
+            });
+        });
+    }
+    Ok(())
+}
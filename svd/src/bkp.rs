@@ -0,0 +1,17 @@
+//! BKP peripheral patches.
+
+use anyhow::Result;
+use drone_svd::Device;
+
+pub fn fix_bkp_1(dev: &mut Device) -> Result<()> {
+    dev.periph("BKP").base_address = 0x4000_6C04;
+    fix_bkp_2(dev)
+}
+
+pub fn fix_bkp_2(dev: &mut Device) -> Result<()> {
+    for i in 11..14 {
+        dev.periph("BKP").reg(&format!("DR{}", i)).field(&format!("DR{}", i)).name =
+            format!("D{}", i);
+    }
+    Ok(())
+}
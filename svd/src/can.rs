@@ -0,0 +1,71 @@
+//! CAN peripheral patches.
+
+use anyhow::Result;
+use drone_svd::{Device, Register};
+
+const CONTROL_REGS: &[&str] = &[
+    "MCR", "MSR", "TSR", "RF0R", "RF1R", "IER", "ESR", "BTR", "TI0R", "TDT0R", "TDL0R", "TDH0R",
+    "TI1R", "TDT1R", "TDL1R", "TDH1R", "TI2R", "TDT2R", "TDL2R", "TDH2R", "RI0R", "RDT0R", "RDL0R",
+    "RDH0R", "RI1R", "RDT1R", "RDL1R", "RDH1R",
+];
+
+const FILTER_REGS: &[&str] = &["FMR", "FM1R", "FS1R", "FFA1R", "FA1R"];
+
+pub fn fix_can1_1(dev: &mut Device) -> Result<()> {
+    fix_filters(dev, "CAN1", "CAN_", 14)?;
+    strip_prefix(dev, "CAN1", CONTROL_REGS);
+    strip_prefix(dev, "CAN1", FILTER_REGS);
+    Ok(())
+}
+
+pub fn fix_can1_2(dev: &mut Device) -> Result<()> {
+    fix_filters(dev, "CAN1", "CAN_", 28)?;
+    strip_prefix(dev, "CAN1", CONTROL_REGS);
+    strip_prefix(dev, "CAN1", FILTER_REGS);
+    Ok(())
+}
+
+pub fn fix_can1_3(dev: &mut Device) -> Result<()> {
+    fix_filters(dev, "CAN1", "", 28)
+}
+
+pub fn fix_can2_1(dev: &mut Device) -> Result<()> {
+    strip_prefix(dev, "CAN2", CONTROL_REGS);
+    Ok(())
+}
+
+fn strip_prefix(dev: &mut Device, periph_name: &str, reg_names: &[&str]) {
+    for reg_name in reg_names {
+        dev.periph(periph_name).reg(&format!("CAN_{}", reg_name)).name = (*reg_name).to_string();
+    }
+}
+
+fn fix_filters(dev: &mut Device, periph_name: &str, prefix: &str, banks: u32) -> Result<()> {
+    let periph = dev.periph(periph_name);
+    merge_bits(periph.reg(&format!("{}FM1R", prefix)), "FBM", banks)?;
+    merge_bits(periph.reg(&format!("{}FS1R", prefix)), "FSC", banks)?;
+    merge_bits(periph.reg(&format!("{}FFA1R", prefix)), "FFA", banks)?;
+    merge_bits(periph.reg(&format!("{}FA1R", prefix)), "FACT", banks)?;
+    for bank in 0..banks {
+        for reg_name in &[format!("F{}R1", bank), format!("F{}R2", bank)] {
+            merge_bits(periph.reg(reg_name), "FB", 32)?;
+        }
+    }
+    Ok(())
+}
+
+fn merge_bits(reg: &mut Register, merged: &str, count: u32) -> Result<()> {
+    let description = reg.field(&format!("{}0", merged)).description.clone();
+    for i in 0..count {
+        let field_name = format!("{}{}", merged, i);
+        assert!(reg.field(&field_name).bit_offset == Some(i));
+        reg.remove_field(&field_name);
+    }
+    reg.new_field(|field| {
+        field.name = merged.to_string();
+        field.description = description;
+        field.bit_offset = Some(0);
+        field.bit_width = Some(count);
+    });
+    Ok(())
+}
@@ -0,0 +1,27 @@
+//! HDMI-CEC peripheral patches.
+
+use anyhow::Result;
+use drone_svd::{Device, Interrupt};
+
+pub fn fix_cec_1(dev: &mut Device) -> Result<()> {
+    let cec = dev.periph("HDMI_CEC");
+    cec.name = "CEC".to_string();
+    for reg_name in &["CR", "CFGR", "TXDR", "RXDR", "ISR", "IER"] {
+        cec.reg(&format!("CEC_{}", reg_name)).name = (*reg_name).to_string();
+    }
+    cec.interrupt.push({
+        let mut interrupt = Interrupt::default();
+        interrupt.name = "CEC".to_string();
+        interrupt.description = "HDMI-CEC global interrupt".to_string();
+        interrupt.value = 93;
+        interrupt
+    });
+    dev.periph("RCC").reg("APB1ENR").field("CEC").name = "CECEN".to_string();
+    dev.periph("RCC").reg("APB1RSTR").new_field(|field| {
+        field.name = "CECRST".to_string();
+        field.description = "CEC reset".to_string();
+        field.bit_offset = Some(27);
+        field.bit_width = Some(1);
+    });
+    Ok(())
+}
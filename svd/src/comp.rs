@@ -0,0 +1,33 @@
+//! COMP peripheral patches.
+
+use anyhow::Result;
+use drone_svd::{Access, Device};
+
+const FIELDS: &[&str] = &[
+    "EN", "PWRMODE", "INMSEL", "INPSEL", "POLARITY", "HYST", "BLANKING", "BRGEN", "SCALEN",
+    "VALUE", "LOCK",
+];
+
+pub fn fix_comp_1(dev: &mut Device) -> Result<()> {
+    strip_prefix(dev, 1, FIELDS);
+    strip_prefix(dev, 2, FIELDS);
+    strip_prefix(dev, 2, &["WINMODE"]);
+    Ok(())
+}
+
+pub fn fix_comp_2(dev: &mut Device) -> Result<()> {
+    strip_prefix(dev, 1, &["INMESEL"]);
+    strip_prefix(dev, 2, &["INMESEL"]);
+    Ok(())
+}
+
+fn strip_prefix(dev: &mut Device, comp: u32, field_names: &[&str]) {
+    let reg = dev.periph("COMP").reg(&format!("COMP{}_CSR", comp));
+    for field_name in field_names {
+        let field = reg.field(&format!("COMP{}_{}", comp, field_name));
+        field.name = (*field_name).to_string();
+        if *field_name == "LOCK" {
+            field.access = Some(Access::ReadWrite);
+        }
+    }
+}
@@ -0,0 +1,29 @@
+//! CRC peripheral patches.
+
+use anyhow::Result;
+use drone_svd::{Access, Device};
+
+pub fn fix_crc_1(dev: &mut Device) -> Result<()> {
+    dev.periph("CRC").reg("CR").access = Some(Access::ReadWrite);
+    Ok(())
+}
+
+pub fn fix_crc_2(dev: &mut Device) -> Result<()> {
+    let cr = dev.periph("CRC").reg("CR");
+    cr.access = Some(Access::ReadWrite);
+    cr.field("CR").name = "RESET".to_string();
+    Ok(())
+}
+
+pub fn fix_crc_3(dev: &mut Device) -> Result<()> {
+    let crc = dev.periph("CRC");
+    crc.reg("CR").field("RESET").access = None;
+    crc.reg("INIT").field("CRC_INIT").name = "INIT".to_string();
+    crc.reg("POL").field("Polynomialcoefficients").name = "POL".to_string();
+    Ok(())
+}
+
+pub fn fix_crc_4(dev: &mut Device) -> Result<()> {
+    dev.periph("RCC").reg("AHB1RSTR").field("CRCRST").bit_offset = Some(12);
+    Ok(())
+}
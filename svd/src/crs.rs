@@ -0,0 +1,33 @@
+//! CRS peripheral patches.
+
+use anyhow::Result;
+use drone_svd::Device;
+
+pub fn fix_crs_1(dev: &mut Device) -> Result<()> {
+    add_crsrst(dev);
+    add_crssmen(dev);
+    Ok(())
+}
+
+pub fn fix_crs_2(dev: &mut Device) -> Result<()> {
+    add_crssmen(dev);
+    Ok(())
+}
+
+fn add_crsrst(dev: &mut Device) {
+    dev.periph("RCC").reg("APB1RSTR1").new_field(|field| {
+        field.name = "CRSRST".to_string();
+        field.description = "CRS reset".to_string();
+        field.bit_offset = Some(24);
+        field.bit_width = Some(1);
+    });
+}
+
+fn add_crssmen(dev: &mut Device) {
+    dev.periph("RCC").reg("APB1SMENR1").new_field(|field| {
+        field.name = "CRSSMEN".to_string();
+        field.description = "CRS clock enable during Sleep and Stop modes".to_string();
+        field.bit_offset = Some(24);
+        field.bit_width = Some(1);
+    });
+}
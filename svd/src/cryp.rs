@@ -0,0 +1,20 @@
+//! CRYP peripheral patches.
+
+use anyhow::Result;
+use drone_svd::Device;
+
+pub fn fix_cryp_1(dev: &mut Device) -> Result<()> {
+    for &(reg_name, field_name, description) in &[
+        ("AHB2RSTR", "CRYPRST", "Cryptographic module reset"),
+        ("AHB2ENR", "CRYPEN", "Cryptographic modules clock enable"),
+        ("AHB2LPENR", "CRYPLPEN", "Cryptography modules clock enable during Sleep mode"),
+    ] {
+        dev.periph("RCC").reg(reg_name).new_field(|field| {
+            field.name = field_name.to_string();
+            field.description = description.to_string();
+            field.bit_offset = Some(4);
+            field.bit_width = Some(1);
+        });
+    }
+    Ok(())
+}
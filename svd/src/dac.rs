@@ -0,0 +1,9 @@
+//! DAC peripheral patches.
+
+use anyhow::Result;
+use drone_svd::Device;
+
+pub fn fix_dac_1(dev: &mut Device) -> Result<()> {
+    dev.periph("DAC1").name = "DAC".to_string();
+    Ok(())
+}
@@ -0,0 +1,22 @@
+//! DFSDM peripheral patches.
+
+use anyhow::Result;
+use drone_svd::Device;
+
+pub fn fix_dfsdm1_1(dev: &mut Device) -> Result<()> {
+    dev.periph("DFSDM").name = "DFSDM1".to_string();
+    fix_rcc(dev);
+    Ok(())
+}
+
+pub fn fix_dfsdm1_2(dev: &mut Device) -> Result<()> {
+    fix_rcc(dev);
+    Ok(())
+}
+
+fn fix_rcc(dev: &mut Device) {
+    let rcc = dev.periph("RCC");
+    rcc.reg("APB2RSTR").field("DFSDMRST").name = "DFSDM1RST".to_string();
+    rcc.reg("APB2ENR").field("DFSDMEN").name = "DFSDM1EN".to_string();
+    rcc.reg("APB2SMENR").field("DFSDMSMEN").name = "DFSDM1SMEN".to_string();
+}
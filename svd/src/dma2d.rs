@@ -0,0 +1,20 @@
+//! DMA2D peripheral patches.
+
+use anyhow::Result;
+use drone_svd::Device;
+
+pub fn fix_dma2d_1(dev: &mut Device) -> Result<()> {
+    for &(reg_name, field_name, description) in &[
+        ("AHB1RSTR", "DMA2DRST", "DMA2D reset"),
+        ("AHB1ENR", "DMA2DEN", "DMA2D clock enable"),
+        ("AHB1LPENR", "DMA2DLPEN", "DMA2D clock enable during Sleep mode"),
+    ] {
+        dev.periph("RCC").reg(reg_name).new_field(|field| {
+            field.name = field_name.to_string();
+            field.description = description.to_string();
+            field.bit_offset = Some(23);
+            field.bit_width = Some(1);
+        });
+    }
+    Ok(())
+}
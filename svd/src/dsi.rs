@@ -0,0 +1,112 @@
+//! DSI Host peripheral patches.
+
+use anyhow::Result;
+use drone_svd::{Device, Interrupt};
+
+const REGS: &[&str] = &[
+    "VR", "CR", "CCR", "LVCIDR", "LCOLCR", "LPCR", "LPMCR", "PCR", "GVCIDR", "MCR", "VMCR", "VPCR",
+    "VCCR", "VNPCR", "VHSACR", "VHBPCR", "VLCR", "VVSACR", "VVBPCR", "VVFPCR", "VVACR", "LCCR",
+    "CMCR", "GHCR", "GPDR", "GPSR", "TCCR0", "TCCR1", "TCCR2", "TCCR3", "TCCR4", "TCCR5", "CLCR",
+    "CLTCR", "DLTCR", "PCTLR", "PCONFR", "PUCR", "PTTCR", "PSR", "ISR0", "ISR1", "IER0", "IER1",
+    "FIR0", "FIR1", "VSCR", "LCVCIDR", "LCCCR", "LPMCCR", "VMCCR", "VPCCR", "VCCCR", "VNPCCR",
+    "VHSACCR", "VHBPCCR", "VLCCR", "VVSACCR", "VVBPCCR", "VVFPCCR", "VVACCR", "WCFGR", "WCR",
+    "WIER", "WISR", "WIFCR", "WPCR1", "WPCR2", "WPCR3", "WPCR4", "WPCR5", "WRPCR",
+];
+
+const L4_OFFSETS: &[(&str, u32)] = &[
+    ("PCR", 0x2C),
+    ("GVCIDR", 0x30),
+    ("MCR", 0x34),
+    ("VMCR", 0x38),
+    ("VPCR", 0x3C),
+    ("VCCR", 0x40),
+    ("VNPCR", 0x44),
+    ("VHSACR", 0x48),
+    ("VHBPCR", 0x4C),
+    ("VLCR", 0x50),
+    ("VVSACR", 0x54),
+    ("VVBPCR", 0x58),
+    ("VVFPCR", 0x5C),
+    ("VVACR", 0x60),
+    ("LCCR", 0x64),
+    ("CMCR", 0x68),
+    ("GHCR", 0x6C),
+    ("GPDR", 0x70),
+    ("GPSR", 0x74),
+    ("TCCR0", 0x78),
+    ("TCCR1", 0x7C),
+    ("TCCR2", 0x80),
+    ("TCCR3", 0x84),
+    ("TCCR4", 0x88),
+    ("TCCR5", 0x8C),
+    ("CLCR", 0x94),
+    ("CLTCR", 0x98),
+    ("DLTCR", 0x9C),
+    ("PCTLR", 0xA0),
+    ("PCONFR", 0xA4),
+    ("PUCR", 0xA8),
+    ("PTTCR", 0xAC),
+    ("PSR", 0xB0),
+    ("ISR0", 0xBC),
+    ("ISR1", 0xC0),
+    ("IER0", 0xC4),
+    ("IER1", 0xC8),
+    ("FIR0", 0xD8),
+    ("FIR1", 0xDC),
+    ("VSCR", 0x100),
+    ("LCVCIDR", 0x10C),
+    ("LCCCR", 0x110),
+    ("LPMCCR", 0x118),
+    ("VMCCR", 0x138),
+    ("VPCCR", 0x13C),
+    ("VCCCR", 0x140),
+    ("VNPCCR", 0x144),
+    ("VHSACCR", 0x148),
+    ("VHBPCCR", 0x14C),
+    ("VLCCR", 0x150),
+    ("VVSACCR", 0x154),
+    ("VVBPCCR", 0x158),
+    ("VVFPCCR", 0x15C),
+    ("VVACCR", 0x160),
+];
+
+pub fn fix_dsi_1(dev: &mut Device) -> Result<()> {
+    let periph = dev.periph("DSIHOST");
+    periph.interrupt.push({
+        let mut interrupt = Interrupt::default();
+        interrupt.name = "DSI".to_string();
+        interrupt.description = "DSI host global interrupt".to_string();
+        interrupt.value = 92;
+        interrupt
+    });
+    for reg_name in REGS {
+        let original = match *reg_name {
+            "CCR" => "DSIHSOT_CCR".to_string(),
+            "PCONFR" => "DSI_PCCONFR".to_string(),
+            "TCCR0" | "TCCR1" | "TCCR2" | "TCCR3" | "TCCR4" | "TCCR5" => {
+                format!("DSI_TCCR{}", reg_name[4..].parse::<u32>()? + 1)
+            }
+            _ => format!("DSI_{}", reg_name),
+        };
+        periph.reg(&original).name = (*reg_name).to_string();
+    }
+    periph.name = "DSI".to_string();
+    Ok(())
+}
+
+pub fn fix_dsi_2(dev: &mut Device) -> Result<()> {
+    let periph = dev.periph("DSI");
+    for reg_name in REGS {
+        let original = if *reg_name == "DLTCR" {
+            "DSI_DLTRC".to_string()
+        } else {
+            format!("DSI_{}", reg_name)
+        };
+        let reg = periph.reg(&original);
+        if let Some(&(_, offset)) = L4_OFFSETS.iter().find(|(name, _)| name == reg_name) {
+            reg.address_offset = offset;
+        }
+        reg.name = (*reg_name).to_string();
+    }
+    Ok(())
+}
@@ -0,0 +1,20 @@
+//! Ethernet peripheral patches.
+
+use anyhow::Result;
+use drone_svd::Device;
+
+pub fn fix_eth_1(dev: &mut Device) -> Result<()> {
+    rename_periphs(dev, "ETHERNET_");
+    Ok(())
+}
+
+pub fn fix_eth_2(dev: &mut Device) -> Result<()> {
+    rename_periphs(dev, "Ethernet_");
+    Ok(())
+}
+
+fn rename_periphs(dev: &mut Device, prefix: &str) {
+    for bank in &["MAC", "MMC", "PTP", "DMA"] {
+        dev.periph(&format!("{}{}", prefix, bank)).name = format!("ETH_{}", bank);
+    }
+}
@@ -0,0 +1,17 @@
+//! FMC peripheral patches.
+
+use anyhow::Result;
+use drone_svd::Device;
+
+pub fn fix_fmc_1(dev: &mut Device) -> Result<()> {
+    dev.periph("FMC").reg("BWTR3").address_offset = 0x114;
+    dev.periph("FMC").reg("BWTR4").address_offset = 0x11C;
+    Ok(())
+}
+
+pub fn fix_fmc_2(dev: &mut Device) -> Result<()> {
+    for reg_name in &["PCR", "SR", "PMEM", "PATT", "ECCR"] {
+        dev.periph("FMC").reg(reg_name).name = format!("{}3", reg_name);
+    }
+    Ok(())
+}
@@ -0,0 +1,14 @@
+//! Firewall peripheral patches.
+
+use anyhow::Result;
+use drone_svd::Device;
+
+pub fn fix_fw(dev: &mut Device) -> Result<()> {
+    dev.periph("RCC").reg("APB2ENR").new_field(|field| {
+        field.name = "FWEN".to_string();
+        field.description = "Firewall clock enable".to_string();
+        field.bit_offset = Some(7);
+        field.bit_width = Some(1);
+    });
+    Ok(())
+}
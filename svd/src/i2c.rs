@@ -1,5 +1,6 @@
 //! I2C peripheral patches.
 
+use crate::parse_svd;
 use anyhow::Result;
 use drone_svd::{Access, Device, Interrupt};
 
@@ -48,6 +49,14 @@ pub fn fix_5(dev: &mut Device) -> Result<()> {
     Ok(())
 }
 
+pub fn add_i2cfmp1(dev: &mut Device) -> Result<()> {
+    let mut f413 = parse_svd("STM32F413.svd")?;
+    fix_5(&mut f413)?;
+    dev.add_periph(f413.periph("I2CFMP1").clone());
+    dev.periph("RCC").reg("DCKCFGR2").field("FMPI2C1SEL").name = "I2CFMP1SEL".to_string();
+    Ok(())
+}
+
 pub fn fix_6(dev: &mut Device) -> Result<()> {
     dev.periph("I2C4").reg("CR1").remove_field("WUPEN");
     Ok(())
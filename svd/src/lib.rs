@@ -4,22 +4,51 @@
 #![allow(clippy::missing_errors_doc)]
 
 pub mod adc;
+pub mod aes;
+pub mod bkp;
+pub mod can;
+pub mod cec;
+pub mod comp;
+pub mod crc;
+pub mod crs;
+pub mod cryp;
+pub mod dac;
+pub mod dfsdm;
 pub mod dma;
+pub mod dma2d;
 pub mod dmamux;
+pub mod dsi;
+pub mod eth;
 pub mod exti;
+pub mod fmc;
+pub mod fw;
 pub mod gpio;
 pub mod i2c;
+pub mod ltdc;
+pub mod octospi;
 pub mod pwr;
+pub mod quadspi;
 pub mod rcc;
 pub mod rtc;
+pub mod sai;
+pub mod sdio;
+pub mod sdmmc;
+pub mod spdifrx;
 pub mod spi;
 pub mod tim;
 pub mod uart;
+pub mod usb;
 
 pub use anyhow::{bail, Result};
 
 use drone_svd::{Config, Device};
-use std::{env, fs::File, path::Path};
+use std::{
+    collections::BTreeMap,
+    env,
+    fs::File,
+    io::{prelude::*, BufWriter},
+    path::Path,
+};
 
 /// Generates code for register mappings.
 pub fn generate_regs(pool_number: usize, pool_size: usize) -> Result<()> {
@@ -37,9 +66,127 @@ pub fn generate_rest() -> Result<()> {
     let dev = svd_deserialize()?;
     let mut reg_output = File::create(out_dir.join("svd_reg_index.rs"))?;
     let mut int_output = File::create(out_dir.join("svd_interrupts.rs"))?;
+    let mut int_periph_output = File::create(out_dir.join("svd_interrupt_periphs.rs"))?;
+    generate_interrupt_periphs(&mut int_periph_output, &dev)?;
     svd_config().generate_rest(&mut reg_output, &mut int_output, dev)
 }
 
+fn generate_interrupt_periphs(output: &mut File, dev: &Device) -> Result<()> {
+    let mut interrupts = BTreeMap::<u32, &str>::new();
+    for periph in dev.peripherals.peripheral.values() {
+        for interrupt in &periph.interrupt {
+            interrupts.entry(interrupt.value).or_insert_with(|| interrupt.name.as_str());
+        }
+    }
+    let periphs = dev
+        .peripherals
+        .peripheral
+        .values()
+        .map(|periph| (periph.name.to_ascii_uppercase(), periph.name.as_str()))
+        .collect::<Vec<_>>();
+    let mut output = BufWriter::new(output);
+    writeln!(output, "/// Interrupt lines with the peripherals they service, sorted by number.")?;
+    writeln!(output, "pub const INTERRUPT_PERIPHS: &[(u32, &str, &[&str])] = &[")?;
+    for (number, name) in interrupts {
+        let periphs = interrupt_periphs(name, &periphs);
+        writeln!(output, "    ({}, {:?}, &{:?}),", number, name, periphs)?;
+    }
+    writeln!(output, "];")?;
+    Ok(())
+}
+
+/// Interrupt names which don't contain the name of the serviced peripheral.
+const INTERRUPT_ALIASES: &[(&str, &str)] = &[
+    ("CRYPTO", "CRYP"),
+    ("DSIHSOT", "DSI"),
+    ("FMPI2C1", "I2CFMP1"),
+    ("I2CFMP1ERROR", "I2CFMP1"),
+    ("I2CFMP1EVENT", "I2CFMP1"),
+    ("LCD", "LTDC"),
+    ("PVD", "PWR"),
+    ("PWM1", "TIM1"),
+    ("RTCALARM", "RTC"),
+    ("SPDIF", "SPDIFRX"),
+    ("TAMP", "RTC"),
+    ("TAMPER", "BKP"),
+    ("USART4", "UART4"),
+    ("USART7", "UART7"),
+    ("USART8", "UART8"),
+    ("USBWAKEUP", "USB"),
+];
+
+/// Returns the peripherals serviced by the interrupt `name`.
+///
+/// ST SVD files often declare an interrupt inside an unrelated peripheral, so
+/// the peripherals are matched against the `_`-separated parts of the
+/// interrupt name instead.
+fn interrupt_periphs<'a>(name: &str, periphs: &[(String, &'a str)]) -> Vec<&'a str> {
+    let name = name.to_ascii_uppercase();
+    let parts = name.split('_').collect::<Vec<_>>();
+    let mut result = Vec::new();
+    let mut i = 0;
+    'parts: while i < parts.len() {
+        for j in (i + 1..=parts.len()).rev() {
+            let mut candidate = parts[i..j].join("_");
+            if j == i + 1 && i > 0 && candidate.bytes().all(|b| b.is_ascii_digit()) {
+                // "ADC1_2" services ADC1 and ADC2.
+                candidate
+                    .insert_str(0, parts[i - 1].trim_end_matches(|c: char| c.is_ascii_digit()));
+            }
+            let matches = match_periphs(&candidate, periphs);
+            if !matches.is_empty() {
+                for periph in matches {
+                    if !result.contains(&periph) {
+                        result.push(periph);
+                    }
+                }
+                i = j;
+                continue 'parts;
+            }
+        }
+        i += 1;
+    }
+    result
+}
+
+fn match_periphs<'a>(candidate: &str, periphs: &[(String, &'a str)]) -> Vec<&'a str> {
+    let find = |predicate: &dyn Fn(&str) -> bool| {
+        periphs
+            .iter()
+            .filter(|(upper, _)| predicate(upper))
+            .map(|&(_, name)| name)
+            .collect::<Vec<_>>()
+    };
+    let exact = find(&|periph| periph == candidate);
+    if !exact.is_empty() {
+        return exact;
+    }
+    if let Some(&(_, alias)) = INTERRUPT_ALIASES.iter().find(|&&(from, _)| from == candidate) {
+        let aliased = find(&|periph| periph == alias);
+        if !aliased.is_empty() {
+            return aliased;
+        }
+    }
+    let numbered = find(&|periph| {
+        periph.len() > candidate.len()
+            && periph.starts_with(candidate)
+            && periph[candidate.len()..].bytes().all(|b| b.is_ascii_digit())
+    });
+    if !numbered.is_empty() {
+        return numbered;
+    }
+    let grouped =
+        find(&|periph| periph.starts_with(candidate) && periph[candidate.len()..].starts_with('_'));
+    if !grouped.is_empty() {
+        return grouped;
+    }
+    let unnumbered = candidate.trim_end_matches(|c: char| c.is_ascii_digit());
+    if unnumbered.len() < candidate.len() && !unnumbered.ends_with('_') {
+        return find(&|periph| periph == unnumbered);
+    }
+    Vec::new()
+}
+
 fn svd_config() -> Config<'static> {
     let mut options = Config::new("stm32_reg_tokens");
     options.bit_band(0x4000_0000..0x4010_0000);
@@ -50,11 +197,11 @@ fn svd_config() -> Config<'static> {
 fn svd_deserialize() -> Result<Device> {
     drone_svd::rerun_if_env_changed();
     match env::var("CARGO_CFG_STM32_MCU")?.as_ref() {
-        "stm32f100" => parse_svd("STM32F100.svd"),
-        "stm32f101" => parse_svd("STM32F101.svd"),
+        "stm32f100" => patch_stm32f100(parse_svd("STM32F100.svd")?),
+        "stm32f101" => patch_stm32f101(parse_svd("STM32F101.svd")?),
         "stm32f102" => patch_stm32f102(parse_svd("STM32F102.svd")?),
-        "stm32f103" => parse_svd("STM32F103.svd"),
-        "stm32f107" => parse_svd("STM32F107.svd"),
+        "stm32f103" => patch_stm32f103(parse_svd("STM32F103.svd")?),
+        "stm32f107" => patch_stm32f107(parse_svd("STM32F107.svd")?),
         "stm32f401" => patch_stm32f401(parse_svd("STM32F401.svd")?),
         "stm32f405" => patch_stm32f405(parse_svd("STM32F405.svd")?),
         "stm32f407" => patch_stm32f407(parse_svd("STM32F407.svd")?),
@@ -81,8 +228,47 @@ fn svd_deserialize() -> Result<Device> {
     }
 }
 
+fn patch_stm32f100(mut dev: Device) -> Result<Device> {
+    crc::fix_crc_1(&mut dev)?;
+    bkp::fix_bkp_2(&mut dev)?;
+    tim::fix_tim1_1(&mut dev)?;
+    tim::fix_tim1_3(&mut dev)?;
+    uart::fix_uart4_5_rst(&mut dev)?;
+    Ok(dev)
+}
+
+fn patch_stm32f101(mut dev: Device) -> Result<Device> {
+    crc::fix_crc_1(&mut dev)?;
+    bkp::fix_bkp_1(&mut dev)?;
+    Ok(dev)
+}
+
 fn patch_stm32f102(mut dev: Device) -> Result<Device> {
     spi::fix_spi2_1(&mut dev)?;
+    usb::fix_usb_1(&mut dev)?;
+    crc::fix_crc_1(&mut dev)?;
+    bkp::fix_bkp_1(&mut dev)?;
+    adc::fix_adc1_4(&mut dev)?;
+    Ok(dev)
+}
+
+fn patch_stm32f103(mut dev: Device) -> Result<Device> {
+    can::fix_can1_1(&mut dev)?;
+    sdio::fix_sdio_1(&mut dev)?;
+    crc::fix_crc_1(&mut dev)?;
+    bkp::fix_bkp_1(&mut dev)?;
+    tim::fix_tim1_1(&mut dev)?;
+    Ok(dev)
+}
+
+fn patch_stm32f107(mut dev: Device) -> Result<Device> {
+    can::fix_can1_2(&mut dev)?;
+    can::fix_can2_1(&mut dev)?;
+    eth::fix_eth_1(&mut dev)?;
+    crc::fix_crc_1(&mut dev)?;
+    bkp::fix_bkp_1(&mut dev)?;
+    tim::fix_tim1_1(&mut dev)?;
+    uart::fix_uart4_5_rst(&mut dev)?;
     Ok(dev)
 }
 
@@ -104,6 +290,10 @@ fn patch_stm32f401(mut dev: Device) -> Result<Device> {
     tim::fix_tim11_2(&mut dev)?;
     adc::fix_adc1_1(&mut dev)?;
     i2c::fix_2(&mut dev)?;
+    crc::fix_crc_2(&mut dev)?;
+    rtc::fix_rtcsel(&mut dev)?;
+    rtc::fix_wucksel(&mut dev)?;
+    uart::fix_usart2_rst(&mut dev)?;
     Ok(dev)
 }
 
@@ -124,6 +314,15 @@ fn patch_stm32f405(mut dev: Device) -> Result<Device> {
     adc::fix_adc_com(&mut dev)?;
     adc::fix_adc1_1(&mut dev)?;
     i2c::fix_2(&mut dev)?;
+    can::fix_can1_3(&mut dev)?;
+    cryp::fix_cryp_1(&mut dev)?;
+    crc::fix_crc_2(&mut dev)?;
+    rtc::fix_rtcsel(&mut dev)?;
+    rtc::fix_tstr(&mut dev)?;
+    rtc::fix_wucksel(&mut dev)?;
+    pwr::add_vos(&mut dev)?;
+    uart::fix_usart2_rst(&mut dev)?;
+    uart::fix_usart3_rst(&mut dev)?;
     Ok(dev)
 }
 
@@ -144,6 +343,16 @@ fn patch_stm32f407(mut dev: Device) -> Result<Device> {
     adc::fix_adc_com(&mut dev)?;
     adc::fix_adc1_1(&mut dev)?;
     i2c::fix_2(&mut dev)?;
+    can::fix_can1_3(&mut dev)?;
+    eth::fix_eth_2(&mut dev)?;
+    cryp::fix_cryp_1(&mut dev)?;
+    crc::fix_crc_2(&mut dev)?;
+    rtc::fix_rtcsel(&mut dev)?;
+    rtc::fix_tstr(&mut dev)?;
+    rtc::fix_wucksel(&mut dev)?;
+    pwr::add_vos(&mut dev)?;
+    uart::fix_usart2_rst(&mut dev)?;
+    uart::fix_usart3_rst(&mut dev)?;
     Ok(dev)
 }
 
@@ -157,6 +366,10 @@ fn patch_stm32f410(mut dev: Device) -> Result<Device> {
     adc::fix_adc1_1(&mut dev)?;
     i2c::fix_3(&mut dev)?;
     i2c::fix_6(&mut dev)?;
+    crc::fix_crc_2(&mut dev)?;
+    rtc::fix_rtcsel(&mut dev)?;
+    rtc::fix_wucksel(&mut dev)?;
+    uart::fix_usart2_rst(&mut dev)?;
     Ok(dev)
 }
 
@@ -174,6 +387,10 @@ fn patch_stm32f411(mut dev: Device) -> Result<Device> {
     tim::fix_tim11_2(&mut dev)?;
     adc::fix_adc1_1(&mut dev)?;
     i2c::fix_2(&mut dev)?;
+    crc::fix_crc_2(&mut dev)?;
+    rtc::fix_rtcsel(&mut dev)?;
+    rtc::fix_wucksel(&mut dev)?;
+    uart::fix_usart2_rst(&mut dev)?;
     Ok(dev)
 }
 
@@ -194,6 +411,11 @@ fn patch_stm32f412(mut dev: Device) -> Result<Device> {
     i2c::fix_2(&mut dev)?;
     i2c::fix_6(&mut dev)?;
     i2c::fix_4(&mut dev)?;
+    quadspi::fix_quadspi_1(&mut dev)?;
+    crc::fix_crc_2(&mut dev)?;
+    rtc::fix_rtcsel(&mut dev)?;
+    rtc::fix_wucksel(&mut dev)?;
+    uart::fix_usart2_rst(&mut dev)?;
     Ok(dev)
 }
 
@@ -213,6 +435,14 @@ fn patch_stm32f413(mut dev: Device) -> Result<Device> {
     rcc::fix_5(&mut dev)?;
     rcc::fix_7(&mut dev)?;
     i2c::fix_5(&mut dev)?;
+    crc::fix_crc_2(&mut dev)?;
+    rtc::fix_rtcsel(&mut dev)?;
+    rtc::fix_rtcapb(&mut dev)?;
+    rtc::fix_wucksel(&mut dev)?;
+    uart::fix_usart2_rst(&mut dev)?;
+    uart::fix_uart4_5_rst(&mut dev)?;
+    uart::fix_uart4_5_lpen(&mut dev)?;
+    sai::fix_sai1_2(&mut dev)?;
     Ok(dev)
 }
 
@@ -232,6 +462,17 @@ fn patch_stm32f427(mut dev: Device) -> Result<Device> {
     tim::fix_tim11_2(&mut dev)?;
     adc::fix_adc_com(&mut dev)?;
     adc::fix_adc1_1(&mut dev)?;
+    can::fix_can1_3(&mut dev)?;
+    eth::fix_eth_2(&mut dev)?;
+    fmc::fix_fmc_1(&mut dev)?;
+    crc::fix_crc_2(&mut dev)?;
+    rtc::fix_rtcsel(&mut dev)?;
+    rtc::fix_tstr(&mut dev)?;
+    rtc::fix_wucksel(&mut dev)?;
+    uart::fix_usart2_rst(&mut dev)?;
+    uart::fix_usart3_rst(&mut dev)?;
+    sai::fix_sai1_1(&mut dev)?;
+    sai::fix_sai1_rcc(&mut dev)?;
     Ok(dev)
 }
 
@@ -251,6 +492,19 @@ fn patch_stm32f429(mut dev: Device) -> Result<Device> {
     tim::fix_tim11_2(&mut dev)?;
     adc::fix_adc_com(&mut dev)?;
     adc::fix_adc1_1(&mut dev)?;
+    can::fix_can1_3(&mut dev)?;
+    eth::fix_eth_2(&mut dev)?;
+    fmc::fix_fmc_1(&mut dev)?;
+    dma2d::fix_dma2d_1(&mut dev)?;
+    ltdc::fix_ltdc_rcc(&mut dev)?;
+    crc::fix_crc_2(&mut dev)?;
+    rtc::fix_rtcsel(&mut dev)?;
+    rtc::fix_tstr(&mut dev)?;
+    rtc::fix_wucksel(&mut dev)?;
+    uart::fix_usart2_rst(&mut dev)?;
+    uart::fix_usart3_rst(&mut dev)?;
+    sai::fix_sai1_2(&mut dev)?;
+    sai::fix_sai1_rcc(&mut dev)?;
     Ok(dev)
 }
 
@@ -267,6 +521,17 @@ fn patch_stm32f446(mut dev: Device) -> Result<Device> {
     tim::fix_tim11_1(&mut dev)?;
     adc::fix_adc_com(&mut dev)?;
     adc::fix_adc1_1(&mut dev)?;
+    can::fix_can1_3(&mut dev)?;
+    crc::fix_crc_2(&mut dev)?;
+    rtc::fix_tstr(&mut dev)?;
+    rtc::fix_wucksel(&mut dev)?;
+    cec::fix_cec_1(&mut dev)?;
+    spdifrx::fix_spdifrx(&mut dev)?;
+    i2c::add_i2cfmp1(&mut dev)?;
+    uart::fix_usart2_rst(&mut dev)?;
+    uart::fix_usart3_rst(&mut dev)?;
+    sai::fix_sai1_1(&mut dev)?;
+    sdio::fix_sdio_2(&mut dev)?;
     Ok(dev)
 }
 
@@ -283,6 +548,17 @@ fn patch_stm32f469(mut dev: Device) -> Result<Device> {
     tim::fix_tim11_1(&mut dev)?;
     adc::fix_adc_com(&mut dev)?;
     adc::fix_adc1_1(&mut dev)?;
+    can::fix_can1_3(&mut dev)?;
+    eth::fix_eth_2(&mut dev)?;
+    fmc::fix_fmc_2(&mut dev)?;
+    dsi::fix_dsi_1(&mut dev)?;
+    crc::fix_crc_2(&mut dev)?;
+    rtc::fix_rtcsel(&mut dev)?;
+    rtc::fix_tstr(&mut dev)?;
+    rtc::fix_wucksel(&mut dev)?;
+    uart::fix_usart2_rst(&mut dev)?;
+    uart::fix_usart3_rst(&mut dev)?;
+    sai::fix_sai1_2(&mut dev)?;
     Ok(dev)
 }
 
@@ -306,6 +582,20 @@ fn patch_stm32l4x1(mut dev: Device) -> Result<Device> {
     uart::fix_usart1_1(&mut dev)?;
     uart::fix_usart1_2(&mut dev)?;
     uart::fix_usart3(&mut dev)?;
+    sdmmc::fix_sdmmc1_2(&mut dev)?;
+    crc::fix_crc_3(&mut dev)?;
+    crc::fix_crc_4(&mut dev)?;
+    dac::fix_dac_1(&mut dev)?;
+    comp::fix_comp_1(&mut dev)?;
+    comp::fix_comp_2(&mut dev)?;
+    sai::fix_sai1_1(&mut dev)?;
+    fw::fix_fw(&mut dev)?;
+    rtc::add_itsf(&mut dev)?;
+    rtc::fix_wucksel(&mut dev)?;
+    pwr::fix_wakeup_flags(&mut dev)?;
+    spi::fix_spi1_1(&mut dev)?;
+    adc::add_adc_com(&mut dev)?;
+    adc::fix_adc_2(&mut dev)?;
     Ok(dev)
 }
 
@@ -330,6 +620,22 @@ fn patch_stm32l4x2(mut dev: Device) -> Result<Device> {
     uart::fix_usart1_1(&mut dev)?;
     uart::fix_usart1_2(&mut dev)?;
     uart::fix_usart3(&mut dev)?;
+    usb::fix_usb_2(&mut dev)?;
+    sdmmc::fix_sdmmc1_2(&mut dev)?;
+    aes::fix_aes(&mut dev)?;
+    crc::fix_crc_3(&mut dev)?;
+    crc::fix_crc_4(&mut dev)?;
+    dac::fix_dac_1(&mut dev)?;
+    comp::fix_comp_1(&mut dev)?;
+    comp::fix_comp_2(&mut dev)?;
+    sai::fix_sai1_1(&mut dev)?;
+    fw::fix_fw(&mut dev)?;
+    rtc::add_itsf(&mut dev)?;
+    rtc::fix_wucksel(&mut dev)?;
+    pwr::fix_wakeup_flags(&mut dev)?;
+    spi::fix_spi1_1(&mut dev)?;
+    adc::add_adc_com(&mut dev)?;
+    adc::fix_adc_2(&mut dev)?;
     Ok(dev)
 }
 
@@ -349,6 +655,22 @@ fn patch_stm32l4x3(mut dev: Device) -> Result<Device> {
     tim::fix_tim3_1(&mut dev)?;
     tim::fix_tim3_2(&mut dev)?;
     uart::fix_usart1_2(&mut dev)?;
+    usb::fix_usb_3(&mut dev)?;
+    sdmmc::fix_sdmmc1_2(&mut dev)?;
+    aes::fix_aes(&mut dev)?;
+    crc::fix_crc_3(&mut dev)?;
+    crc::fix_crc_4(&mut dev)?;
+    dac::fix_dac_1(&mut dev)?;
+    comp::fix_comp_1(&mut dev)?;
+    sai::fix_sai1_1(&mut dev)?;
+    crs::fix_crs_1(&mut dev)?;
+    fw::fix_fw(&mut dev)?;
+    rtc::add_itsf(&mut dev)?;
+    rtc::fix_wucksel(&mut dev)?;
+    pwr::fix_wakeup_flags(&mut dev)?;
+    spi::fix_spi1_1(&mut dev)?;
+    adc::add_adc_com(&mut dev)?;
+    adc::fix_adc_2(&mut dev)?;
     Ok(dev)
 }
 
@@ -370,6 +692,21 @@ fn patch_stm32l4x5(mut dev: Device) -> Result<Device> {
     tim::fix_tim8(&mut dev)?;
     gpio::add_ascr(&mut dev)?;
     uart::fix_usart1_2(&mut dev)?;
+    sdmmc::fix_sdmmc1_2(&mut dev)?;
+    fmc::fix_fmc_2(&mut dev)?;
+    crc::fix_crc_3(&mut dev)?;
+    crc::fix_crc_4(&mut dev)?;
+    dac::fix_dac_1(&mut dev)?;
+    comp::fix_comp_1(&mut dev)?;
+    dfsdm::fix_dfsdm1_1(&mut dev)?;
+    sai::fix_sai1_1(&mut dev)?;
+    fw::fix_fw(&mut dev)?;
+    rtc::add_itsf(&mut dev)?;
+    rtc::fix_wucksel(&mut dev)?;
+    pwr::fix_wakeup_flags(&mut dev)?;
+    spi::fix_spi1_1(&mut dev)?;
+    adc::fix_adc_com_2(&mut dev)?;
+    adc::fix_adc1_2(&mut dev)?;
     Ok(dev)
 }
 
@@ -389,6 +726,21 @@ fn patch_stm32l4x6(mut dev: Device) -> Result<Device> {
     tim::fix_tim3_1(&mut dev)?;
     tim::fix_tim8(&mut dev)?;
     uart::fix_usart1_2(&mut dev)?;
+    can::fix_can1_3(&mut dev)?;
+    sdmmc::fix_sdmmc1_1(&mut dev)?;
+    fmc::fix_fmc_2(&mut dev)?;
+    aes::fix_aes(&mut dev)?;
+    crc::fix_crc_3(&mut dev)?;
+    comp::fix_comp_1(&mut dev)?;
+    dfsdm::fix_dfsdm1_2(&mut dev)?;
+    sai::fix_sai1_1(&mut dev)?;
+    crs::fix_crs_2(&mut dev)?;
+    fw::fix_fw(&mut dev)?;
+    rtc::add_itsf(&mut dev)?;
+    rtc::fix_wucksel(&mut dev)?;
+    pwr::fix_wakeup_flags(&mut dev)?;
+    spi::fix_spi1_1(&mut dev)?;
+    adc::fix_adc1_3(&mut dev)?;
     Ok(dev)
 }
 
@@ -410,6 +762,19 @@ fn patch_stm32l4plus(mut dev: Device) -> Result<Device> {
     tim::fix_tim8(&mut dev)?;
     adc::fix_adc_1(&mut dev)?;
     uart::fix_usart1_2(&mut dev)?;
+    fmc::fix_fmc_2(&mut dev)?;
+    octospi::fix_octospi(&mut dev)?;
+    ltdc::fix_ltdc(&mut dev)?;
+    dsi::fix_dsi_2(&mut dev)?;
+    aes::fix_aes(&mut dev)?;
+    crc::fix_crc_3(&mut dev)?;
+    comp::fix_comp_1(&mut dev)?;
+    sai::fix_sai1_1(&mut dev)?;
+    sai::add_sai1_pdm(&mut dev)?;
+    rtc::add_itsf(&mut dev)?;
+    rtc::fix_wucksel(&mut dev)?;
+    pwr::fix_wakeup_flags(&mut dev)?;
+    spi::fix_spi1_1(&mut dev)?;
     Ok(dev)
 }
 
@@ -0,0 +1,25 @@
+//! LTDC peripheral patches.
+
+use anyhow::Result;
+use drone_svd::Device;
+
+pub fn fix_ltdc(dev: &mut Device) -> Result<()> {
+    dev.periph("LTCD").name = "LTDC".to_string();
+    Ok(())
+}
+
+pub fn fix_ltdc_rcc(dev: &mut Device) -> Result<()> {
+    for &(reg_name, field_name, description) in &[
+        ("APB2RSTR", "LTDCRST", "LTDC reset"),
+        ("APB2ENR", "LTDCEN", "LTDC clock enable"),
+        ("APB2LPENR", "LTDCLPEN", "LTDC clock enable during Sleep mode"),
+    ] {
+        dev.periph("RCC").reg(reg_name).new_field(|field| {
+            field.name = field_name.to_string();
+            field.description = description.to_string();
+            field.bit_offset = Some(26);
+            field.bit_width = Some(1);
+        });
+    }
+    Ok(())
+}
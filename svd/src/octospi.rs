@@ -0,0 +1,22 @@
+//! OCTOSPI peripheral patches.
+
+use anyhow::Result;
+use drone_svd::{Access, Device};
+
+pub fn fix_octospi(dev: &mut Device) -> Result<()> {
+    for &(reg_name, field_name, description) in &[
+        ("AHB3RSTR", "OSPI1RST", "OSPI1 memory interface reset"),
+        ("AHB3ENR", "OSPI1EN", "OSPI1 memory interface clock enable"),
+        ("AHB3SMENR", "OSPI1SMEN", "OSPI1 clocks enable during Sleep and Stop modes"),
+    ] {
+        dev.periph("RCC").reg(reg_name).new_field(|field| {
+            field.name = field_name.to_string();
+            field.description = description.to_string();
+            field.bit_offset = Some(8);
+            field.bit_width = Some(1);
+        });
+    }
+    dev.periph("RCC").reg("AHB3SMENR").field("OCTOSPI2").name = "OSPI2SMEN".to_string();
+    dev.periph("OCTOSPI1").reg("SR").access = Some(Access::ReadOnly);
+    Ok(())
+}
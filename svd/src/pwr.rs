@@ -12,3 +12,23 @@ pub fn fix(dev: &mut Device) -> Result<()> {
     });
     Ok(())
 }
+
+pub fn fix_wakeup_flags(dev: &mut Device) -> Result<()> {
+    for i in 1..=5 {
+        dev.periph("PWR").reg("SR1").field(&format!("CWUF{}", i)).name = format!("WUF{}", i);
+        dev.periph("PWR").reg("SCR").field(&format!("WUF{}", i)).name = format!("CWUF{}", i);
+    }
+    dev.periph("PWR").reg("SR1").field("CSBF").name = "SBF".to_string();
+    dev.periph("PWR").reg("SCR").field("SBF").name = "CSBF".to_string();
+    Ok(())
+}
+
+pub fn add_vos(dev: &mut Device) -> Result<()> {
+    dev.periph("PWR").reg("CR").new_field(|field| {
+        field.name = "VOS".to_string();
+        field.description = "Regulator voltage scaling output selection".to_string();
+        field.bit_offset = Some(14);
+        field.bit_width = Some(1);
+    });
+    Ok(())
+}
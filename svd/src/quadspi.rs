@@ -0,0 +1,38 @@
+//! QUADSPI peripheral patches.
+
+use anyhow::Result;
+use drone_svd::{Access, Device};
+
+pub fn fix_quadspi_1(dev: &mut Device) -> Result<()> {
+    for &(name, description, offset, reset, suffix) in &[
+        ("AHB3RSTR", "AHB3 peripheral reset register", 0x18, 0x0000, "RST"),
+        ("AHB3ENR", "AHB3 peripheral clock enable register", 0x38, 0x0000, "EN"),
+        (
+            "AHB3LPENR",
+            "AHB3 peripheral clock enable in low power mode register",
+            0x58,
+            0x0003,
+            "LPEN",
+        ),
+    ] {
+        dev.periph("RCC").new_reg(|reg| {
+            reg.name = name.to_string();
+            reg.description = description.to_string();
+            reg.address_offset = offset;
+            reg.size = Some(0x20);
+            reg.access = Some(Access::ReadWrite);
+            reg.reset_value = Some(reset);
+            for &(field_name, field_description, bit_offset) in
+                &[("FSMC", "Flexible static memory controller", 0), ("QSPI", "QUADSPI", 1)]
+            {
+                reg.new_field(|field| {
+                    field.name = format!("{}{}", field_name, suffix);
+                    field.description = field_description.to_string();
+                    field.bit_offset = Some(bit_offset);
+                    field.bit_width = Some(1);
+                });
+            }
+        });
+    }
+    Ok(())
+}
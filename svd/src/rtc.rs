@@ -1,7 +1,7 @@
 //! RTC peripheral patches.
 
 use anyhow::Result;
-use drone_svd::Device;
+use drone_svd::{Access, Device};
 
 pub fn fix(dev: &mut Device) -> Result<()> {
     dev.periph("RCC").reg("APB1ENR1").new_field(|field| {
@@ -18,3 +18,43 @@ pub fn fix(dev: &mut Device) -> Result<()> {
     });
     Ok(())
 }
+
+pub fn fix_rtcsel(dev: &mut Device) -> Result<()> {
+    dev.periph("RCC").reg("BDCR").remove_field("RTCSEL1");
+    dev.periph("RCC").reg("BDCR").field("RTCSEL0").bit_width = Some(2);
+    dev.periph("RCC").reg("BDCR").field("RTCSEL0").name = "RTCSEL".to_string();
+    Ok(())
+}
+
+pub fn fix_rtcapb(dev: &mut Device) -> Result<()> {
+    dev.periph("RCC").reg("APB1LPENR").field("RTCAPBEN").name = "RTCAPBLPEN".to_string();
+    dev.periph("RCC").reg("APB1ENR").field("RTCAPB").name = "RTCAPBEN".to_string();
+    Ok(())
+}
+
+pub fn fix_tstr(dev: &mut Device) -> Result<()> {
+    for field_name in &["ALARMOUTTYPE", "TSINSEL", "TAMP1INSEL", "TAMPIE", "TAMP1TRG", "TAMP1E"] {
+        dev.periph("RTC").reg("TSTR").remove_field(field_name);
+    }
+    for field_name in &["SU", "ST", "MNU", "MNT", "HU", "HT", "PM"] {
+        let field = dev.periph("RTC").reg("TR").field(field_name).clone();
+        dev.periph("RTC").reg("TSTR").add_field(field);
+    }
+    Ok(())
+}
+
+pub fn add_itsf(dev: &mut Device) -> Result<()> {
+    dev.periph("RTC").reg("ISR").new_field(|field| {
+        field.name = "ITSF".to_string();
+        field.description = "Internal time-stamp flag".to_string();
+        field.bit_offset = Some(17);
+        field.bit_width = Some(1);
+        field.access = Some(Access::ReadWrite);
+    });
+    Ok(())
+}
+
+pub fn fix_wucksel(dev: &mut Device) -> Result<()> {
+    dev.periph("RTC").reg("CR").field("WCKSEL").name = "WUCKSEL".to_string();
+    Ok(())
+}
@@ -0,0 +1,102 @@
+//! SAI peripheral patches.
+
+use anyhow::Result;
+use drone_svd::{Access, Device};
+
+pub fn fix_sai1_1(dev: &mut Device) -> Result<()> {
+    fix_blocks(dev, "SAI1");
+    Ok(())
+}
+
+pub fn fix_sai1_2(dev: &mut Device) -> Result<()> {
+    fix_blocks(dev, "SAI");
+    dev.periph("SAI").name = "SAI1".to_string();
+    Ok(())
+}
+
+pub fn fix_sai1_rcc(dev: &mut Device) -> Result<()> {
+    for &(reg_name, field_name, description) in &[
+        ("APB2RSTR", "SAI1RST", "SAI1 reset"),
+        ("APB2ENR", "SAI1EN", "SAI1 clock enable"),
+        ("APB2LPENR", "SAI1LPEN", "SAI1 clock enable during Sleep mode"),
+    ] {
+        dev.periph("RCC").reg(reg_name).new_field(|field| {
+            field.name = field_name.to_string();
+            field.description = description.to_string();
+            field.bit_offset = Some(22);
+            field.bit_width = Some(1);
+        });
+    }
+    Ok(())
+}
+
+fn fix_blocks(dev: &mut Device, periph_name: &str) {
+    let periph = dev.periph(periph_name);
+    periph.reg("ACR1").field("SAIAEN").name = "SAIEN".to_string();
+    periph.reg("BCR1").field("SAIBEN").name = "SAIEN".to_string();
+    periph.reg("AIM").field("LFSDET").name = "LFSDETIE".to_string();
+    periph.reg("ASR").access = Some(Access::ReadOnly);
+    periph.reg("ACLRFR").access = Some(Access::WriteOnly);
+    for block in &["A", "B"] {
+        let cr1 = periph.reg(&format!("{}CR1", block));
+        cr1.field("MCJDIV").name = "MCKDIV".to_string();
+        cr1.field("OutDri").name = "OUTDRIV".to_string();
+        let cr2 = periph.reg(&format!("{}CR2", block));
+        cr2.field("FFLUS").name = "FFLUSH".to_string();
+        cr2.field("MUTECN").name = "MUTECNT".to_string();
+        let im = periph.reg(&format!("{}IM", block));
+        im.field("WCKCFG").name = "WCKCFGIE".to_string();
+        im.field("MUTEDET").name = "MUTEDETIE".to_string();
+        let clrfr = periph.reg(&format!("{}CLRFR", block));
+        for field_name in &["LFSDET", "CNRDY", "WCKCFG", "MUTEDET", "OVRUDR"] {
+            clrfr.field(field_name).name = format!("C{}", field_name);
+        }
+    }
+}
+
+pub fn add_sai1_pdm(dev: &mut Device) -> Result<()> {
+    let periph = dev.periph("SAI1");
+    periph.new_reg(|reg| {
+        reg.name = "PDMCR".to_string();
+        reg.description = "PDM control register".to_string();
+        reg.address_offset = 0x44;
+        reg.size = Some(0x20);
+        reg.access = Some(Access::ReadWrite);
+        reg.reset_value = Some(0);
+        for &(name, description, offset, width) in &[
+            ("PDMEN", "PDM enable", 0, 1),
+            ("MICNBR", "Number of microphones", 4, 2),
+            ("CKEN1", "Clock enable of bitstream clock number 1", 8, 1),
+            ("CKEN2", "Clock enable of bitstream clock number 2", 9, 1),
+            ("CKEN3", "Clock enable of bitstream clock number 3", 10, 1),
+            ("CKEN4", "Clock enable of bitstream clock number 4", 11, 1),
+        ] {
+            reg.new_field(|field| {
+                field.name = name.to_string();
+                field.description = description.to_string();
+                field.bit_offset = Some(offset);
+                field.bit_width = Some(width);
+            });
+        }
+    });
+    periph.new_reg(|reg| {
+        reg.name = "PDMDLY".to_string();
+        reg.description = "PDM delay register".to_string();
+        reg.address_offset = 0x48;
+        reg.size = Some(0x20);
+        reg.access = Some(Access::ReadWrite);
+        reg.reset_value = Some(0);
+        for i in 0..4 {
+            for &(suffix, side, shift) in &[("L", "left", 0), ("R", "right", 4)] {
+                reg.new_field(|field| {
+                    field.name = format!("DLYM{}{}", i + 1, suffix);
+                    field.description =
+                        format!("Delay line adjust for the {} microphone of pair {}", side, i + 1);
+                    field.bit_offset = Some(i * 8 + shift);
+                    field.bit_width = Some(3);
+                });
+            }
+        }
+    });
+    Ok(())
+}
@@ -0,0 +1,21 @@
+//! SDIO peripheral patches.
+
+use crate::parse_svd;
+use anyhow::Result;
+use drone_svd::Device;
+
+pub fn fix_sdio_1(dev: &mut Device) -> Result<()> {
+    dev.periph("SDIO").reg("DCTRL").field("PWSTART").name = "RWSTART".to_string();
+    dev.periph("SDIO").reg("DCTRL").field("PWSTOP").name = "RWSTOP".to_string();
+    dev.periph("SDIO").reg("MASK").field("CEATENDIE").name = "CEATAENDIE".to_string();
+    dev.periph("SDIO").reg("FIFOCNT").field("FIF0COUNT").name = "FIFOCOUNT".to_string();
+    dev.periph("SDIO").reg("RESPI1").name = "RESP1".to_string();
+    Ok(())
+}
+
+pub fn fix_sdio_2(dev: &mut Device) -> Result<()> {
+    let mut f429 = parse_svd("STM32F429.svd")?;
+    dev.peripherals.peripheral.remove("SDMMC");
+    dev.add_periph(f429.periph("SDIO").clone());
+    Ok(())
+}
@@ -0,0 +1,17 @@
+//! SDMMC peripheral patches.
+
+use anyhow::Result;
+use drone_svd::Device;
+
+pub fn fix_sdmmc1_1(dev: &mut Device) -> Result<()> {
+    dev.periph("RCC").reg("APB2ENR").field("SDMMCEN").name = "SDMMC1EN".to_string();
+    dev.periph("RCC").reg("APB2RSTR").field("SDMMCRST").name = "SDMMC1RST".to_string();
+    dev.periph("RCC").reg("APB2SMENR").field("SDMMCSMEN").name = "SDMMC1SMEN".to_string();
+    Ok(())
+}
+
+pub fn fix_sdmmc1_2(dev: &mut Device) -> Result<()> {
+    fix_sdmmc1_1(dev)?;
+    dev.periph("SDMMC").name = "SDMMC1".to_string();
+    Ok(())
+}
@@ -0,0 +1,9 @@
+//! SPDIFRX peripheral patches.
+
+use anyhow::Result;
+use drone_svd::Device;
+
+pub fn fix_spdifrx(dev: &mut Device) -> Result<()> {
+    dev.periph("SPDIF_RX").name = "SPDIFRX".to_string();
+    Ok(())
+}
@@ -42,3 +42,10 @@ pub fn fix_spi3_2(dev: &mut Device) -> Result<()> {
     dev.periph("RCC").reg("APB1SMENR1").field("SP3SMEN").name = "SPI3SMEN".to_string();
     Ok(())
 }
+
+pub fn fix_spi1_1(dev: &mut Device) -> Result<()> {
+    let field = dev.periph("SPI1").reg("CR1").field("DFF");
+    field.name = "CRCL".to_string();
+    field.description = "CRC length".to_string();
+    Ok(())
+}
@@ -15,6 +15,12 @@ pub fn fix_tim1_2(dev: &mut Device) -> Result<()> {
     Ok(())
 }
 
+pub fn fix_tim1_3(dev: &mut Device) -> Result<()> {
+    dev.periph("TIM1").reg("CCMR1_Input").field("IC2F").bit_offset = Some(12);
+    dev.periph("TIM1").reg("CCMR2_Input").field("IC4F").bit_offset = Some(12);
+    Ok(())
+}
+
 pub fn fix_tim2_1(dev: &mut Device) -> Result<()> {
     add_third_bit(dev, "TIM2", "SMCR", "SMS", 16);
     add_third_bit(dev, "TIM2", "CCMR1_Output", "OC1M", 16);
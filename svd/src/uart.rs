@@ -64,3 +64,25 @@ pub fn fix_lpuart1(dev: &mut Device) -> Result<()> {
     copy_field(dev, "USART3", "LPUART1", "CR3", "UCESM");
     Ok(())
 }
+
+pub fn fix_usart2_rst(dev: &mut Device) -> Result<()> {
+    dev.periph("RCC").reg("APB1RSTR").field("UART2RST").name = "USART2RST".to_string();
+    Ok(())
+}
+
+pub fn fix_usart3_rst(dev: &mut Device) -> Result<()> {
+    dev.periph("RCC").reg("APB1RSTR").field("UART3RST").name = "USART3RST".to_string();
+    Ok(())
+}
+
+pub fn fix_uart4_5_rst(dev: &mut Device) -> Result<()> {
+    dev.periph("RCC").reg("APB1RSTR").field("USART4RST").name = "UART4RST".to_string();
+    dev.periph("RCC").reg("APB1RSTR").field("USART5RST").name = "UART5RST".to_string();
+    Ok(())
+}
+
+pub fn fix_uart4_5_lpen(dev: &mut Device) -> Result<()> {
+    dev.periph("RCC").reg("APB1LPENR").field("USART4LPEN").name = "UART4LPEN".to_string();
+    dev.periph("RCC").reg("APB1LPENR").field("USART5LPEN").name = "UART5LPEN".to_string();
+    Ok(())
+}
@@ -0,0 +1,92 @@
+//! USB peripheral patches.
+
+use anyhow::Result;
+use drone_svd::{Access, Device, Interrupt};
+
+pub fn fix_usb_1(dev: &mut Device) -> Result<()> {
+    dev.periph("RCC").reg("APB1ENR").new_field(|field| {
+        field.name = "USBEN".to_string();
+        field.description = "USB clock enable".to_string();
+        field.bit_offset = Some(23);
+        field.bit_width = Some(1);
+    });
+    dev.periph("RCC").reg("APB1RSTR").new_field(|field| {
+        field.name = "USBRST".to_string();
+        field.description = "USB reset".to_string();
+        field.bit_offset = Some(23);
+        field.bit_width = Some(1);
+    });
+    Ok(())
+}
+
+pub fn fix_usb_2(dev: &mut Device) -> Result<()> {
+    dev.periph("RCC").reg("APB1ENR1").field("USBF").name = "USBFSEN".to_string();
+    Ok(())
+}
+
+pub fn fix_usb_3(dev: &mut Device) -> Result<()> {
+    dev.periph("RCC").reg("APB1ENR1").field("USBF").name = "USBFSEN".to_string();
+    dev.periph("RCC").reg("APB1RSTR1").new_field(|field| {
+        field.name = "USBFSRST".to_string();
+        field.description = "USB FS reset".to_string();
+        field.bit_offset = Some(26);
+        field.bit_width = Some(1);
+    });
+    dev.periph("USB").base_address = 0x4000_6800;
+    dev.periph("USB").interrupt.push({
+        let mut interrupt = Interrupt::default();
+        interrupt.name = "USB_FS".to_string();
+        interrupt.description = "USB FS global interrupt".to_string();
+        interrupt.value = 67;
+        interrupt
+    });
+    dev.periph("USB").new_reg(|reg| {
+        reg.name = "LPMCSR".to_string();
+        reg.description = "LPM control and status register".to_string();
+        reg.address_offset = 0x54;
+        reg.size = Some(0x20);
+        reg.reset_value = Some(0x0000);
+        for &(name, description, offset, width, access) in &[
+            ("LPMEN", "LPM support enable", 0, 1, Access::ReadWrite),
+            ("LPMACK", "LPM token acknowledge enable", 1, 1, Access::ReadWrite),
+            ("REMWAKE", "bRemoteWake value", 3, 1, Access::ReadOnly),
+            ("BESL", "BESL value", 4, 4, Access::ReadOnly),
+        ] {
+            reg.new_field(|field| {
+                field.name = name.to_string();
+                field.description = description.to_string();
+                field.bit_offset = Some(offset);
+                field.bit_width = Some(width);
+                field.access = Some(access);
+            });
+        }
+    });
+    dev.periph("USB").new_reg(|reg| {
+        reg.name = "BCDR".to_string();
+        reg.description = "Battery charging detector".to_string();
+        reg.address_offset = 0x58;
+        reg.size = Some(0x20);
+        reg.reset_value = Some(0x0000);
+        for &(name, description, offset, access) in &[
+            ("BCDEN", "Battery charging detector (BCD) enable", 0, Access::ReadWrite),
+            ("DCDEN", "Data contact detection (DCD) mode enable", 1, Access::ReadWrite),
+            ("PDEN", "Primary detection (PD) mode enable", 2, Access::ReadWrite),
+            ("SDEN", "Secondary detection (SD) mode enable", 3, Access::ReadWrite),
+            ("DCDET", "Data contact detection (DCD) status", 4, Access::ReadOnly),
+            ("PDET", "Primary detection (PD) status", 5, Access::ReadOnly),
+            ("SDET", "Secondary detection (SD) status", 6, Access::ReadOnly),
+            ("PS2DET", "DM pull-up detection status", 7, Access::ReadOnly),
+            ("DPPU", "DP pull-up control", 15, Access::ReadWrite),
+        ] {
+            reg.new_field(|field| {
+                field.name = name.to_string();
+                field.description = description.to_string();
+                field.bit_offset = Some(offset);
+                field.bit_width = Some(1);
+                field.access = Some(access);
+            });
+        }
+    });
+    dev.periph("USB").name = "USB_FS".to_string();
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use drone_stm32_map::thr::INTERRUPT_PERIPHS;
+
+fn interrupt_periphs(interrupt: &str) -> &'static [&'static str] {
+    INTERRUPT_PERIPHS
+        .iter()
+        .find(|(_, name, _)| *name == interrupt)
+        .map(|(_, _, periphs)| *periphs)
+        .unwrap_or_else(|| panic!("interrupt {} not found", interrupt))
+}
+
+#[test]
+fn interrupt_periphs_by_name() {
+    assert_eq!(interrupt_periphs("RCC"), ["RCC"]);
+    assert_eq!(interrupt_periphs("FLASH"), ["FLASH"]);
+    assert_eq!(interrupt_periphs("EXTI0"), ["EXTI"]);
+    assert_eq!(interrupt_periphs("SPI1"), ["SPI1"]);
+    #[cfg(not(stm32_mcu = "stm32f410"))]
+    assert_eq!(interrupt_periphs("TIM2"), ["TIM2"]);
+    #[cfg(not(stm32_mcu = "stm32f413"))]
+    assert_eq!(interrupt_periphs("I2C1_EV"), ["I2C1"]);
+    #[cfg(any(
+        stm32_mcu = "stm32f401",
+        stm32_mcu = "stm32f405",
+        stm32_mcu = "stm32f407",
+        stm32_mcu = "stm32f411",
+        stm32_mcu = "stm32f412",
+        stm32_mcu = "stm32f413",
+        stm32_mcu = "stm32f427",
+        stm32_mcu = "stm32f429",
+        stm32_mcu = "stm32f446",
+        stm32_mcu = "stm32f469",
+    ))]
+    assert_eq!(interrupt_periphs("TIM1_UP_TIM10"), ["TIM1", "TIM10"]);
+}
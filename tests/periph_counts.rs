@@ -0,0 +1,507 @@
+use drone_core::token::Token;
+use drone_stm32_map::stm32_reg_tokens;
+
+stm32_reg_tokens! {
+    struct Regs;
+}
+
+#[test]
+#[cfg(feature = "dma")]
+#[allow(unused_mut, unused_variables)]
+fn dma_ch_count() {
+    let reg = unsafe { Regs::take() };
+    let mut dma1_count = 0;
+    let mut dma2_count = 0;
+    #[cfg(all(
+        feature = "dma",
+        any(
+            stm32_mcu = "stm32f401",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f410",
+            stm32_mcu = "stm32f411",
+            stm32_mcu = "stm32f412",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469",
+        )
+    ))]
+    {
+        let dma1_ch0 = drone_stm32_map::periph::dma::periph_dma1_ch0!(reg);
+        let dma2_ch0 = drone_stm32_map::periph::dma::periph_dma2_ch0!(reg);
+        dma1_count += 1;
+        dma2_count += 1;
+    }
+    #[cfg(all(
+        feature = "dma",
+        any(
+            stm32_mcu = "stm32f100",
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f102",
+            stm32_mcu = "stm32f103",
+            stm32_mcu = "stm32f107",
+            stm32_mcu = "stm32f401",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f410",
+            stm32_mcu = "stm32f411",
+            stm32_mcu = "stm32f412",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469",
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
+        )
+    ))]
+    {
+        let dma1_ch1 = drone_stm32_map::periph::dma::periph_dma1_ch1!(reg);
+        let dma1_ch2 = drone_stm32_map::periph::dma::periph_dma1_ch2!(reg);
+        let dma1_ch3 = drone_stm32_map::periph::dma::periph_dma1_ch3!(reg);
+        let dma1_ch4 = drone_stm32_map::periph::dma::periph_dma1_ch4!(reg);
+        let dma1_ch5 = drone_stm32_map::periph::dma::periph_dma1_ch5!(reg);
+        let dma1_ch6 = drone_stm32_map::periph::dma::periph_dma1_ch6!(reg);
+        let dma1_ch7 = drone_stm32_map::periph::dma::periph_dma1_ch7!(reg);
+        let dma2_ch1 = drone_stm32_map::periph::dma::periph_dma2_ch1!(reg);
+        let dma2_ch2 = drone_stm32_map::periph::dma::periph_dma2_ch2!(reg);
+        let dma2_ch3 = drone_stm32_map::periph::dma::periph_dma2_ch3!(reg);
+        let dma2_ch4 = drone_stm32_map::periph::dma::periph_dma2_ch4!(reg);
+        let dma2_ch5 = drone_stm32_map::periph::dma::periph_dma2_ch5!(reg);
+        dma1_count += 7;
+        dma2_count += 5;
+    }
+    #[cfg(all(
+        feature = "dma",
+        any(
+            stm32_mcu = "stm32f401",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f410",
+            stm32_mcu = "stm32f411",
+            stm32_mcu = "stm32f412",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469",
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
+        )
+    ))]
+    {
+        let dma2_ch6 = drone_stm32_map::periph::dma::periph_dma2_ch6!(reg);
+        let dma2_ch7 = drone_stm32_map::periph::dma::periph_dma2_ch7!(reg);
+        dma2_count += 2;
+    }
+    assert_eq!(dma1_count, drone_stm32_map::periph::STM32_DMA1_CH_COUNT);
+    assert_eq!(dma2_count, drone_stm32_map::periph::STM32_DMA2_CH_COUNT);
+}
+
+#[test]
+#[cfg(feature = "gpio")]
+#[allow(unused_mut, unused_variables)]
+fn gpio_port_count() {
+    let reg = unsafe { Regs::take() };
+    let mut count = 0;
+    #[cfg(all(
+        feature = "gpio",
+        any(
+            stm32_mcu = "stm32f100",
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f102",
+            stm32_mcu = "stm32f103",
+            stm32_mcu = "stm32f107",
+            stm32_mcu = "stm32f401",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f410",
+            stm32_mcu = "stm32f411",
+            stm32_mcu = "stm32f412",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469",
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
+        )
+    ))]
+    {
+        let gpio_a = drone_stm32_map::periph::gpio::periph_gpio_a!(reg);
+        let gpio_b = drone_stm32_map::periph::gpio::periph_gpio_b!(reg);
+        let gpio_c = drone_stm32_map::periph::gpio::periph_gpio_c!(reg);
+        count += 3;
+    }
+    #[cfg(all(
+        feature = "gpio",
+        any(
+            stm32_mcu = "stm32f100",
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f102",
+            stm32_mcu = "stm32f103",
+            stm32_mcu = "stm32f107",
+            stm32_mcu = "stm32f401",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f411",
+            stm32_mcu = "stm32f412",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469",
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
+        )
+    ))]
+    {
+        let gpio_d = drone_stm32_map::periph::gpio::periph_gpio_d!(reg);
+        count += 1;
+    }
+    #[cfg(all(
+        feature = "gpio",
+        any(
+            stm32_mcu = "stm32f100",
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f103",
+            stm32_mcu = "stm32f107",
+            stm32_mcu = "stm32f401",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f411",
+            stm32_mcu = "stm32f412",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469",
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
+        )
+    ))]
+    {
+        let gpio_e = drone_stm32_map::periph::gpio::periph_gpio_e!(reg);
+        count += 1;
+    }
+    #[cfg(all(
+        feature = "gpio",
+        any(
+            stm32_mcu = "stm32f100",
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f103",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f412",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469",
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
+        )
+    ))]
+    {
+        let gpio_f = drone_stm32_map::periph::gpio::periph_gpio_f!(reg);
+        let gpio_g = drone_stm32_map::periph::gpio::periph_gpio_g!(reg);
+        count += 2;
+    }
+    #[cfg(all(
+        feature = "gpio",
+        any(
+            stm32_mcu = "stm32f401",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f410",
+            stm32_mcu = "stm32f411",
+            stm32_mcu = "stm32f412",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469",
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
+        )
+    ))]
+    {
+        let gpio_h = drone_stm32_map::periph::gpio::periph_gpio_h!(reg);
+        count += 1;
+    }
+    #[cfg(all(
+        feature = "gpio",
+        any(
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f469",
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32l4x6",
+        )
+    ))]
+    {
+        let gpio_i = drone_stm32_map::periph::gpio::periph_gpio_i!(reg);
+        count += 1;
+    }
+    #[cfg(all(
+        feature = "gpio",
+        any(
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f469",
+        )
+    ))]
+    {
+        let gpio_j = drone_stm32_map::periph::gpio::periph_gpio_j!(reg);
+        let gpio_k = drone_stm32_map::periph::gpio::periph_gpio_k!(reg);
+        count += 2;
+    }
+    assert_eq!(count, drone_stm32_map::periph::STM32_GPIO_PORT_COUNT);
+}
+
+#[test]
+#[cfg(feature = "uart")]
+#[allow(unused_mut, unused_variables)]
+fn uart_count() {
+    let reg = unsafe { Regs::take() };
+    let mut count = 0;
+    #[cfg(all(
+        feature = "uart",
+        any(
+            stm32_mcu = "stm32f100",
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f102",
+            stm32_mcu = "stm32f103",
+            stm32_mcu = "stm32f107",
+            stm32_mcu = "stm32f401",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f410",
+            stm32_mcu = "stm32f411",
+            stm32_mcu = "stm32f412",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469",
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
+        )
+    ))]
+    {
+        let usart1 = drone_stm32_map::periph::uart::periph_usart1!(reg);
+        let usart2 = drone_stm32_map::periph::uart::periph_usart2!(reg);
+        count += 2;
+    }
+    #[cfg(all(
+        feature = "uart",
+        any(
+            stm32_mcu = "stm32f100",
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f103",
+            stm32_mcu = "stm32f107",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f412",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469",
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
+        )
+    ))]
+    {
+        let usart3 = drone_stm32_map::periph::uart::periph_usart3!(reg);
+        count += 1;
+    }
+    #[cfg(all(
+        feature = "uart",
+        any(
+            stm32_mcu = "stm32f100",
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f103",
+            stm32_mcu = "stm32f107",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469",
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
+        )
+    ))]
+    {
+        let uart4 = drone_stm32_map::periph::uart::periph_uart4!(reg);
+        count += 1;
+    }
+    #[cfg(all(
+        feature = "uart",
+        any(
+            stm32_mcu = "stm32f100",
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f103",
+            stm32_mcu = "stm32f107",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469",
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
+        )
+    ))]
+    {
+        let uart5 = drone_stm32_map::periph::uart::periph_uart5!(reg);
+        count += 1;
+    }
+    #[cfg(all(
+        feature = "uart",
+        any(
+            stm32_mcu = "stm32f401",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f410",
+            stm32_mcu = "stm32f411",
+            stm32_mcu = "stm32f412",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469",
+        )
+    ))]
+    {
+        let usart6 = drone_stm32_map::periph::uart::periph_usart6!(reg);
+        count += 1;
+    }
+    #[cfg(all(
+        feature = "uart",
+        any(
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
+        )
+    ))]
+    {
+        let lpuart1 = drone_stm32_map::periph::uart::periph_lpuart1!(reg);
+        count += 1;
+    }
+    assert_eq!(count, drone_stm32_map::periph::STM32_UART_COUNT);
+}
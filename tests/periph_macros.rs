@@ -29,6 +29,11 @@ fn periph_macros1() {
             stm32_mcu = "stm32l4s5",
             stm32_mcu = "stm32l4s7",
             stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
         )
     ))]
     {
@@ -37,6 +42,11 @@ fn periph_macros1() {
     #[cfg(all(
         feature = "adc",
         any(
+            stm32_mcu = "stm32f100",
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f102",
+            stm32_mcu = "stm32f103",
+            stm32_mcu = "stm32f107",
             stm32_mcu = "stm32f401",
             stm32_mcu = "stm32f405",
             stm32_mcu = "stm32f407",
@@ -54,6 +64,11 @@ fn periph_macros1() {
             stm32_mcu = "stm32l4s5",
             stm32_mcu = "stm32l4s7",
             stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
         )
     ))]
     {
@@ -68,12 +83,252 @@ fn periph_macros1() {
             stm32_mcu = "stm32f429",
             stm32_mcu = "stm32f446",
             stm32_mcu = "stm32f469",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
+        )
+    ))]
+    {
+        let adc2 = drone_stm32_map::periph::adc::periph_adc2!(reg);
+        let adc3 = drone_stm32_map::periph::adc::periph_adc3!(reg);
+    }
+    #[cfg(all(
+        feature = "adc",
+        any(
+            stm32_mcu = "stm32f103",
+            stm32_mcu = "stm32f107",
         )
     ))]
     {
         let adc2 = drone_stm32_map::periph::adc::periph_adc2!(reg);
+    }
+    #[cfg(all(feature = "adc", any(stm32_mcu = "stm32f103")))]
+    {
         let adc3 = drone_stm32_map::periph::adc::periph_adc3!(reg);
     }
+    #[cfg(all(
+        feature = "aes",
+        any(
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x6",
+        )
+    ))]
+    {
+        let aes = drone_stm32_map::periph::aes::periph_aes!(reg);
+    }
+    #[cfg(all(
+        feature = "bkp",
+        any(
+            stm32_mcu = "stm32f100",
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f102",
+            stm32_mcu = "stm32f103",
+            stm32_mcu = "stm32f107",
+        )
+    ))]
+    {
+        let bkp = drone_stm32_map::periph::bkp::periph_bkp!(reg);
+    }
+    #[cfg(all(
+        feature = "can",
+        any(
+            stm32_mcu = "stm32f107",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469",
+            stm32_mcu = "stm32l4x6",
+        )
+    ))]
+    {
+        let can1 = drone_stm32_map::periph::can::periph_can1!(reg);
+    }
+    #[cfg(all(
+        feature = "can",
+        any(
+            stm32_mcu = "stm32f107",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469",
+            stm32_mcu = "stm32l4x6",
+        )
+    ))]
+    {
+        let can2 = drone_stm32_map::periph::can::periph_can2!(reg);
+    }
+    #[cfg(all(feature = "cec", any(stm32_mcu = "stm32f100", stm32_mcu = "stm32f446")))]
+    {
+        let cec = drone_stm32_map::periph::cec::periph_cec!(reg);
+    }
+    #[cfg(all(
+        feature = "comp",
+        any(
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
+        )
+    ))]
+    {
+        let comp1 = drone_stm32_map::periph::comp::periph_comp1!(reg);
+    }
+    #[cfg(all(
+        feature = "crc",
+        any(
+            stm32_mcu = "stm32f100",
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f102",
+            stm32_mcu = "stm32f103",
+            stm32_mcu = "stm32f107",
+            stm32_mcu = "stm32f401",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f410",
+            stm32_mcu = "stm32f411",
+            stm32_mcu = "stm32f412",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469",
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
+        )
+    ))]
+    {
+        let crc = drone_stm32_map::periph::crc::periph_crc!(reg);
+    }
+    #[cfg(all(
+        feature = "crs",
+        any(
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x6",
+        )
+    ))]
+    {
+        let crs = drone_stm32_map::periph::crs::periph_crs!(reg);
+    }
+    #[cfg(all(
+        feature = "cryp",
+        any(
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f469",
+        )
+    ))]
+    {
+        let cryp = drone_stm32_map::periph::cryp::periph_cryp!(reg);
+    }
+    #[cfg(all(
+        feature = "dac",
+        any(
+            stm32_mcu = "stm32f100",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f410",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469",
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
+        )
+    ))]
+    {
+        let dac = drone_stm32_map::periph::dac::periph_dac!(reg);
+    }
+    #[cfg(all(
+        feature = "dfsdm",
+        any(
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
+        )
+    ))]
+    {
+        let dfsdm1 = drone_stm32_map::periph::dfsdm::periph_dfsdm1!(reg);
+    }
+    #[cfg(all(
+        feature = "dfsdm",
+        any(
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
+        )
+    ))]
+    {
+        let dfsdm1_ch0 = drone_stm32_map::periph::dfsdm::periph_dfsdm1_ch0!(reg);
+    }
+    #[cfg(all(
+        feature = "dfsdm",
+        any(
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
+        )
+    ))]
+    {
+        let dfsdm1_flt0 = drone_stm32_map::periph::dfsdm::periph_dfsdm1_flt0!(reg);
+    }
     #[cfg(all(
         feature = "dma",
         any(
@@ -240,42 +495,78 @@ fn periph_macros1() {
         let dmamux1_rg3 = drone_stm32_map::periph::dma::periph_dmamux1_rg3!(reg);
     }
     #[cfg(all(
-        feature = "exti",
+        feature = "dma2d",
         any(
-            stm32_mcu = "stm32f401",
-            stm32_mcu = "stm32f405",
-            stm32_mcu = "stm32f407",
-            stm32_mcu = "stm32f410",
-            stm32_mcu = "stm32f411",
-            stm32_mcu = "stm32f412",
-            stm32_mcu = "stm32f413",
-            stm32_mcu = "stm32f427",
             stm32_mcu = "stm32f429",
-            stm32_mcu = "stm32f446",
             stm32_mcu = "stm32f469",
-            stm32_mcu = "stm32l4r5",
-            stm32_mcu = "stm32l4r7",
             stm32_mcu = "stm32l4r9",
-            stm32_mcu = "stm32l4s5",
-            stm32_mcu = "stm32l4s7",
             stm32_mcu = "stm32l4s9",
-            stm32_mcu = "stm32l4x1",
-            stm32_mcu = "stm32l4x2",
-            stm32_mcu = "stm32l4x3",
-            stm32_mcu = "stm32l4x5",
-            stm32_mcu = "stm32l4x6",
         )
     ))]
     {
-        let exti0 = drone_stm32_map::periph::exti::periph_exti0!(reg);
-        let exti1 = drone_stm32_map::periph::exti::periph_exti1!(reg);
-        let exti2 = drone_stm32_map::periph::exti::periph_exti2!(reg);
-        let exti3 = drone_stm32_map::periph::exti::periph_exti3!(reg);
-        let exti4 = drone_stm32_map::periph::exti::periph_exti4!(reg);
-        let exti5 = drone_stm32_map::periph::exti::periph_exti5!(reg);
-        let exti6 = drone_stm32_map::periph::exti::periph_exti6!(reg);
-        let exti7 = drone_stm32_map::periph::exti::periph_exti7!(reg);
-        let exti8 = drone_stm32_map::periph::exti::periph_exti8!(reg);
+        let dma2d = drone_stm32_map::periph::dma2d::periph_dma2d!(reg);
+    }
+    #[cfg(all(
+        feature = "dsi",
+        any(
+            stm32_mcu = "stm32f469",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s9",
+        )
+    ))]
+    {
+        let dsi = drone_stm32_map::periph::dsi::periph_dsi!(reg);
+    }
+    #[cfg(all(
+        feature = "eth",
+        any(
+            stm32_mcu = "stm32f107",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f469",
+        )
+    ))]
+    {
+        let eth = drone_stm32_map::periph::eth::periph_eth!(reg);
+    }
+    #[cfg(all(
+        feature = "exti",
+        any(
+            stm32_mcu = "stm32f401",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f410",
+            stm32_mcu = "stm32f411",
+            stm32_mcu = "stm32f412",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469",
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
+        )
+    ))]
+    {
+        let exti0 = drone_stm32_map::periph::exti::periph_exti0!(reg);
+        let exti1 = drone_stm32_map::periph::exti::periph_exti1!(reg);
+        let exti2 = drone_stm32_map::periph::exti::periph_exti2!(reg);
+        let exti3 = drone_stm32_map::periph::exti::periph_exti3!(reg);
+        let exti4 = drone_stm32_map::periph::exti::periph_exti4!(reg);
+        let exti5 = drone_stm32_map::periph::exti::periph_exti5!(reg);
+        let exti6 = drone_stm32_map::periph::exti::periph_exti6!(reg);
+        let exti7 = drone_stm32_map::periph::exti::periph_exti7!(reg);
+        let exti8 = drone_stm32_map::periph::exti::periph_exti8!(reg);
         let exti9 = drone_stm32_map::periph::exti::periph_exti9!(reg);
         let exti10 = drone_stm32_map::periph::exti::periph_exti10!(reg);
         let exti11 = drone_stm32_map::periph::exti::periph_exti11!(reg);
@@ -335,6 +626,57 @@ fn periph_macros1() {
     {
         let exti23 = drone_stm32_map::periph::exti::periph_exti23!(reg);
     }
+    #[cfg(all(
+        feature = "exti",
+        any(
+            stm32_mcu = "stm32f100",
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f102",
+            stm32_mcu = "stm32f103",
+            stm32_mcu = "stm32f107",
+        )
+    ))]
+    {
+        let exti0 = drone_stm32_map::periph::exti::periph_exti0!(reg);
+        let exti1 = drone_stm32_map::periph::exti::periph_exti1!(reg);
+        let exti2 = drone_stm32_map::periph::exti::periph_exti2!(reg);
+        let exti3 = drone_stm32_map::periph::exti::periph_exti3!(reg);
+        let exti4 = drone_stm32_map::periph::exti::periph_exti4!(reg);
+        let exti5 = drone_stm32_map::periph::exti::periph_exti5!(reg);
+        let exti6 = drone_stm32_map::periph::exti::periph_exti6!(reg);
+        let exti7 = drone_stm32_map::periph::exti::periph_exti7!(reg);
+        let exti8 = drone_stm32_map::periph::exti::periph_exti8!(reg);
+        let exti9 = drone_stm32_map::periph::exti::periph_exti9!(reg);
+        let exti10 = drone_stm32_map::periph::exti::periph_exti10!(reg);
+        let exti11 = drone_stm32_map::periph::exti::periph_exti11!(reg);
+        let exti12 = drone_stm32_map::periph::exti::periph_exti12!(reg);
+        let exti13 = drone_stm32_map::periph::exti::periph_exti13!(reg);
+        let exti14 = drone_stm32_map::periph::exti::periph_exti14!(reg);
+        let exti15 = drone_stm32_map::periph::exti::periph_exti15!(reg);
+        let exti16 = drone_stm32_map::periph::exti::periph_exti16!(reg);
+        let exti17 = drone_stm32_map::periph::exti::periph_exti17!(reg);
+    }
+    #[cfg(all(
+        feature = "exti",
+        any(
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f102",
+            stm32_mcu = "stm32f103",
+            stm32_mcu = "stm32f107",
+        )
+    ))]
+    {
+        let exti18 = drone_stm32_map::periph::exti::periph_exti18!(reg);
+    }
+    #[cfg(all(
+        feature = "exti",
+        any(
+            stm32_mcu = "stm32f107",
+        )
+    ))]
+    {
+        let exti19 = drone_stm32_map::periph::exti::periph_exti19!(reg);
+    }
     #[cfg(all(
         feature = "exti",
         any(
@@ -370,29 +712,697 @@ fn periph_macros1() {
         let exti39 = drone_stm32_map::periph::exti::periph_exti39!(reg);
     }
     #[cfg(all(
-        feature = "exti",
+        feature = "exti",
+        any(
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
+        )
+    ))]
+    {
+        let exti40 = drone_stm32_map::periph::exti::periph_exti40!(reg);
+    }
+    #[cfg(all(
+        feature = "fmc",
+        any(
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469",
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
+        )
+    ))]
+    {
+        let fmc = drone_stm32_map::periph::fmc::periph_fmc!(reg);
+    }
+    #[cfg(all(
+        feature = "fsmc",
+        any(
+            stm32_mcu = "stm32f100",
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f103",
+        )
+    ))]
+    {
+        let fsmc = drone_stm32_map::periph::fsmc::periph_fsmc!(reg);
+    }
+    #[cfg(all(
+        feature = "fw",
+        any(
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
+        )
+    ))]
+    {
+        let fw = drone_stm32_map::periph::fw::periph_fw!(reg);
+    }
+    #[cfg(all(
+        feature = "gfxmmu",
+        any(
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s9",
+        )
+    ))]
+    {
+        let gfxmmu = drone_stm32_map::periph::gfxmmu::periph_gfxmmu!(reg);
+    }
+    #[cfg(all(
+        feature = "gpio",
+        any(
+            stm32_mcu = "stm32f100",
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f102",
+            stm32_mcu = "stm32f103",
+            stm32_mcu = "stm32f107",
+            stm32_mcu = "stm32f401",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f410",
+            stm32_mcu = "stm32f411",
+            stm32_mcu = "stm32f412",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469",
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
+        )
+    ))]
+    {
+        let gpio_a = drone_stm32_map::periph::gpio::periph_gpio_a!(reg);
+        let gpio_b = drone_stm32_map::periph::gpio::periph_gpio_b!(reg);
+        let gpio_c = drone_stm32_map::periph::gpio::periph_gpio_c!(reg);
+    }
+    #[cfg(all(
+        feature = "gpio",
+        any(
+            stm32_mcu = "stm32f100",
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f102",
+            stm32_mcu = "stm32f103",
+            stm32_mcu = "stm32f107",
+            stm32_mcu = "stm32f401",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f411",
+            stm32_mcu = "stm32f412",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469",
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
+        )
+    ))]
+    {
+        let gpio_d = drone_stm32_map::periph::gpio::periph_gpio_d!(reg);
+    }
+    #[cfg(all(
+        feature = "gpio",
+        any(
+            stm32_mcu = "stm32f100",
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f103",
+            stm32_mcu = "stm32f107",
+            stm32_mcu = "stm32f401",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f411",
+            stm32_mcu = "stm32f412",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469",
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
+        )
+    ))]
+    {
+        let gpio_e = drone_stm32_map::periph::gpio::periph_gpio_e!(reg);
+    }
+    #[cfg(all(
+        feature = "gpio",
+        any(
+            stm32_mcu = "stm32f100",
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f103",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f412",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469",
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
+        )
+    ))]
+    {
+        let gpio_f = drone_stm32_map::periph::gpio::periph_gpio_f!(reg);
+        let gpio_g = drone_stm32_map::periph::gpio::periph_gpio_g!(reg);
+    }
+    #[cfg(all(
+        feature = "gpio",
+        any(
+            stm32_mcu = "stm32f401",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f410",
+            stm32_mcu = "stm32f411",
+            stm32_mcu = "stm32f412",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469",
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
+        )
+    ))]
+    {
+        let gpio_h = drone_stm32_map::periph::gpio::periph_gpio_h!(reg);
+    }
+    #[cfg(all(
+        feature = "gpio",
+        any(
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f469",
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32l4x6",
+        )
+    ))]
+    {
+        let gpio_i = drone_stm32_map::periph::gpio::periph_gpio_i!(reg);
+    }
+    #[cfg(all(
+        feature = "gpio",
+        any(
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f469",
+        )
+    ))]
+    {
+        let gpio_j = drone_stm32_map::periph::gpio::periph_gpio_j!(reg);
+        let gpio_k = drone_stm32_map::periph::gpio::periph_gpio_k!(reg);
+    }
+    #[cfg(all(
+        feature = "i2c",
+        any(
+            stm32_mcu = "stm32f401",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f410",
+            stm32_mcu = "stm32f411",
+            stm32_mcu = "stm32f412",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469",
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
+        )
+    ))]
+    {
+        let i2c1 = drone_stm32_map::periph::i2c::periph_i2c1!(reg);
+        let i2c2 = drone_stm32_map::periph::i2c::periph_i2c2!(reg);
+    }
+    #[cfg(all(
+        feature = "i2c",
+        any(
+            stm32_mcu = "stm32f401",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f411",
+            stm32_mcu = "stm32f412",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469",
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
+        )
+    ))]
+    {
+        let i2c3 = drone_stm32_map::periph::i2c::periph_i2c3!(reg);
+    }
+    #[cfg(all(
+        feature = "i2c",
+        any(
+            stm32_mcu = "stm32f410",
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x6",
+        )
+    ))]
+    {
+        let i2c4 = drone_stm32_map::periph::i2c::periph_i2c4!(reg);
+    }
+    #[cfg(all(
+        feature = "i2c",
+        any(
+            stm32_mcu = "stm32f412",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f446",
+        )
+    ))]
+    {
+        let i2cfmp1 = drone_stm32_map::periph::i2c::periph_i2cfmp1!(reg);
+    }
+    #[cfg(all(feature = "lcd", any(stm32_mcu = "stm32l4x3", stm32_mcu = "stm32l4x6")))]
+    {
+        let lcd = drone_stm32_map::periph::lcd::periph_lcd!(reg);
+    }
+    #[cfg(all(
+        feature = "ltdc",
+        any(
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f469",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s9",
+        )
+    ))]
+    {
+        let ltdc = drone_stm32_map::periph::ltdc::periph_ltdc!(reg);
+    }
+    #[cfg(all(
+        feature = "octospi",
+        any(
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9",
+        )
+    ))]
+    {
+        let octospi1 = drone_stm32_map::periph::octospi::periph_octospi1!(reg);
+    }
+    #[cfg(all(
+        feature = "octospi",
+        any(
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9",
+        )
+    ))]
+    {
+        let octospi2 = drone_stm32_map::periph::octospi::periph_octospi2!(reg);
+    }
+    #[cfg(all(
+        feature = "octospi",
+        any(
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9",
+        )
+    ))]
+    {
+        let octospim = drone_stm32_map::periph::octospi::periph_octospim!(reg);
+    }
+    #[cfg(all(
+        feature = "opamp",
+        any(
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
+        )
+    ))]
+    {
+        let opamp1 = drone_stm32_map::periph::opamp::periph_opamp1!(reg);
+    }
+    #[cfg(all(
+        feature = "osc",
+        any(
+            stm32_mcu = "stm32f100",
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f102",
+            stm32_mcu = "stm32f103",
+            stm32_mcu = "stm32f107",
+            stm32_mcu = "stm32f401",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f410",
+            stm32_mcu = "stm32f411",
+            stm32_mcu = "stm32f412",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469",
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
+        )
+    ))]
+    {
+        let hse = drone_stm32_map::periph::osc::periph_hse!(reg);
+    }
+    #[cfg(all(
+        feature = "osc",
+        any(
+            stm32_mcu = "stm32f100",
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f102",
+            stm32_mcu = "stm32f103",
+            stm32_mcu = "stm32f107",
+            stm32_mcu = "stm32f401",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f410",
+            stm32_mcu = "stm32f411",
+            stm32_mcu = "stm32f412",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469",
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
+        )
+    ))]
+    {
+        let hsi = drone_stm32_map::periph::osc::periph_hsi!(reg);
+    }
+    #[cfg(all(
+        feature = "osc",
+        any(
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+        )
+    ))]
+    {
+        let hsi48 = drone_stm32_map::periph::osc::periph_hsi48!(reg);
+    }
+    #[cfg(all(
+        feature = "osc",
+        any(
+            stm32_mcu = "stm32f100",
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f102",
+            stm32_mcu = "stm32f103",
+            stm32_mcu = "stm32f107",
+            stm32_mcu = "stm32f401",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f410",
+            stm32_mcu = "stm32f411",
+            stm32_mcu = "stm32f412",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469",
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
+        )
+    ))]
+    {
+        let lse = drone_stm32_map::periph::osc::periph_lse!(reg);
+    }
+    #[cfg(all(
+        feature = "osc",
+        any(
+            stm32_mcu = "stm32f100",
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f102",
+            stm32_mcu = "stm32f103",
+            stm32_mcu = "stm32f107",
+            stm32_mcu = "stm32f401",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f410",
+            stm32_mcu = "stm32f411",
+            stm32_mcu = "stm32f412",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469",
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
+        )
+    ))]
+    {
+        let lsi = drone_stm32_map::periph::osc::periph_lsi!(reg);
+    }
+    #[cfg(all(
+        feature = "osc",
+        any(
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
+        )
+    ))]
+    {
+        let msi = drone_stm32_map::periph::osc::periph_msi!(reg);
+    }
+    #[cfg(all(
+        feature = "otg_fs",
+        any(
+            stm32_mcu = "stm32f401",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f411",
+            stm32_mcu = "stm32f412",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469",
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32l4x6",
+        )
+    ))]
+    {
+        let otg_fs = drone_stm32_map::periph::otg_fs::periph_otg_fs!(reg);
+    }
+    #[cfg(all(
+        feature = "otg_hs",
+        any(
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469",
+        )
+    ))]
+    {
+        let otg_hs = drone_stm32_map::periph::otg_hs::periph_otg_hs!(reg);
+    }
+    #[cfg(all(
+        feature = "pwr",
+        any(
+            stm32_mcu = "stm32f401",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f410",
+            stm32_mcu = "stm32f411",
+            stm32_mcu = "stm32f412",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469",
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
+        )
+    ))]
+    {
+        let pwr = drone_stm32_map::periph::pwr::periph_pwr!(reg);
+    }
+    #[cfg(all(
+        feature = "quadspi",
         any(
-            stm32_mcu = "stm32l4r5",
-            stm32_mcu = "stm32l4r7",
-            stm32_mcu = "stm32l4r9",
-            stm32_mcu = "stm32l4s5",
-            stm32_mcu = "stm32l4s7",
-            stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32f412",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469",
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
             stm32_mcu = "stm32l4x5",
             stm32_mcu = "stm32l4x6",
         )
     ))]
     {
-        let exti40 = drone_stm32_map::periph::exti::periph_exti40!(reg);
+        let quadspi = drone_stm32_map::periph::quadspi::periph_quadspi!(reg);
     }
     #[cfg(all(
-        feature = "gpio",
+        feature = "rtc",
         any(
-            stm32_mcu = "stm32f100",
-            stm32_mcu = "stm32f101",
-            stm32_mcu = "stm32f102",
-            stm32_mcu = "stm32f103",
-            stm32_mcu = "stm32f107",
             stm32_mcu = "stm32f401",
             stm32_mcu = "stm32f405",
             stm32_mcu = "stm32f407",
@@ -418,23 +1428,11 @@ fn periph_macros1() {
         )
     ))]
     {
-        let gpio_a = drone_stm32_map::periph::gpio::periph_gpio_a!(reg);
-        let gpio_b = drone_stm32_map::periph::gpio::periph_gpio_b!(reg);
-        let gpio_c = drone_stm32_map::periph::gpio::periph_gpio_c!(reg);
+        let rtc = drone_stm32_map::periph::rtc::periph_rtc!(reg);
     }
     #[cfg(all(
-        feature = "gpio",
+        feature = "sai",
         any(
-            stm32_mcu = "stm32f100",
-            stm32_mcu = "stm32f101",
-            stm32_mcu = "stm32f102",
-            stm32_mcu = "stm32f103",
-            stm32_mcu = "stm32f107",
-            stm32_mcu = "stm32f401",
-            stm32_mcu = "stm32f405",
-            stm32_mcu = "stm32f407",
-            stm32_mcu = "stm32f411",
-            stm32_mcu = "stm32f412",
             stm32_mcu = "stm32f413",
             stm32_mcu = "stm32f427",
             stm32_mcu = "stm32f429",
@@ -454,20 +1452,11 @@ fn periph_macros1() {
         )
     ))]
     {
-        let gpio_d = drone_stm32_map::periph::gpio::periph_gpio_d!(reg);
+        let sai1 = drone_stm32_map::periph::sai::periph_sai1!(reg);
     }
     #[cfg(all(
-        feature = "gpio",
+        feature = "sai",
         any(
-            stm32_mcu = "stm32f100",
-            stm32_mcu = "stm32f101",
-            stm32_mcu = "stm32f103",
-            stm32_mcu = "stm32f107",
-            stm32_mcu = "stm32f401",
-            stm32_mcu = "stm32f405",
-            stm32_mcu = "stm32f407",
-            stm32_mcu = "stm32f411",
-            stm32_mcu = "stm32f412",
             stm32_mcu = "stm32f413",
             stm32_mcu = "stm32f427",
             stm32_mcu = "stm32f429",
@@ -487,39 +1476,58 @@ fn periph_macros1() {
         )
     ))]
     {
-        let gpio_e = drone_stm32_map::periph::gpio::periph_gpio_e!(reg);
+        let sai1_a = drone_stm32_map::periph::sai::periph_sai1_a!(reg);
     }
     #[cfg(all(
-        feature = "gpio",
+        feature = "sdio",
         any(
-            stm32_mcu = "stm32f100",
-            stm32_mcu = "stm32f101",
             stm32_mcu = "stm32f103",
+            stm32_mcu = "stm32f401",
             stm32_mcu = "stm32f405",
             stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f411",
             stm32_mcu = "stm32f412",
             stm32_mcu = "stm32f413",
             stm32_mcu = "stm32f427",
             stm32_mcu = "stm32f429",
             stm32_mcu = "stm32f446",
             stm32_mcu = "stm32f469",
+        )
+    ))]
+    {
+        let sdio = drone_stm32_map::periph::sdio::periph_sdio!(reg);
+    }
+    #[cfg(all(
+        feature = "sdmmc",
+        any(
             stm32_mcu = "stm32l4r5",
             stm32_mcu = "stm32l4r7",
             stm32_mcu = "stm32l4r9",
             stm32_mcu = "stm32l4s5",
             stm32_mcu = "stm32l4s7",
             stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
             stm32_mcu = "stm32l4x5",
             stm32_mcu = "stm32l4x6",
         )
     ))]
     {
-        let gpio_f = drone_stm32_map::periph::gpio::periph_gpio_f!(reg);
-        let gpio_g = drone_stm32_map::periph::gpio::periph_gpio_g!(reg);
+        let sdmmc1 = drone_stm32_map::periph::sdmmc::periph_sdmmc1!(reg);
+    }
+    #[cfg(all(feature = "spdifrx", any(stm32_mcu = "stm32f446")))]
+    {
+        let spdifrx = drone_stm32_map::periph::spdifrx::periph_spdifrx!(reg);
     }
     #[cfg(all(
-        feature = "gpio",
+        feature = "spi",
         any(
+            stm32_mcu = "stm32f100",
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f102",
+            stm32_mcu = "stm32f103",
+            stm32_mcu = "stm32f107",
             stm32_mcu = "stm32f401",
             stm32_mcu = "stm32f405",
             stm32_mcu = "stm32f407",
@@ -545,15 +1553,25 @@ fn periph_macros1() {
         )
     ))]
     {
-        let gpio_h = drone_stm32_map::periph::gpio::periph_gpio_h!(reg);
+        let spi1 = drone_stm32_map::periph::spi::periph_spi1!(reg);
+        let spi2 = drone_stm32_map::periph::spi::periph_spi2!(reg);
     }
     #[cfg(all(
-        feature = "gpio",
+        feature = "spi",
         any(
+            stm32_mcu = "stm32f100",
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f103",
+            stm32_mcu = "stm32f107",
+            stm32_mcu = "stm32f401",
             stm32_mcu = "stm32f405",
             stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f411",
+            stm32_mcu = "stm32f412",
+            stm32_mcu = "stm32f413",
             stm32_mcu = "stm32f427",
             stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
             stm32_mcu = "stm32f469",
             stm32_mcu = "stm32l4r5",
             stm32_mcu = "stm32l4r7",
@@ -561,76 +1579,51 @@ fn periph_macros1() {
             stm32_mcu = "stm32l4s5",
             stm32_mcu = "stm32l4s7",
             stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
             stm32_mcu = "stm32l4x6",
         )
     ))]
     {
-        let gpio_i = drone_stm32_map::periph::gpio::periph_gpio_i!(reg);
+        let spi3 = drone_stm32_map::periph::spi::periph_spi3!(reg);
     }
     #[cfg(all(
-        feature = "gpio",
+        feature = "spi",
         any(
+            stm32_mcu = "stm32f401",
             stm32_mcu = "stm32f405",
             stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f411",
+            stm32_mcu = "stm32f412",
             stm32_mcu = "stm32f427",
             stm32_mcu = "stm32f429",
             stm32_mcu = "stm32f469",
         )
     ))]
     {
-        let gpio_j = drone_stm32_map::periph::gpio::periph_gpio_j!(reg);
-        let gpio_k = drone_stm32_map::periph::gpio::periph_gpio_k!(reg);
+        let i2s2ext = drone_stm32_map::periph::spi::periph_i2s2ext!(reg);
     }
     #[cfg(all(
-        feature = "i2c",
+        feature = "spi",
         any(
             stm32_mcu = "stm32f401",
             stm32_mcu = "stm32f405",
             stm32_mcu = "stm32f407",
-            stm32_mcu = "stm32f410",
             stm32_mcu = "stm32f411",
             stm32_mcu = "stm32f412",
-            stm32_mcu = "stm32f413",
             stm32_mcu = "stm32f427",
             stm32_mcu = "stm32f429",
-            stm32_mcu = "stm32f446",
             stm32_mcu = "stm32f469",
-            stm32_mcu = "stm32l4r5",
-            stm32_mcu = "stm32l4r7",
-            stm32_mcu = "stm32l4r9",
-            stm32_mcu = "stm32l4s5",
-            stm32_mcu = "stm32l4s7",
-            stm32_mcu = "stm32l4s9",
-            stm32_mcu = "stm32l4x1",
-            stm32_mcu = "stm32l4x2",
-            stm32_mcu = "stm32l4x3",
-            stm32_mcu = "stm32l4x5",
-            stm32_mcu = "stm32l4x6",
         )
     ))]
     {
-        let i2c1 = drone_stm32_map::periph::i2c::periph_i2c1!(reg);
-        let i2c2 = drone_stm32_map::periph::i2c::periph_i2c2!(reg);
+        let i2s3ext = drone_stm32_map::periph::spi::periph_i2s3ext!(reg);
     }
     #[cfg(all(
-        feature = "i2c",
+        feature = "swpmi",
         any(
-            stm32_mcu = "stm32f401",
-            stm32_mcu = "stm32f405",
-            stm32_mcu = "stm32f407",
-            stm32_mcu = "stm32f411",
-            stm32_mcu = "stm32f412",
-            stm32_mcu = "stm32f413",
-            stm32_mcu = "stm32f427",
-            stm32_mcu = "stm32f429",
-            stm32_mcu = "stm32f446",
-            stm32_mcu = "stm32f469",
-            stm32_mcu = "stm32l4r5",
-            stm32_mcu = "stm32l4r7",
-            stm32_mcu = "stm32l4r9",
-            stm32_mcu = "stm32l4s5",
-            stm32_mcu = "stm32l4s7",
-            stm32_mcu = "stm32l4s9",
             stm32_mcu = "stm32l4x1",
             stm32_mcu = "stm32l4x2",
             stm32_mcu = "stm32l4x3",
@@ -639,27 +1632,35 @@ fn periph_macros1() {
         )
     ))]
     {
-        let i2c3 = drone_stm32_map::periph::i2c::periph_i2c3!(reg);
+        let swpmi1 = drone_stm32_map::periph::swpmi::periph_swpmi1!(reg);
     }
     #[cfg(all(
-        feature = "i2c",
+        feature = "syscfg",
         any(
-            stm32_mcu = "stm32l4r5",
-            stm32_mcu = "stm32l4r7",
-            stm32_mcu = "stm32l4r9",
-            stm32_mcu = "stm32l4s5",
-            stm32_mcu = "stm32l4s7",
-            stm32_mcu = "stm32l4s9",
-            stm32_mcu = "stm32l4x2",
-            stm32_mcu = "stm32l4x6",
+            stm32_mcu = "stm32f100",
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f102",
+            stm32_mcu = "stm32f103",
+            stm32_mcu = "stm32f107",
         )
     ))]
     {
-        let i2c4 = drone_stm32_map::periph::i2c::periph_i2c4!(reg);
+        let afio = drone_stm32_map::periph::syscfg::periph_afio!(reg);
     }
     #[cfg(all(
-        feature = "rtc",
+        feature = "syscfg",
         any(
+            stm32_mcu = "stm32f401",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f410",
+            stm32_mcu = "stm32f411",
+            stm32_mcu = "stm32f412",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469",
             stm32_mcu = "stm32l4r5",
             stm32_mcu = "stm32l4r7",
             stm32_mcu = "stm32l4r9",
@@ -674,16 +1675,25 @@ fn periph_macros1() {
         )
     ))]
     {
-        let rtc = drone_stm32_map::periph::rtc::periph_rtc!(reg);
+        let syscfg = drone_stm32_map::periph::syscfg::periph_syscfg!(reg);
     }
     #[cfg(all(
-        feature = "spi",
+        feature = "tim",
         any(
             stm32_mcu = "stm32f100",
-            stm32_mcu = "stm32f101",
-            stm32_mcu = "stm32f102",
             stm32_mcu = "stm32f103",
             stm32_mcu = "stm32f107",
+            stm32_mcu = "stm32f401",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f410",
+            stm32_mcu = "stm32f411",
+            stm32_mcu = "stm32f412",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469",
             stm32_mcu = "stm32l4r5",
             stm32_mcu = "stm32l4r7",
             stm32_mcu = "stm32l4r9",
@@ -698,16 +1708,21 @@ fn periph_macros1() {
         )
     ))]
     {
-        let spi1 = drone_stm32_map::periph::spi::periph_spi1!(reg);
-        let spi2 = drone_stm32_map::periph::spi::periph_spi2!(reg);
+        let tim1 = drone_stm32_map::periph::tim::periph_tim1!(reg);
     }
     #[cfg(all(
-        feature = "spi",
+        feature = "tim",
         any(
-            stm32_mcu = "stm32f100",
-            stm32_mcu = "stm32f101",
-            stm32_mcu = "stm32f103",
-            stm32_mcu = "stm32f107",
+            stm32_mcu = "stm32f401",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f411",
+            stm32_mcu = "stm32f412",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469",
             stm32_mcu = "stm32l4r5",
             stm32_mcu = "stm32l4r7",
             stm32_mcu = "stm32l4r9",
@@ -722,7 +1737,7 @@ fn periph_macros1() {
         )
     ))]
     {
-        let spi3 = drone_stm32_map::periph::spi::periph_spi3!(reg);
+        let tim2 = drone_stm32_map::periph::tim::periph_tim2!(reg);
     }
     #[cfg(all(
         feature = "tim",
@@ -730,7 +1745,6 @@ fn periph_macros1() {
             stm32_mcu = "stm32f401",
             stm32_mcu = "stm32f405",
             stm32_mcu = "stm32f407",
-            stm32_mcu = "stm32f410",
             stm32_mcu = "stm32f411",
             stm32_mcu = "stm32f412",
             stm32_mcu = "stm32f413",
@@ -746,13 +1760,12 @@ fn periph_macros1() {
             stm32_mcu = "stm32l4s9",
             stm32_mcu = "stm32l4x1",
             stm32_mcu = "stm32l4x2",
-            stm32_mcu = "stm32l4x3",
             stm32_mcu = "stm32l4x5",
             stm32_mcu = "stm32l4x6",
         )
     ))]
     {
-        let tim1 = drone_stm32_map::periph::tim::periph_tim1!(reg);
+        let tim3 = drone_stm32_map::periph::tim::periph_tim3!(reg);
     }
     #[cfg(all(
         feature = "tim",
@@ -773,15 +1786,13 @@ fn periph_macros1() {
             stm32_mcu = "stm32l4s5",
             stm32_mcu = "stm32l4s7",
             stm32_mcu = "stm32l4s9",
-            stm32_mcu = "stm32l4x1",
-            stm32_mcu = "stm32l4x2",
-            stm32_mcu = "stm32l4x3",
             stm32_mcu = "stm32l4x5",
             stm32_mcu = "stm32l4x6",
         )
     ))]
     {
-        let tim2 = drone_stm32_map::periph::tim::periph_tim2!(reg);
+        let tim4 = drone_stm32_map::periph::tim::periph_tim4!(reg);
+        let tim5 = drone_stm32_map::periph::tim::periph_tim5!(reg);
     }
     #[cfg(all(
         feature = "tim",
@@ -804,12 +1815,14 @@ fn periph_macros1() {
             stm32_mcu = "stm32l4s9",
             stm32_mcu = "stm32l4x1",
             stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
             stm32_mcu = "stm32l4x5",
             stm32_mcu = "stm32l4x6",
         )
     ))]
     {
-        let tim3 = drone_stm32_map::periph::tim::periph_tim3!(reg);
+        fn general_tim_32<T: drone_stm32_map::periph::tim::general::GeneralTim32Map>() {}
+        general_tim_32::<drone_stm32_map::periph::tim::general::Tim2>();
     }
     #[cfg(all(
         feature = "tim",
@@ -817,6 +1830,7 @@ fn periph_macros1() {
             stm32_mcu = "stm32f401",
             stm32_mcu = "stm32f405",
             stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f410",
             stm32_mcu = "stm32f411",
             stm32_mcu = "stm32f412",
             stm32_mcu = "stm32f413",
@@ -835,8 +1849,8 @@ fn periph_macros1() {
         )
     ))]
     {
-        let tim4 = drone_stm32_map::periph::tim::periph_tim4!(reg);
-        let tim5 = drone_stm32_map::periph::tim::periph_tim5!(reg);
+        fn general_tim_32<T: drone_stm32_map::periph::tim::general::GeneralTim32Map>() {}
+        general_tim_32::<drone_stm32_map::periph::tim::general::Tim5>();
     }
     #[cfg(all(
         feature = "tim",
@@ -904,6 +1918,7 @@ fn periph_macros1() {
     #[cfg(all(
         feature = "tim",
         any(
+            stm32_mcu = "stm32f103",
             stm32_mcu = "stm32f405",
             stm32_mcu = "stm32f407",
             stm32_mcu = "stm32f412",
@@ -984,6 +1999,7 @@ fn periph_macros1() {
     #[cfg(all(
         feature = "tim",
         any(
+            stm32_mcu = "stm32f100",
             stm32_mcu = "stm32f405",
             stm32_mcu = "stm32f407",
             stm32_mcu = "stm32f412",
@@ -1016,11 +2032,47 @@ fn periph_macros1() {
         )
     ))]
     {
-        let tim15 = drone_stm32_map::periph::tim::periph_tim15!(reg);
-        let tim16 = drone_stm32_map::periph::tim::periph_tim16!(reg);
+        let tim15 = drone_stm32_map::periph::tim::periph_tim15!(reg);
+        let tim16 = drone_stm32_map::periph::tim::periph_tim16!(reg);
+    }
+    #[cfg(all(
+        feature = "tim",
+        any(
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
+        )
+    ))]
+    {
+        let tim17 = drone_stm32_map::periph::tim::periph_tim17!(reg);
+    }
+    #[cfg(all(
+        feature = "tim",
+        any(
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
+        )
+    ))]
+    {
+        let lptim1 = drone_stm32_map::periph::tim::periph_lptim1!(reg);
+        let lptim2 = drone_stm32_map::periph::tim::periph_lptim2!(reg);
     }
     #[cfg(all(
-        feature = "tim",
+        feature = "tsc",
         any(
             stm32_mcu = "stm32l4r5",
             stm32_mcu = "stm32l4r7",
@@ -1028,16 +2080,35 @@ fn periph_macros1() {
             stm32_mcu = "stm32l4s5",
             stm32_mcu = "stm32l4s7",
             stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
             stm32_mcu = "stm32l4x5",
             stm32_mcu = "stm32l4x6",
         )
     ))]
     {
-        let tim17 = drone_stm32_map::periph::tim::periph_tim17!(reg);
+        let tsc = drone_stm32_map::periph::tsc::periph_tsc!(reg);
     }
     #[cfg(all(
-        feature = "tim",
+        feature = "uart",
         any(
+            stm32_mcu = "stm32f100",
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f102",
+            stm32_mcu = "stm32f103",
+            stm32_mcu = "stm32f107",
+            stm32_mcu = "stm32f401",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f410",
+            stm32_mcu = "stm32f411",
+            stm32_mcu = "stm32f412",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469",
             stm32_mcu = "stm32l4r5",
             stm32_mcu = "stm32l4r7",
             stm32_mcu = "stm32l4r9",
@@ -1052,12 +2123,24 @@ fn periph_macros1() {
         )
     ))]
     {
-        let lptim1 = drone_stm32_map::periph::tim::periph_lptim1!(reg);
-        let lptim2 = drone_stm32_map::periph::tim::periph_lptim2!(reg);
+        let usart1 = drone_stm32_map::periph::uart::periph_usart1!(reg);
+        let usart2 = drone_stm32_map::periph::uart::periph_usart2!(reg);
     }
     #[cfg(all(
         feature = "uart",
         any(
+            stm32_mcu = "stm32f100",
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f103",
+            stm32_mcu = "stm32f107",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f412",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469",
             stm32_mcu = "stm32l4r5",
             stm32_mcu = "stm32l4r7",
             stm32_mcu = "stm32l4r9",
@@ -1072,13 +2155,22 @@ fn periph_macros1() {
         )
     ))]
     {
-        let usart1 = drone_stm32_map::periph::uart::periph_usart1!(reg);
-        let usart2 = drone_stm32_map::periph::uart::periph_usart2!(reg);
         let usart3 = drone_stm32_map::periph::uart::periph_usart3!(reg);
     }
     #[cfg(all(
         feature = "uart",
         any(
+            stm32_mcu = "stm32f100",
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f103",
+            stm32_mcu = "stm32f107",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469",
             stm32_mcu = "stm32l4r5",
             stm32_mcu = "stm32l4r7",
             stm32_mcu = "stm32l4r9",
@@ -1097,6 +2189,17 @@ fn periph_macros1() {
     #[cfg(all(
         feature = "uart",
         any(
+            stm32_mcu = "stm32f100",
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f103",
+            stm32_mcu = "stm32f107",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469",
             stm32_mcu = "stm32l4r5",
             stm32_mcu = "stm32l4r7",
             stm32_mcu = "stm32l4r9",
@@ -1110,6 +2213,25 @@ fn periph_macros1() {
     {
         let uart5 = drone_stm32_map::periph::uart::periph_uart5!(reg);
     }
+    #[cfg(all(
+        feature = "uart",
+        any(
+            stm32_mcu = "stm32f401",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f410",
+            stm32_mcu = "stm32f411",
+            stm32_mcu = "stm32f412",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469",
+        )
+    ))]
+    {
+        let usart6 = drone_stm32_map::periph::uart::periph_usart6!(reg);
+    }
     #[cfg(all(
         feature = "uart",
         any(
@@ -1129,6 +2251,47 @@ fn periph_macros1() {
     {
         let lpuart1 = drone_stm32_map::periph::uart::periph_lpuart1!(reg);
     }
+    #[cfg(all(
+        feature = "usb",
+        any(
+            stm32_mcu = "stm32f102",
+            stm32_mcu = "stm32f103",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+        )
+    ))]
+    {
+        let usb = drone_stm32_map::periph::usb::periph_usb!(reg);
+    }
+    #[cfg(all(
+        feature = "vrefbuf",
+        any(
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
+        )
+    ))]
+    {
+        let vrefbuf = drone_stm32_map::periph::vrefbuf::periph_vrefbuf!(reg);
+    }
+}
+
+#[test]
+#[allow(unused_variables)]
+fn periph_macros_can_f103() {
+    let reg = unsafe { Regs::take() };
+    #[cfg(all(feature = "can", any(stm32_mcu = "stm32f103")))]
+    {
+        let can1 = drone_stm32_map::periph::can::periph_can1!(reg);
+    }
 }
 
 #[test]
@@ -1507,4 +2670,147 @@ fn periph_macros2() {
         let gpio_k14 = drone_stm32_map::periph::gpio::periph_gpio_k14!(reg);
         let gpio_k15 = drone_stm32_map::periph::gpio::periph_gpio_k15!(reg);
     }
+    #[cfg(all(
+        feature = "i2c",
+        any(
+            stm32_mcu = "stm32f401",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f410",
+            stm32_mcu = "stm32f411",
+            stm32_mcu = "stm32f412",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469",
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
+        )
+    ))]
+    {
+        let i2c1 = drone_stm32_map::periph::i2c::periph_i2c!(1, reg);
+    }
+    #[cfg(all(
+        feature = "spi",
+        any(
+            stm32_mcu = "stm32f100",
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f102",
+            stm32_mcu = "stm32f103",
+            stm32_mcu = "stm32f107",
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
+        )
+    ))]
+    {
+        let spi1 = drone_stm32_map::periph::spi::periph_spi!(1, reg);
+    }
+    #[cfg(all(
+        feature = "tim",
+        any(
+            stm32_mcu = "stm32f401",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f411",
+            stm32_mcu = "stm32f412",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469",
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
+        )
+    ))]
+    {
+        let tim2 = drone_stm32_map::periph::tim::periph_tim!(2, reg);
+    }
+    #[cfg(all(
+        feature = "uart",
+        any(
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
+        )
+    ))]
+    {
+        let usart2 = drone_stm32_map::periph::uart::periph_usart!(2, reg);
+    }
+}
+
+#[test]
+#[allow(unused_variables)]
+fn periph_macros3() {
+    let reg = unsafe { Regs::take() };
+    #[cfg(all(
+        feature = "gpio",
+        any(
+            stm32_mcu = "stm32f100",
+            stm32_mcu = "stm32f101",
+            stm32_mcu = "stm32f102",
+            stm32_mcu = "stm32f103",
+            stm32_mcu = "stm32f107",
+            stm32_mcu = "stm32f401",
+            stm32_mcu = "stm32f405",
+            stm32_mcu = "stm32f407",
+            stm32_mcu = "stm32f410",
+            stm32_mcu = "stm32f411",
+            stm32_mcu = "stm32f412",
+            stm32_mcu = "stm32f413",
+            stm32_mcu = "stm32f427",
+            stm32_mcu = "stm32f429",
+            stm32_mcu = "stm32f446",
+            stm32_mcu = "stm32f469",
+            stm32_mcu = "stm32l4r5",
+            stm32_mcu = "stm32l4r7",
+            stm32_mcu = "stm32l4r9",
+            stm32_mcu = "stm32l4s5",
+            stm32_mcu = "stm32l4s7",
+            stm32_mcu = "stm32l4s9",
+            stm32_mcu = "stm32l4x1",
+            stm32_mcu = "stm32l4x2",
+            stm32_mcu = "stm32l4x3",
+            stm32_mcu = "stm32l4x5",
+            stm32_mcu = "stm32l4x6",
+        )
+    ))]
+    {
+        let (gpio_a_head, gpio_a_pins) = drone_stm32_map::periph::gpio::periph_gpio_a_split!(reg);
+    }
 }